@@ -17,7 +17,7 @@ pub struct CliCallFunctionAction {
 
 #[derive(Debug)]
 pub struct CallFunctionAction {
-    method_name: String,
+    pub method_name: String,
     args: Vec<u8>,
     gas: near_primitives::types::Gas,
     deposit: near_primitives::types::Balance,
@@ -149,6 +149,12 @@ impl CallFunctionAction {
                                     file_path
                                 );
                             }
+                            near_primitives::views::ActionView::FunctionCall { .. } => {
+                                println!(
+                                    "\n Contract code {:?} is unchanged, the initialize method has been successfully called.",
+                                    file_path
+                                );
+                            }
                             _ => unreachable!("Error"),
                         }
                     }
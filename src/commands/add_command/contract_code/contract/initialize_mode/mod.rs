@@ -56,6 +56,25 @@ impl NextAction {
         Self::from(cli_action)
     }
 
+    pub fn init_method_name(&self) -> Option<&str> {
+        match self {
+            NextAction::Initialize(call_function_action) => {
+                Some(call_function_action.method_name.as_str())
+            }
+            NextAction::NoInitialize(_) => None,
+        }
+    }
+
+    /// The name of the sign subcommand, to repeat it in the rollback command.
+    pub fn sign_subcommand_name(&self) -> &'static str {
+        match self {
+            NextAction::Initialize(call_function_action) => {
+                call_function_action.sign_option.subcommand_name()
+            }
+            NextAction::NoInitialize(no_initialize) => no_initialize.sign_option.subcommand_name(),
+        }
+    }
+
     pub async fn process(
         self,
         prepopulated_unsigned_transaction: near_primitives::transaction::Transaction,
@@ -66,6 +66,12 @@ impl Contract {
 #[derive(Debug, Default, clap::Clap)]
 pub struct CliContractFile {
     file_path: Option<std::path::PathBuf>,
+    /// Deploy without confirmation even if methods of the deployed code disappear
+    #[clap(long)]
+    allow_removed_methods: bool,
+    /// Skip the deploy if the account already has this code (the initialize call is still made)
+    #[clap(long)]
+    skip_if_unchanged: bool,
     #[clap(subcommand)]
     next_action: Option<self::initialize_mode::CliNextAction>,
 }
@@ -73,6 +79,8 @@ pub struct CliContractFile {
 #[derive(Debug)]
 pub struct ContractFile {
     pub file_path: std::path::PathBuf,
+    allow_removed_methods: bool,
+    skip_if_unchanged: bool,
     next_action: self::initialize_mode::NextAction,
 }
 
@@ -88,6 +96,8 @@ impl From<CliContractFile> for ContractFile {
         };
         ContractFile {
             file_path,
+            allow_removed_methods: item.allow_removed_methods,
+            skip_if_unchanged: item.skip_if_unchanged,
             next_action,
         }
     }
@@ -106,6 +116,17 @@ impl ContractFile {
         path
     }
 
+    fn confirm_removed_methods() -> bool {
+        println!();
+        let selection = Select::with_theme(&ColorfulTheme::default())
+            .with_prompt("Some methods of the deployed code are missing in the new one. Do you want to deploy it?")
+            .items(&["No, cancel the deploy", "Yes, deploy it anyway"])
+            .default(0)
+            .interact()
+            .unwrap();
+        selection == 1
+    }
+
     pub async fn process(
         self,
         prepopulated_unsigned_transaction: near_primitives::transaction::Transaction,
@@ -116,6 +137,121 @@ impl ContractFile {
             .map_err(|err| color_eyre::Report::msg(format!("Failed to open file: {:?}", err)))?
             .read_to_end(&mut code)
             .map_err(|err| color_eyre::Report::msg(format!("Failed to read file: {:?}", err)))?;
+        if self.skip_if_unchanged {
+            match &network_connection_config {
+                Some(connection_config) => {
+                    let account_id = &prepopulated_unsigned_transaction.receiver_id;
+                    let code_hash =
+                        crate::common::fetch_account_code_hash(connection_config, account_id)
+                            .await?;
+                    if code_hash == near_primitives::hash::hash(&code) {
+                        println!(
+                            "\nThe contract code {:?} is already deployed to {} (code hash {}), the deploy is skipped",
+                            self.file_path, account_id, code_hash
+                        );
+                        return match self.next_action {
+                            self::initialize_mode::NextAction::NoInitialize(_) => Ok(()),
+                            next_action => {
+                                next_action
+                                    .process(
+                                        prepopulated_unsigned_transaction,
+                                        network_connection_config.clone(),
+                                        self.file_path,
+                                    )
+                                    .await
+                            }
+                        };
+                    }
+                }
+                None => println!("\nWarning: --skip-if-unchanged is ignored in offline mode"),
+            }
+        }
+        let max_contract_size =
+            crate::common::fetch_max_contract_size(network_connection_config.as_ref()).await;
+        let contract_code_check = crate::common::check_contract_code(
+            &code,
+            max_contract_size,
+            self.next_action.init_method_name(),
+        )
+        .map_err(|err| {
+            color_eyre::Report::msg(format!(
+                "The contract {:?} cannot be deployed: {}",
+                self.file_path, err
+            ))
+        })?;
+        let exported_functions = contract_code_check.module.exported_functions();
+        println!(
+            "\nContract code {:?}: {} bytes (the limit is {} bytes)",
+            self.file_path,
+            code.len(),
+            max_contract_size
+        );
+        println!(
+            "Exported functions ({}): {}",
+            exported_functions.len(),
+            exported_functions.join(", ")
+        );
+        if exported_functions.is_empty() {
+            println!("Warning: the contract does not export any functions");
+        }
+        if !contract_code_check.unknown_imports.is_empty() {
+            println!(
+                "Warning: the contract imports what the NEAR runtime does not provide, calls to it will fail:\n  {}",
+                contract_code_check.unknown_imports.join("\n  ")
+            );
+        }
+        if let Some(network_connection_config) = &network_connection_config {
+            let account_id = &prepopulated_unsigned_transaction.receiver_id;
+            match crate::common::fetch_deployed_contract_code(network_connection_config, account_id)
+                .await
+            {
+                Ok(Some(deployed_contract_code)) => {
+                    match crate::common::WasmModuleInfo::parse(&deployed_contract_code.code) {
+                        Ok(deployed_module) => {
+                            let exported_functions_diff =
+                                crate::common::ExportedFunctionsDiff::new(
+                                    &deployed_module,
+                                    &contract_code_check.module,
+                                );
+                            println!(
+                                "\nMethods compared with the code deployed to {}:",
+                                account_id
+                            );
+                            exported_functions_diff.print();
+                            if !exported_functions_diff.removed.is_empty()
+                                && !self.allow_removed_methods
+                                && !ContractFile::confirm_removed_methods()
+                            {
+                                println!("\nThe deploy is cancelled");
+                                return Ok(());
+                            }
+                        }
+                        Err(err) => println!(
+                            "Warning: the deployed code cannot be compared with the new one: {}",
+                            err
+                        ),
+                    }
+                    PreviousContractCode {
+                        account_id: account_id.to_string(),
+                        deployed_contract_code,
+                        network_connection_config: network_connection_config.clone(),
+                    }
+                    .backup(self.next_action.sign_subcommand_name())?;
+                }
+                Ok(None) => {
+                    println!("\n{} has no contract code deployed yet, all methods are new:", account_id);
+                    crate::common::ExportedFunctionsDiff::new(
+                        &crate::common::WasmModuleInfo::default(),
+                        &contract_code_check.module,
+                    )
+                    .print();
+                }
+                Err(err) => println!(
+                    "\nWarning: the code deployed to {} cannot be fetched, so it is neither backed up nor compared with the new one: {}",
+                    account_id, err
+                ),
+            }
+        }
         let action = near_primitives::transaction::Action::DeployContract(
             near_primitives::transaction::DeployContractAction { code },
         );
@@ -134,3 +270,114 @@ impl ContractFile {
             .await
     }
 }
+
+/// The code that was deployed to the account before the new one.
+#[derive(Debug)]
+struct PreviousContractCode {
+    account_id: String,
+    deployed_contract_code: crate::common::DeployedContractCode,
+    network_connection_config: crate::common::ConnectionConfig,
+}
+
+impl PreviousContractCode {
+    /// Backs up the code before the new one is deployed and prints how to roll the deploy back;
+    /// the deploy is not sent if the backup cannot be written.
+    fn backup(&self, sign_subcommand: &str) -> crate::CliResult {
+        let home_dir = dirs::home_dir().expect("Impossible to get your home dir!");
+        let mut backups_dir = std::path::PathBuf::from(&home_dir);
+        backups_dir.push(crate::consts::DIR_NAME_CONTRACT_BACKUPS);
+        let backup_path =
+            backup_contract_code(&backups_dir, &self.account_id, &self.deployed_contract_code)?;
+        println!(
+            "\nThe previously deployed code of {} is saved to {}\nTo roll back the deploy, run:\n  {}",
+            self.account_id,
+            backup_path.display(),
+            rollback_command(
+                &self.network_connection_config,
+                &self.account_id,
+                &backup_path,
+                sign_subcommand
+            )
+        );
+        Ok(())
+    }
+}
+
+/// Saves the deployed code as `<account>/<code hash>-<block height>.wasm` in the backups directory.
+fn backup_contract_code(
+    backups_dir: &std::path::Path,
+    account_id: &str,
+    deployed_contract_code: &crate::common::DeployedContractCode,
+) -> color_eyre::eyre::Result<std::path::PathBuf> {
+    let mut path = backups_dir.join(account_id);
+    std::fs::create_dir_all(&path)?;
+    path.push(format!(
+        "{}-{}.wasm",
+        deployed_contract_code.code_hash, deployed_contract_code.block_height
+    ));
+    std::fs::write(&path, &deployed_contract_code.code).map_err(|err| {
+        color_eyre::Report::msg(format!("Failed to back up the deployed code: {:?}", err))
+    })?;
+    Ok(path)
+}
+
+/// The command that deploys the backed up code again (the methods added by the new code are
+/// removed by the rollback, so it is not asked to confirm that).
+fn rollback_command(
+    network_connection_config: &crate::common::ConnectionConfig,
+    account_id: &str,
+    backup_path: &std::path::Path,
+    sign_subcommand: &str,
+) -> String {
+    let network_args = match network_connection_config {
+        crate::common::ConnectionConfig::Testnet => "testnet".to_string(),
+        crate::common::ConnectionConfig::Mainnet => "mainnet".to_string(),
+        crate::common::ConnectionConfig::Betanet => "betanet".to_string(),
+        crate::common::ConnectionConfig::Custom { url } => format!("custom --url {}", url),
+    };
+    format!(
+        "near-cli add contract-code network {} account '{}' contract-file '{}' --allow-removed-methods no-initialize {}",
+        network_args,
+        account_id,
+        backup_path.display(),
+        sign_subcommand
+    )
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn backup_and_rollback_command() {
+        let backups_dir = std::env::temp_dir().join(format!(
+            "near-cli-test-contract-backups-{}",
+            std::process::id()
+        ));
+        let deployed_contract_code = crate::common::DeployedContractCode {
+            code_hash: near_primitives::hash::hash(b"\0asm"),
+            code: b"\0asm".to_vec(),
+            block_height: 42,
+        };
+        let backup_path =
+            backup_contract_code(&backups_dir, "contract.testnet", &deployed_contract_code)
+                .unwrap();
+        assert_eq!(
+            backup_path,
+            backups_dir
+                .join("contract.testnet")
+                .join(format!("{}-42.wasm", deployed_contract_code.code_hash))
+        );
+        assert_eq!(std::fs::read(&backup_path).unwrap(), b"\0asm".to_vec());
+        assert_eq!(
+            rollback_command(
+                &crate::common::ConnectionConfig::Testnet,
+                "contract.testnet",
+                std::path::Path::new("/backups/code.wasm"),
+                "sign-with-keychain"
+            ),
+            "near-cli add contract-code network testnet account 'contract.testnet' contract-file '/backups/code.wasm' --allow-removed-methods no-initialize sign-with-keychain"
+        );
+        std::fs::remove_dir_all(&backups_dir).unwrap();
+    }
+}
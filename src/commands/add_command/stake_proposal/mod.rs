@@ -2,3 +2,6 @@ pub mod operation_mode;
 mod sender;
 mod transactions_signing;
 mod transfer_near_tokens_type;
+
+/// The number of epochs after an unstake proposal before the locked balance is returned.
+const UNSTAKE_LOCKUP_EPOCHS: u64 = 3;
@@ -97,6 +97,16 @@ impl TransactionsSigningAction {
         network_connection_config: Option<crate::common::ConnectionConfig>,
         stake: u128,
     ) -> crate::CliResult {
+        crate::common::check_staking_key(&self.transactions_signing_public_key)
+            .map_err(color_eyre::Report::msg)?;
+        if let Some(connection_config) = network_connection_config.as_ref() {
+            check_stake_proposal(
+                connection_config,
+                &prepopulated_unsigned_transaction.signer_id,
+                stake,
+            )
+            .await?;
+        }
         let action = near_primitives::transaction::Action::Stake(
             near_primitives::transaction::StakeAction {
                 stake,
@@ -127,11 +137,18 @@ impl TransactionsSigningAction {
                                 stake,
                                 public_key: _,
                             } => {
-                                println!(
-                                    "\nValidator <{}> has successfully staked {}.",
-                                    transaction_info.transaction.signer_id,
-                                    crate::common::NearBalance::from_yoctonear(stake),
-                                );
+                                if stake == 0 {
+                                    println!(
+                                        "\nValidator <{}> has successfully proposed to unstake.",
+                                        transaction_info.transaction.signer_id,
+                                    );
+                                } else {
+                                    println!(
+                                        "\nValidator <{}> has successfully staked {}.",
+                                        transaction_info.transaction.signer_id,
+                                        crate::common::NearBalance::from_yoctonear(stake),
+                                    );
+                                }
                             }
                             _ => unreachable!("Error"),
                         }
@@ -149,3 +166,58 @@ impl TransactionsSigningAction {
         Ok(())
     }
 }
+
+/// Checks the stake against the account's balance plus locked amount, so that the proposal does
+/// not fail after being sent, and compares it with the estimated minimum stake and seat price.
+async fn check_stake_proposal(
+    network_connection_config: &crate::common::ConnectionConfig,
+    validator_account_id: &str,
+    stake: u128,
+) -> crate::CliResult {
+    let (account_view, epoch_validators, seat_price_config) = futures::try_join!(
+        crate::common::fetch_account_view(network_connection_config, validator_account_id),
+        crate::common::EpochValidators::fetch(network_connection_config, None),
+        crate::common::SeatPriceConfig::fetch(network_connection_config),
+    )?;
+    crate::common::check_stake_proposal(stake, account_view.amount, account_view.locked)
+        .map_err(color_eyre::Report::msg)?;
+    if stake == 0 {
+        return Ok(());
+    }
+    // The runtime compares the stake with the seat price of the epoch it is processed in, so an
+    // estimate over the next validators can only warn.
+    let next_stakes = epoch_validators
+        .next_validators
+        .iter()
+        .map(|validator| validator.stake)
+        .collect::<Vec<_>>();
+    if let Some(minimum_stake) = seat_price_config
+        .estimate_seat_price(&next_stakes)
+        .map(|seat_price| seat_price_config.minimum_stake(seat_price))
+        .filter(|minimum_stake| stake < *minimum_stake)
+    {
+        println!(
+            "\nWARNING: The stake {} is below the estimated minimum stake {}, \
+            so the proposal is likely to fail with InsufficientStake.",
+            crate::common::NearBalance::from_yoctonear(stake),
+            crate::common::NearBalance::from_yoctonear(minimum_stake)
+        );
+    }
+    match seat_price_config
+        .estimate_seat_price(&epoch_validators.proposed_stakes(Some((validator_account_id, stake))))
+    {
+        Some(seat_price) if stake < seat_price => println!(
+            "\nWARNING: The stake {} is below the estimated seat price {}, \
+            so the validator is unlikely to get a seat.",
+            crate::common::NearBalance::from_yoctonear(stake),
+            crate::common::NearBalance::from_yoctonear(seat_price)
+        ),
+        Some(seat_price) => println!(
+            "\nThe stake {} is above the estimated seat price {}.",
+            crate::common::NearBalance::from_yoctonear(stake),
+            crate::common::NearBalance::from_yoctonear(seat_price)
+        ),
+        None => {}
+    }
+    Ok(())
+}
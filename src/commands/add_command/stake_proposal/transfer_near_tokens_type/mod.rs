@@ -1,14 +1,21 @@
-use dialoguer::Input;
+use dialoguer::{theme::ColorfulTheme, Input, Select};
+use strum::{EnumDiscriminants, EnumIter, EnumMessage, IntoEnumIterator};
 
 #[derive(Debug, clap::Clap)]
 pub enum CliTransfer {
     /// Enter an amount
     Amount(CliTransferNEARTokensAction),
+    /// Unstake everything (stake 0)
+    Unstake(CliUnstakeAction),
 }
 
-#[derive(Debug)]
+#[derive(Debug, EnumDiscriminants)]
+#[strum_discriminants(derive(EnumMessage, EnumIter))]
 pub enum Transfer {
+    #[strum_discriminants(strum(message = "Stake an amount"))]
     Amount(TransferNEARTokensAction),
+    #[strum_discriminants(strum(message = "Unstake everything (stake 0)"))]
+    Unstake(UnstakeAction),
 }
 
 impl From<CliTransfer> for Transfer {
@@ -17,13 +24,30 @@ impl From<CliTransfer> for Transfer {
             CliTransfer::Amount(cli_transfer_near_action) => {
                 Self::Amount(cli_transfer_near_action.into())
             }
+            CliTransfer::Unstake(cli_unstake_action) => Self::Unstake(cli_unstake_action.into()),
         }
     }
 }
 
 impl Transfer {
     pub fn choose_transfer_near() -> Self {
-        Self::from(CliTransfer::Amount(Default::default()))
+        println!();
+        let variants = TransferDiscriminants::iter().collect::<Vec<_>>();
+        let transfers = variants
+            .iter()
+            .map(|p| p.get_message().unwrap().to_owned())
+            .collect::<Vec<_>>();
+        let selected_transfer = Select::with_theme(&ColorfulTheme::default())
+            .with_prompt("Do you want to stake or to unstake?")
+            .items(&transfers)
+            .default(0)
+            .interact()
+            .unwrap();
+        let cli_transfer = match variants[selected_transfer] {
+            TransferDiscriminants::Amount => CliTransfer::Amount(Default::default()),
+            TransferDiscriminants::Unstake => CliTransfer::Unstake(Default::default()),
+        };
+        Self::from(cli_transfer)
     }
 
     pub async fn process(
@@ -37,6 +61,11 @@ impl Transfer {
                     .process(prepopulated_unsigned_transaction, network_connection_config)
                     .await
             }
+            Transfer::Unstake(unstake_action) => {
+                unstake_action
+                    .process(prepopulated_unsigned_transaction, network_connection_config)
+                    .await
+            }
         }
     }
 }
@@ -75,7 +104,7 @@ impl From<CliTransferNEARTokensAction> for TransferNEARTokensAction {
 impl TransferNEARTokensAction {
     pub fn input_amount() -> crate::common::NearBalance {
         Input::new()
-            .with_prompt("How many NEAR Tokens do you want to transfer? (example: 10NEAR or 0.5near or 10000yoctonear)")
+            .with_prompt("How many NEAR Tokens do you want to stake? (example: 10NEAR or 0.5near or 10000yoctonear)")
             .interact_text()
             .unwrap()
     }
@@ -94,3 +123,48 @@ impl TransferNEARTokensAction {
             .await
     }
 }
+
+/// создание предложения снять весь стейк
+#[derive(Debug, Default, clap::Clap)]
+pub struct CliUnstakeAction {
+    #[clap(subcommand)]
+    sign_transactions: Option<super::transactions_signing::CliTransactionsSigning>,
+}
+
+#[derive(Debug)]
+pub struct UnstakeAction {
+    pub sign_transactions: super::transactions_signing::TransactionsSigning,
+}
+
+impl From<CliUnstakeAction> for UnstakeAction {
+    fn from(item: CliUnstakeAction) -> Self {
+        println!(
+            "\nA stake proposal of 0 takes the validator out of the validator set. The locked \
+            balance stays locked while the validator is in the current and the next epoch's \
+            validator sets and is returned to the account after that, in {} to {} epochs.",
+            super::UNSTAKE_LOCKUP_EPOCHS - 1,
+            super::UNSTAKE_LOCKUP_EPOCHS
+        );
+        let sign_transactions = match item.sign_transactions {
+            Some(cli_sign_transaction) => cli_sign_transaction.into(),
+            None => super::transactions_signing::TransactionsSigning::choose_sign_transactions(),
+        };
+        Self { sign_transactions }
+    }
+}
+
+impl UnstakeAction {
+    pub async fn process(
+        self,
+        prepopulated_unsigned_transaction: near_primitives::transaction::Transaction,
+        network_connection_config: Option<crate::common::ConnectionConfig>,
+    ) -> crate::CliResult {
+        self.sign_transactions
+            .process(
+                prepopulated_unsigned_transaction,
+                network_connection_config,
+                0,
+            )
+            .await
+    }
+}
@@ -2,4 +2,4 @@ pub mod operation_mode;
 mod receiver;
 mod sender;
 pub mod sign_transaction;
-mod transaction_actions;
+pub mod transaction_actions;
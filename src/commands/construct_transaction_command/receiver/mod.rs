@@ -90,7 +90,11 @@ impl Receiver {
             receiver_id: self.receiver_account_id.clone(),
             ..prepopulated_unsigned_transaction
         };
-        self.action
+        let (unsigned_transaction, skip_action) = self
+            .action
+            .process(unsigned_transaction, network_connection_config.clone())
+            .await?;
+        skip_action
             .process(unsigned_transaction, network_connection_config)
             .await
     }
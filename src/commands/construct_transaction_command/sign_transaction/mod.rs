@@ -91,6 +91,16 @@ impl SignTransaction {
         Self::from(cli_sign_option)
     }
 
+    /// The subcommand that selects this sign option on the command line.
+    pub fn subcommand_name(&self) -> &'static str {
+        match self {
+            SignTransaction::SignPrivateKey(_) => "sign-private-key",
+            SignTransaction::SignWithKeychain(_) => "sign-with-keychain",
+            SignTransaction::SignWithLedger(_) => "sign-with-ledger",
+            SignTransaction::SignManually(_) => "sign-manually",
+        }
+    }
+
     pub async fn process(
         self,
         prepopulated_unsigned_transaction: near_primitives::transaction::Transaction,
@@ -118,4 +128,97 @@ impl SignTransaction {
             }
         }
     }
+
+    /// Adds a function call to the transaction, signs and sends it, and prints the outcome.
+    pub async fn process_function_call(
+        self,
+        prepopulated_unsigned_transaction: near_primitives::transaction::Transaction,
+        network_connection_config: crate::common::ConnectionConfig,
+        method_name: &str,
+        args: serde_json::Value,
+        gas: near_primitives::types::Gas,
+        deposit: near_primitives::types::Balance,
+    ) -> crate::CliResult {
+        self.send_function_call(
+            prepopulated_unsigned_transaction,
+            network_connection_config,
+            method_name,
+            args,
+            gas,
+            deposit,
+        )
+        .await
+        .map(|_| ())
+    }
+
+    /// Same as `process_function_call`, and returns the outcome when the call succeeded.
+    pub async fn send_function_call(
+        self,
+        prepopulated_unsigned_transaction: near_primitives::transaction::Transaction,
+        network_connection_config: crate::common::ConnectionConfig,
+        method_name: &str,
+        args: serde_json::Value,
+        gas: near_primitives::types::Gas,
+        deposit: near_primitives::types::Balance,
+    ) -> color_eyre::eyre::Result<Option<near_primitives::views::FinalExecutionOutcomeView>> {
+        let action = near_primitives::transaction::Action::FunctionCall(
+            near_primitives::transaction::FunctionCallAction {
+                method_name: method_name.to_string(),
+                args: args.to_string().into_bytes(),
+                gas,
+                deposit,
+            },
+        );
+        let mut actions = prepopulated_unsigned_transaction.actions.clone();
+        actions.push(action);
+        let unsigned_transaction = near_primitives::transaction::Transaction {
+            actions,
+            ..prepopulated_unsigned_transaction
+        };
+        match self
+            .process(
+                unsigned_transaction,
+                Some(network_connection_config.clone()),
+            )
+            .await?
+        {
+            Some(transaction_info) => {
+                match transaction_info.status {
+                    near_primitives::views::FinalExecutionStatus::NotStarted
+                    | near_primitives::views::FinalExecutionStatus::Started => unreachable!(),
+                    near_primitives::views::FinalExecutionStatus::Failure(
+                        ref tx_execution_error,
+                    ) => crate::common::print_transaction_error(tx_execution_error.clone()).await,
+                    near_primitives::views::FinalExecutionStatus::SuccessValue(ref value) => {
+                        println!(
+                            "\nThe \"{}\" call to <{}> on behalf of <{}> succeeded.",
+                            method_name,
+                            transaction_info.transaction.receiver_id,
+                            transaction_info.transaction.signer_id,
+                        );
+                        if let Ok(result) = near_primitives::serialize::from_base64(value) {
+                            if !result.is_empty() {
+                                println!(
+                                    "Result: {}",
+                                    crate::common::format_call_function_result(
+                                        &result,
+                                        &Default::default()
+                                    )
+                                );
+                            }
+                        }
+                    }
+                }
+                println!("\nTransaction Id {id}.\n\nTo see the transaction in the transaction explorer, please open this url in your browser:
+                    \n{path}{id}\n", id=transaction_info.transaction_outcome.id, path=network_connection_config.transaction_explorer());
+                match transaction_info.status {
+                    near_primitives::views::FinalExecutionStatus::SuccessValue(_) => {
+                        Ok(Some(transaction_info))
+                    }
+                    _ => Ok(None),
+                }
+            }
+            None => Ok(None),
+        }
+    }
 }
@@ -5,18 +5,29 @@ use std::path::PathBuf;
 /// подписание сформированной транзакции с помощью файла с ключами
 #[derive(Debug, Default, clap::Clap)]
 pub struct CliSignKeychain {
+    /// Prove the outcome with the light client proof once the transaction is sent
+    #[clap(long)]
+    verify: bool,
+    /// The hash of a final block you trust to anchor the light client proof; it must be newer
+    /// than the block which includes the transaction (implies --verify)
+    #[clap(long)]
+    light_client_head: Option<near_primitives::hash::CryptoHash>,
     #[clap(subcommand)]
     submit: Option<super::sign_with_private_key::Submit>,
 }
 
 #[derive(Debug)]
 pub struct SignKeychain {
+    pub verify: bool,
+    pub light_client_head: Option<near_primitives::hash::CryptoHash>,
     pub submit: Option<super::sign_with_private_key::Submit>,
 }
 
 impl From<CliSignKeychain> for SignKeychain {
     fn from(item: CliSignKeychain) -> Self {
         SignKeychain {
+            verify: item.verify || item.light_client_head.is_some(),
+            light_client_head: item.light_client_head,
             submit: item.submit,
         }
     }
@@ -45,6 +56,8 @@ impl SignKeychain {
         let sign_with_private_key = super::sign_with_private_key::SignPrivateKey {
             signer_public_key: account_json.public_key,
             signer_secret_key: account_json.private_key,
+            verify: self.verify,
+            light_client_head: self.light_client_head,
             submit: self.submit.clone(),
         };
         sign_with_private_key
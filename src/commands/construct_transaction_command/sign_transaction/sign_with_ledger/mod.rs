@@ -8,6 +8,13 @@ use strum::{EnumDiscriminants, EnumIter, EnumMessage, IntoEnumIterator};
 pub struct CliSignLedger {
     #[clap(long)]
     seed_phrase_hd_path: Option<slip10::BIP32Path>,
+    /// Prove the outcome with the light client proof once the transaction is sent
+    #[clap(long)]
+    verify: bool,
+    /// The hash of a final block you trust to anchor the light client proof; it must be newer
+    /// than the block which includes the transaction (implies --verify)
+    #[clap(long)]
+    light_client_head: Option<near_primitives::hash::CryptoHash>,
     #[clap(subcommand)]
     submit: Option<Submit>,
 }
@@ -15,6 +22,8 @@ pub struct CliSignLedger {
 #[derive(Debug)]
 pub struct SignLedger {
     pub seed_phrase_hd_path: slip10::BIP32Path,
+    pub verify: bool,
+    pub light_client_head: Option<near_primitives::hash::CryptoHash>,
     pub submit: Option<Submit>,
 }
 
@@ -28,6 +37,8 @@ impl From<CliSignLedger> for SignLedger {
 
         Self {
             seed_phrase_hd_path,
+            verify: item.verify || item.light_client_head.is_some(),
+            light_client_head: item.light_client_head,
             submit,
         }
     }
@@ -70,6 +81,8 @@ impl SignLedger {
         };
 
         let submit: Option<Submit> = self.submit.clone();
+        let verify = self.verify;
+        let light_client_head = self.light_client_head.clone();
         match network_connection_config {
             None => {
                 let unsigned_transaction = near_primitives::transaction::Transaction {
@@ -193,27 +206,26 @@ impl SignLedger {
                     "\n\n---  Signed transaction:   ---\n    {:#?}",
                     &signed_transaction
                 );
-                match submit {
-                    None => {
-                        let submit = Submit::choose_submit();
-                        submit
-                            .process_online(
-                                network_connection_config,
-                                signed_transaction,
-                                serialize_to_base64,
-                            )
-                            .await
-                    }
-                    Some(submit) => {
-                        submit
-                            .process_online(
-                                network_connection_config,
-                                signed_transaction,
-                                serialize_to_base64,
-                            )
-                            .await
-                    }
+                let submit = match submit {
+                    None => Submit::choose_submit(),
+                    Some(submit) => submit,
+                };
+                let transaction_info = submit
+                    .process_online(
+                        network_connection_config.clone(),
+                        signed_transaction,
+                        serialize_to_base64,
+                    )
+                    .await?;
+                if let (true, Some(transaction_info)) = (verify, &transaction_info) {
+                    crate::common::verify_sent_transaction_outcome(
+                        &network_connection_config,
+                        transaction_info,
+                        light_client_head,
+                    )
+                    .await?;
                 }
+                Ok(transaction_info)
             }
         }
     }
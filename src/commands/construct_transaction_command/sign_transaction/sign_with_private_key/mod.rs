@@ -9,6 +9,13 @@ pub struct CliSignPrivateKey {
     signer_public_key: Option<near_crypto::PublicKey>,
     #[clap(long)]
     signer_secret_key: Option<near_crypto::SecretKey>,
+    /// Prove the outcome with the light client proof once the transaction is sent
+    #[clap(long)]
+    verify: bool,
+    /// The hash of a final block you trust to anchor the light client proof; it must be newer
+    /// than the block which includes the transaction (implies --verify)
+    #[clap(long)]
+    light_client_head: Option<near_primitives::hash::CryptoHash>,
     #[clap(subcommand)]
     submit: Option<Submit>,
 }
@@ -17,6 +24,8 @@ pub struct CliSignPrivateKey {
 pub struct SignPrivateKey {
     pub signer_public_key: near_crypto::PublicKey,
     pub signer_secret_key: near_crypto::SecretKey,
+    pub verify: bool,
+    pub light_client_head: Option<near_primitives::hash::CryptoHash>,
     pub submit: Option<Submit>,
 }
 
@@ -37,6 +46,8 @@ impl From<CliSignPrivateKey> for SignPrivateKey {
             Self {
                 signer_public_key,
                 signer_secret_key,
+                verify: item.verify || item.light_client_head.is_some(),
+                light_client_head: item.light_client_head,
                 submit,
             }
         } else {
@@ -46,6 +57,8 @@ impl From<CliSignPrivateKey> for SignPrivateKey {
             Self::from(CliSignPrivateKey {
                 signer_public_key: Some(signer_public_key),
                 signer_secret_key: Some(signer_secret_key),
+                verify: item.verify,
+                light_client_head: item.light_client_head,
                 submit: None,
             })
         }
@@ -79,6 +92,8 @@ impl SignPrivateKey {
         let public_key: near_crypto::PublicKey = self.signer_public_key.clone();
         let signer_secret_key: near_crypto::SecretKey = self.signer_secret_key.clone();
         let submit: Option<Submit> = self.submit.clone();
+        let verify = self.verify;
+        let light_client_head = self.light_client_head.clone();
         match network_connection_config {
             None => {
                 let unsigned_transaction = near_primitives::transaction::Transaction {
@@ -155,27 +170,26 @@ impl SignPrivateKey {
                     "\n\n---  Signed transaction:   ---\n    {:#?}",
                     &signed_transaction
                 );
-                match submit {
-                    None => {
-                        let submit = Submit::choose_submit();
-                        submit
-                            .process_online(
-                                network_connection_config,
-                                signed_transaction,
-                                serialize_to_base64,
-                            )
-                            .await
-                    }
-                    Some(submit) => {
-                        submit
-                            .process_online(
-                                network_connection_config,
-                                signed_transaction,
-                                serialize_to_base64,
-                            )
-                            .await
-                    }
+                let submit = match submit {
+                    None => Submit::choose_submit(),
+                    Some(submit) => submit,
+                };
+                let transaction_info = submit
+                    .process_online(
+                        network_connection_config.clone(),
+                        signed_transaction,
+                        serialize_to_base64,
+                    )
+                    .await?;
+                if let (true, Some(transaction_info)) = (verify, &transaction_info) {
+                    crate::common::verify_sent_transaction_outcome(
+                        &network_connection_config,
+                        transaction_info,
+                        light_client_head,
+                    )
+                    .await?;
                 }
+                Ok(transaction_info)
             }
         }
     }
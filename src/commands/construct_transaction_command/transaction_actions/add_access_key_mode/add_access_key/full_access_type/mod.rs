@@ -32,7 +32,7 @@ impl FullAccessType {
         prepopulated_unsigned_transaction: near_primitives::transaction::Transaction,
         network_connection_config: Option<crate::common::ConnectionConfig>,
         public_key: near_crypto::PublicKey,
-    ) -> crate::CliResult {
+    ) -> super::super::super::CollectedTransaction {
         let access_key: near_primitives::account::AccessKey = near_primitives::account::AccessKey {
             nonce,
             permission: near_primitives::account::AccessKeyPermission::FullAccess,
@@ -56,9 +56,7 @@ impl FullAccessType {
                     .await
             }
             super::super::super::NextAction::Skip(skip_action) => {
-                skip_action
-                    .process(unsigned_transaction, network_connection_config)
-                    .await
+                Ok((unsigned_transaction, skip_action))
             }
         }
     }
@@ -135,7 +135,7 @@ impl FunctionCallType {
         prepopulated_unsigned_transaction: near_primitives::transaction::Transaction,
         network_connection_config: Option<crate::common::ConnectionConfig>,
         public_key: near_crypto::PublicKey,
-    ) -> crate::CliResult {
+    ) -> super::super::super::CollectedTransaction {
         let access_key: near_primitives::account::AccessKey = near_primitives::account::AccessKey {
             nonce,
             permission: near_primitives::account::AccessKeyPermission::FunctionCall(
@@ -165,9 +165,7 @@ impl FunctionCallType {
                     .await
             }
             super::super::super::NextAction::Skip(skip_action) => {
-                skip_action
-                    .process(unsigned_transaction, network_connection_config)
-                    .await
+                Ok((unsigned_transaction, skip_action))
             }
         }
     }
@@ -64,7 +64,7 @@ impl AddAccessKeyAction {
         self,
         prepopulated_unsigned_transaction: near_primitives::transaction::Transaction,
         network_connection_config: Option<crate::common::ConnectionConfig>,
-    ) -> crate::CliResult {
+    ) -> super::super::CollectedTransaction {
         match self.permission {
             AccessKeyPermission::GrantFullAccess(full_access_type) => {
                 full_access_type
@@ -50,7 +50,7 @@ impl GenerateKeypair {
         self,
         prepopulated_unsigned_transaction: near_primitives::transaction::Transaction,
         network_connection_config: Option<crate::common::ConnectionConfig>,
-    ) -> crate::CliResult {
+    ) -> super::super::CollectedTransaction {
         let new_master_seed_phrase_words_count: usize = 12;
         let seed_phrase_hd_path = slip10::BIP32Path::from_str("m/44'/397'/0'").unwrap();
 
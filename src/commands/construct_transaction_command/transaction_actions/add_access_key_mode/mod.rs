@@ -31,7 +31,7 @@ impl AddAccessKeyMode {
         self,
         prepopulated_unsigned_transaction: near_primitives::transaction::Transaction,
         network_connection_config: Option<crate::common::ConnectionConfig>,
-    ) -> crate::CliResult {
+    ) -> super::CollectedTransaction {
         self.public_key_mode
             .process(prepopulated_unsigned_transaction, network_connection_config)
             .await
@@ -95,7 +95,7 @@ impl PublicKeyMode {
         self,
         prepopulated_unsigned_transaction: near_primitives::transaction::Transaction,
         network_connection_config: Option<crate::common::ConnectionConfig>,
-    ) -> crate::CliResult {
+    ) -> super::CollectedTransaction {
         match self {
             PublicKeyMode::PublicKey(add_access_key_action) => {
                 add_access_key_action
@@ -8,6 +8,8 @@ pub struct CliCallFunctionAction {
     method_name: Option<String>,
     #[clap(long)]
     args: Option<String>,
+    #[clap(flatten)]
+    function_args: crate::common::CliFunctionArgs,
     #[clap(long)]
     gas: Option<crate::common::NearGas>,
     #[clap(long)]
@@ -19,7 +21,7 @@ pub struct CliCallFunctionAction {
 #[derive(Debug)]
 pub struct CallFunctionAction {
     method_name: String,
-    args: Vec<u8>,
+    args: crate::common::FunctionArgs,
     gas: near_primitives::types::Gas,
     deposit: near_primitives::types::Balance,
     next_action: Box<super::NextAction>,
@@ -31,9 +33,12 @@ impl From<CliCallFunctionAction> for CallFunctionAction {
             Some(cli_method_name) => cli_method_name,
             None => CallFunctionAction::input_method_name(),
         };
-        let args: Vec<u8> = match item.args {
-            Some(cli_args) => cli_args.into_bytes(),
-            None => CallFunctionAction::input_args(),
+        let args = match item.function_args.into_args() {
+            Some(function_args) => function_args,
+            None => crate::common::FunctionArgs::Bytes(match item.args {
+                Some(cli_args) => cli_args.into_bytes(),
+                None => CallFunctionAction::input_args(),
+            }),
         };
         let gas: near_primitives::types::Gas = match item.gas {
             Some(cli_gas) => match cli_gas {
@@ -114,11 +119,11 @@ impl CallFunctionAction {
         self,
         prepopulated_unsigned_transaction: near_primitives::transaction::Transaction,
         network_connection_config: Option<crate::common::ConnectionConfig>,
-    ) -> crate::CliResult {
+    ) -> super::CollectedTransaction {
         let action = near_primitives::transaction::Action::FunctionCall(
             near_primitives::transaction::FunctionCallAction {
                 method_name: self.method_name.clone(),
-                args: self.args.clone(),
+                args: self.args.into_bytes()?,
                 gas: self.gas.clone(),
                 deposit: self.deposit.clone(),
             },
@@ -135,11 +140,7 @@ impl CallFunctionAction {
                     .process(unsigned_transaction, network_connection_config)
                     .await
             }
-            super::NextAction::Skip(skip_action) => {
-                skip_action
-                    .process(unsigned_transaction, network_connection_config)
-                    .await
-            }
+            super::NextAction::Skip(skip_action) => Ok((unsigned_transaction, skip_action)),
         }
     }
 }
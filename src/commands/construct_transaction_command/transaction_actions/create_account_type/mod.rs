@@ -30,7 +30,7 @@ impl CreateAccountAction {
         self,
         prepopulated_unsigned_transaction: near_primitives::transaction::Transaction,
         network_connection_config: Option<crate::common::ConnectionConfig>,
-    ) -> crate::CliResult {
+    ) -> super::CollectedTransaction {
         let action = near_primitives::transaction::Action::CreateAccount(
             near_primitives::transaction::CreateAccountAction {},
         );
@@ -46,11 +46,7 @@ impl CreateAccountAction {
                     .process(unsigned_transaction, network_connection_config)
                     .await
             }
-            super::NextAction::Skip(skip_action) => {
-                skip_action
-                    .process(unsigned_transaction, network_connection_config)
-                    .await
-            }
+            super::NextAction::Skip(skip_action) => Ok((unsigned_transaction, skip_action)),
         }
     }
 }
@@ -46,7 +46,7 @@ impl DeleteAccessKeyAction {
         self,
         prepopulated_unsigned_transaction: near_primitives::transaction::Transaction,
         network_connection_config: Option<crate::common::ConnectionConfig>,
-    ) -> crate::CliResult {
+    ) -> super::CollectedTransaction {
         let action = near_primitives::transaction::Action::DeleteKey(
             near_primitives::transaction::DeleteKeyAction {
                 public_key: self.public_key,
@@ -64,11 +64,7 @@ impl DeleteAccessKeyAction {
                     .process(unsigned_transaction, network_connection_config)
                     .await
             }
-            super::NextAction::Skip(skip_action) => {
-                skip_action
-                    .process(unsigned_transaction, network_connection_config)
-                    .await
-            }
+            super::NextAction::Skip(skip_action) => Ok((unsigned_transaction, skip_action)),
         }
     }
 }
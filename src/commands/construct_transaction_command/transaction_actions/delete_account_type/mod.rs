@@ -47,7 +47,7 @@ impl DeleteAccountAction {
         self,
         prepopulated_unsigned_transaction: near_primitives::transaction::Transaction,
         network_connection_config: Option<crate::common::ConnectionConfig>,
-    ) -> crate::CliResult {
+    ) -> super::CollectedTransaction {
         let beneficiary_id: near_primitives::types::AccountId = self.beneficiary_id.clone();
         let action = near_primitives::transaction::Action::DeleteAccount(
             near_primitives::transaction::DeleteAccountAction { beneficiary_id },
@@ -64,11 +64,7 @@ impl DeleteAccountAction {
                     .process(unsigned_transaction, network_connection_config)
                     .await
             }
-            super::NextAction::Skip(skip_action) => {
-                skip_action
-                    .process(unsigned_transaction, network_connection_config)
-                    .await
-            }
+            super::NextAction::Skip(skip_action) => Ok((unsigned_transaction, skip_action)),
         }
     }
 }
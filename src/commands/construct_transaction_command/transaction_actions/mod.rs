@@ -1,13 +1,13 @@
 use dialoguer::{theme::ColorfulTheme, Select};
 use strum::{EnumDiscriminants, EnumIter, EnumMessage, IntoEnumIterator};
 
-mod add_access_key_mode;
-mod call_function_type;
-mod create_account_type;
-mod delete_access_key_type;
+pub mod add_access_key_mode;
+pub mod call_function_type;
+pub mod create_account_type;
+pub mod delete_access_key_type;
 mod delete_account_type;
 mod stake_near_tokens_type;
-mod transfer_near_tokens_type;
+pub mod transfer_near_tokens_type;
 
 #[derive(Debug, clap::Clap)]
 pub enum CliNextAction {
@@ -66,6 +66,11 @@ impl From<CliSkipNextAction> for CliNextAction {
     }
 }
 
+/// The transaction with the collected actions and the step which signs it; the actions are
+/// only collected, so that the caller decides how the transaction is sent.
+pub type CollectedTransaction =
+    color_eyre::eyre::Result<(near_primitives::transaction::Transaction, SkipAction)>;
+
 impl NextAction {
     pub fn input_next_action() -> Self {
         println!();
@@ -91,18 +96,14 @@ impl NextAction {
         self,
         prepopulated_unsigned_transaction: near_primitives::transaction::Transaction,
         network_connection_config: Option<crate::common::ConnectionConfig>,
-    ) -> crate::CliResult {
+    ) -> CollectedTransaction {
         match self {
             NextAction::AddAction(select_action) => {
                 select_action
                     .process(prepopulated_unsigned_transaction, network_connection_config)
                     .await
             }
-            NextAction::Skip(skip_action) => {
-                skip_action
-                    .process(prepopulated_unsigned_transaction, network_connection_config)
-                    .await
-            }
+            NextAction::Skip(skip_action) => Ok((prepopulated_unsigned_transaction, skip_action)),
         }
     }
 }
@@ -136,7 +137,7 @@ impl SelectAction {
         self,
         prepopulated_unsigned_transaction: near_primitives::transaction::Transaction,
         network_connection_config: Option<crate::common::ConnectionConfig>,
-    ) -> crate::CliResult {
+    ) -> CollectedTransaction {
         self.transaction_subcommand
             .process(prepopulated_unsigned_transaction, network_connection_config)
             .await
@@ -252,7 +253,7 @@ impl ActionSubcommand {
         self,
         prepopulated_unsigned_transaction: near_primitives::transaction::Transaction,
         network_connection_config: Option<crate::common::ConnectionConfig>,
-    ) -> crate::CliResult {
+    ) -> CollectedTransaction {
         match self {
             ActionSubcommand::TransferNEARTokens(args_transfer) => {
                 args_transfer
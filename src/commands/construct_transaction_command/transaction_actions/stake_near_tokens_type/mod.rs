@@ -59,7 +59,7 @@ impl StakeNEARTokensAction {
         self,
         mut prepopulated_unsigned_transaction: near_primitives::transaction::Transaction,
         network_connection_config: Option<crate::common::ConnectionConfig>,
-    ) -> crate::CliResult {
+    ) -> super::CollectedTransaction {
         let action = near_primitives::transaction::Action::Stake(
             near_primitives::transaction::StakeAction {
                 stake: self.stake.to_yoctonear(),
@@ -74,9 +74,7 @@ impl StakeNEARTokensAction {
                     .await
             }
             super::NextAction::Skip(skip_action) => {
-                skip_action
-                    .process(prepopulated_unsigned_transaction, network_connection_config)
-                    .await
+                Ok((prepopulated_unsigned_transaction, skip_action))
             }
         }
     }
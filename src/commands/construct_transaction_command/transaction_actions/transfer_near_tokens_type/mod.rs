@@ -45,7 +45,7 @@ impl TransferNEARTokensAction {
         self,
         mut prepopulated_unsigned_transaction: near_primitives::transaction::Transaction,
         network_connection_config: Option<crate::common::ConnectionConfig>,
-    ) -> crate::CliResult {
+    ) -> super::CollectedTransaction {
         let action = near_primitives::transaction::Action::Transfer(
             near_primitives::transaction::TransferAction {
                 deposit: self.amount.to_yoctonear(),
@@ -59,9 +59,7 @@ impl TransferNEARTokensAction {
                     .await
             }
             super::NextAction::Skip(skip_action) => {
-                skip_action
-                    .process(prepopulated_unsigned_transaction, network_connection_config)
-                    .await
+                Ok((prepopulated_unsigned_transaction, skip_action))
             }
         }
     }
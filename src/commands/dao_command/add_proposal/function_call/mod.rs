@@ -0,0 +1,125 @@
+use dialoguer::Input;
+
+const DEFAULT_PROPOSAL_FUNCTION_CALL_GAS: near_primitives::types::Gas = 100_000_000_000_000;
+
+/// Specify the function call
+#[derive(Debug, Default, clap::Clap)]
+pub struct CliFunctionCallProposal {
+    receiver_account_id: Option<String>,
+    method_name: Option<String>,
+    /// The arguments as JSON
+    args: Option<crate::common::FunctionArgsJson>,
+    #[clap(flatten)]
+    function_args: crate::common::CliFunctionArgs,
+    /// The deposit attached to the call
+    #[clap(long)]
+    deposit: Option<crate::common::NearBalance>,
+    /// Gas for the call (100 TeraGas by default)
+    #[clap(long = "prepaid-gas")]
+    gas: Option<crate::common::NearGas>,
+    #[clap(subcommand)]
+    sign_option: Option<
+        crate::commands::construct_transaction_command::sign_transaction::CliSignTransaction,
+    >,
+}
+
+#[derive(Debug)]
+pub struct FunctionCallProposal {
+    receiver_account_id: String,
+    method_name: String,
+    args: crate::common::FunctionArgs,
+    deposit: near_primitives::types::Balance,
+    gas: near_primitives::types::Gas,
+    sign_option: crate::commands::construct_transaction_command::sign_transaction::SignTransaction,
+}
+
+impl From<CliFunctionCallProposal> for FunctionCallProposal {
+    fn from(item: CliFunctionCallProposal) -> Self {
+        let receiver_account_id: String = match item.receiver_account_id {
+            Some(cli_receiver_account_id) => cli_receiver_account_id,
+            None => FunctionCallProposal::input_receiver_account_id(),
+        };
+        let method_name: String = match item.method_name {
+            Some(cli_method_name) => cli_method_name,
+            None => FunctionCallProposal::input_method_name(),
+        };
+        let args = match item.function_args.into_args() {
+            Some(function_args) => function_args,
+            None => crate::common::FunctionArgs::Bytes(match item.args {
+                Some(cli_args) => cli_args.inner,
+                None => FunctionCallProposal::input_args(),
+            }),
+        };
+        let sign_option = match item.sign_option {
+            Some(cli_sign_transaction) => cli_sign_transaction.into(),
+            None => crate::commands::construct_transaction_command::sign_transaction::SignTransaction::choose_sign_option(),
+        };
+        Self {
+            receiver_account_id,
+            method_name,
+            args,
+            deposit: item
+                .deposit
+                .map(|deposit| deposit.to_yoctonear())
+                .unwrap_or_default(),
+            gas: item
+                .gas
+                .map(|gas| gas.inner)
+                .unwrap_or(DEFAULT_PROPOSAL_FUNCTION_CALL_GAS),
+            sign_option,
+        }
+    }
+}
+
+impl FunctionCallProposal {
+    fn input_receiver_account_id() -> String {
+        println!();
+        Input::new()
+            .with_prompt("What is the account ID of the contract to call?")
+            .interact_text()
+            .unwrap()
+    }
+
+    fn input_method_name() -> String {
+        println!();
+        Input::new()
+            .with_prompt("What is the name of the method?")
+            .interact_text()
+            .unwrap()
+    }
+
+    fn input_args() -> Vec<u8> {
+        println!();
+        let args: crate::common::FunctionArgsJson = Input::new()
+            .with_prompt("Enter the arguments as JSON")
+            .with_initial_text("{}")
+            .interact_text()
+            .unwrap();
+        args.inner
+    }
+
+    pub async fn process(
+        self,
+        prepopulated_unsigned_transaction: near_primitives::transaction::Transaction,
+        network_connection_config: crate::common::ConnectionConfig,
+        description: String,
+    ) -> crate::CliResult {
+        let args = self.args.into_bytes()?;
+        super::add_proposal(
+            self.sign_option,
+            prepopulated_unsigned_transaction,
+            network_connection_config,
+            description,
+            super::super::DaoProposalKind::FunctionCall {
+                receiver_id: self.receiver_account_id,
+                actions: vec![super::super::DaoActionCall {
+                    method_name: self.method_name,
+                    args: near_primitives::serialize::to_base64(&args),
+                    deposit: self.deposit,
+                    gas: self.gas,
+                }],
+            },
+        )
+        .await
+    }
+}
@@ -0,0 +1,76 @@
+use dialoguer::Input;
+
+/// Specify the member and the role
+#[derive(Debug, Default, clap::Clap)]
+pub struct CliMemberProposal {
+    member_account_id: Option<String>,
+    role: Option<String>,
+    #[clap(subcommand)]
+    sign_option: Option<
+        crate::commands::construct_transaction_command::sign_transaction::CliSignTransaction,
+    >,
+}
+
+#[derive(Debug)]
+pub struct MemberProposal {
+    member_account_id: String,
+    role: String,
+    sign_option: crate::commands::construct_transaction_command::sign_transaction::SignTransaction,
+}
+
+impl From<CliMemberProposal> for MemberProposal {
+    fn from(item: CliMemberProposal) -> Self {
+        let member_account_id: String = match item.member_account_id {
+            Some(cli_member_account_id) => cli_member_account_id,
+            None => MemberProposal::input_member_account_id(),
+        };
+        let role: String = match item.role {
+            Some(cli_role) => cli_role,
+            None => MemberProposal::input_role(),
+        };
+        let sign_option = match item.sign_option {
+            Some(cli_sign_transaction) => cli_sign_transaction.into(),
+            None => crate::commands::construct_transaction_command::sign_transaction::SignTransaction::choose_sign_option(),
+        };
+        Self {
+            member_account_id,
+            role,
+            sign_option,
+        }
+    }
+}
+
+impl MemberProposal {
+    fn input_member_account_id() -> String {
+        println!();
+        Input::new()
+            .with_prompt("What is the account ID of the member?")
+            .interact_text()
+            .unwrap()
+    }
+
+    fn input_role() -> String {
+        println!();
+        Input::new()
+            .with_prompt("What is the role? (example: council)")
+            .interact_text()
+            .unwrap()
+    }
+
+    pub async fn process(
+        self,
+        prepopulated_unsigned_transaction: near_primitives::transaction::Transaction,
+        network_connection_config: crate::common::ConnectionConfig,
+        description: String,
+        proposal_kind: fn(String, String) -> super::super::DaoProposalKind,
+    ) -> crate::CliResult {
+        super::add_proposal(
+            self.sign_option,
+            prepopulated_unsigned_transaction,
+            network_connection_config,
+            description,
+            proposal_kind(self.member_account_id, self.role),
+        )
+        .await
+    }
+}
@@ -0,0 +1,283 @@
+use dialoguer::{theme::ColorfulTheme, Input, Select};
+use strum::{EnumDiscriminants, EnumIter, EnumMessage, IntoEnumIterator};
+
+mod function_call;
+mod member;
+mod transfer;
+mod upgrade_self;
+
+/// Specify the proposer and the description of the proposal
+#[derive(Debug, Default, clap::Clap)]
+pub struct CliAddProposal {
+    proposer_account_id: Option<String>,
+    /// The description of the proposal
+    #[clap(long)]
+    description: Option<String>,
+    #[clap(subcommand)]
+    proposal_kind: Option<CliProposalKind>,
+}
+
+#[derive(Debug)]
+pub struct AddProposal {
+    proposer_account_id: String,
+    description: String,
+    proposal_kind: ProposalKind,
+}
+
+impl From<CliAddProposal> for AddProposal {
+    fn from(item: CliAddProposal) -> Self {
+        let proposer_account_id: String = match item.proposer_account_id {
+            Some(cli_proposer_account_id) => cli_proposer_account_id,
+            None => AddProposal::input_proposer_account_id(),
+        };
+        let description: String = match item.description {
+            Some(cli_description) => cli_description,
+            None => AddProposal::input_description(),
+        };
+        let proposal_kind = match item.proposal_kind {
+            Some(cli_proposal_kind) => ProposalKind::from(cli_proposal_kind),
+            None => ProposalKind::choose_proposal_kind(),
+        };
+        Self {
+            proposer_account_id,
+            description,
+            proposal_kind,
+        }
+    }
+}
+
+impl AddProposal {
+    fn input_proposer_account_id() -> String {
+        println!();
+        Input::new()
+            .with_prompt("What is the account ID of the proposer?")
+            .interact_text()
+            .unwrap()
+    }
+
+    fn input_description() -> String {
+        println!();
+        Input::new()
+            .with_prompt("What is the description of the proposal?")
+            .interact_text()
+            .unwrap()
+    }
+
+    pub async fn process(
+        self,
+        prepopulated_unsigned_transaction: near_primitives::transaction::Transaction,
+        network_connection_config: crate::common::ConnectionConfig,
+    ) -> crate::CliResult {
+        let unsigned_transaction = near_primitives::transaction::Transaction {
+            signer_id: self.proposer_account_id.clone(),
+            ..prepopulated_unsigned_transaction
+        };
+        self.proposal_kind
+            .process(
+                unsigned_transaction,
+                network_connection_config,
+                self.description,
+            )
+            .await
+    }
+}
+
+#[derive(Debug, clap::Clap)]
+pub enum CliProposalKind {
+    /// Propose to transfer NEAR tokens from the DAO
+    Transfer(self::transfer::CliTransferProposal),
+    /// Propose to call a function from the DAO
+    FunctionCall(self::function_call::CliFunctionCallProposal),
+    /// Propose to add a member to a role
+    AddMember(self::member::CliMemberProposal),
+    /// Propose to remove a member from a role
+    RemoveMember(self::member::CliMemberProposal),
+    /// Propose to upgrade the DAO contract to a code stored in it with `store_blob`
+    UpgradeSelf(self::upgrade_self::CliUpgradeSelfProposal),
+}
+
+#[derive(Debug, EnumDiscriminants)]
+#[strum_discriminants(derive(EnumMessage, EnumIter))]
+pub enum ProposalKind {
+    #[strum_discriminants(strum(message = "Transfer NEAR tokens"))]
+    Transfer(self::transfer::TransferProposal),
+    #[strum_discriminants(strum(message = "Call a function"))]
+    FunctionCall(self::function_call::FunctionCallProposal),
+    #[strum_discriminants(strum(message = "Add a member to a role"))]
+    AddMember(self::member::MemberProposal),
+    #[strum_discriminants(strum(message = "Remove a member from a role"))]
+    RemoveMember(self::member::MemberProposal),
+    #[strum_discriminants(strum(message = "Upgrade the DAO contract"))]
+    UpgradeSelf(self::upgrade_self::UpgradeSelfProposal),
+}
+
+impl From<CliProposalKind> for ProposalKind {
+    fn from(item: CliProposalKind) -> Self {
+        match item {
+            CliProposalKind::Transfer(cli_transfer_proposal) => {
+                Self::Transfer(cli_transfer_proposal.into())
+            }
+            CliProposalKind::FunctionCall(cli_function_call_proposal) => {
+                Self::FunctionCall(cli_function_call_proposal.into())
+            }
+            CliProposalKind::AddMember(cli_member_proposal) => {
+                Self::AddMember(cli_member_proposal.into())
+            }
+            CliProposalKind::RemoveMember(cli_member_proposal) => {
+                Self::RemoveMember(cli_member_proposal.into())
+            }
+            CliProposalKind::UpgradeSelf(cli_upgrade_self_proposal) => {
+                Self::UpgradeSelf(cli_upgrade_self_proposal.into())
+            }
+        }
+    }
+}
+
+impl ProposalKind {
+    fn choose_proposal_kind() -> Self {
+        println!();
+        let variants = ProposalKindDiscriminants::iter().collect::<Vec<_>>();
+        let kinds = variants
+            .iter()
+            .map(|p| p.get_message().unwrap().to_owned())
+            .collect::<Vec<_>>();
+        let selected_kind = Select::with_theme(&ColorfulTheme::default())
+            .with_prompt("What do you want to propose?")
+            .items(&kinds)
+            .default(0)
+            .interact()
+            .unwrap();
+        let cli_proposal_kind = match variants[selected_kind] {
+            ProposalKindDiscriminants::Transfer => CliProposalKind::Transfer(Default::default()),
+            ProposalKindDiscriminants::FunctionCall => {
+                CliProposalKind::FunctionCall(Default::default())
+            }
+            ProposalKindDiscriminants::AddMember => CliProposalKind::AddMember(Default::default()),
+            ProposalKindDiscriminants::RemoveMember => {
+                CliProposalKind::RemoveMember(Default::default())
+            }
+            ProposalKindDiscriminants::UpgradeSelf => {
+                CliProposalKind::UpgradeSelf(Default::default())
+            }
+        };
+        Self::from(cli_proposal_kind)
+    }
+
+    async fn process(
+        self,
+        prepopulated_unsigned_transaction: near_primitives::transaction::Transaction,
+        network_connection_config: crate::common::ConnectionConfig,
+        description: String,
+    ) -> crate::CliResult {
+        match self {
+            ProposalKind::Transfer(transfer_proposal) => {
+                transfer_proposal
+                    .process(
+                        prepopulated_unsigned_transaction,
+                        network_connection_config,
+                        description,
+                    )
+                    .await
+            }
+            ProposalKind::FunctionCall(function_call_proposal) => {
+                function_call_proposal
+                    .process(
+                        prepopulated_unsigned_transaction,
+                        network_connection_config,
+                        description,
+                    )
+                    .await
+            }
+            ProposalKind::AddMember(member_proposal) => {
+                member_proposal
+                    .process(
+                        prepopulated_unsigned_transaction,
+                        network_connection_config,
+                        description,
+                        |member_id, role| super::DaoProposalKind::AddMemberToRole {
+                            member_id,
+                            role,
+                        },
+                    )
+                    .await
+            }
+            ProposalKind::RemoveMember(member_proposal) => {
+                member_proposal
+                    .process(
+                        prepopulated_unsigned_transaction,
+                        network_connection_config,
+                        description,
+                        |member_id, role| super::DaoProposalKind::RemoveMemberFromRole {
+                            member_id,
+                            role,
+                        },
+                    )
+                    .await
+            }
+            ProposalKind::UpgradeSelf(upgrade_self_proposal) => {
+                upgrade_self_proposal
+                    .process(
+                        prepopulated_unsigned_transaction,
+                        network_connection_config,
+                        description,
+                    )
+                    .await
+            }
+        }
+    }
+}
+
+/// Adds the proposal with the bond the DAO policy requires attached.
+async fn add_proposal(
+    sign_option: crate::commands::construct_transaction_command::sign_transaction::SignTransaction,
+    prepopulated_unsigned_transaction: near_primitives::transaction::Transaction,
+    network_connection_config: crate::common::ConnectionConfig,
+    description: String,
+    kind: super::DaoProposalKind,
+) -> crate::CliResult {
+    let policy = super::DaoPolicy::fetch(
+        &network_connection_config,
+        &prepopulated_unsigned_transaction.receiver_id,
+    )
+    .await?;
+    match &kind {
+        super::DaoProposalKind::AddMemberToRole { role, .. }
+        | super::DaoProposalKind::RemoveMemberFromRole { role, .. }
+            if !policy
+                .roles
+                .iter()
+                .any(|policy_role| &policy_role.name == role) =>
+        {
+            return Err(color_eyre::Report::msg(format!(
+                "The DAO has no role {:?}, its roles are: {}",
+                role,
+                policy
+                    .roles
+                    .iter()
+                    .map(|policy_role| format!("{:?}", policy_role.name))
+                    .collect::<Vec<_>>()
+                    .join(", ")
+            )));
+        }
+        _ => {}
+    }
+    println!(
+        "\nThe proposal bond of {} is attached; it is returned when the proposal is approved or rejected.",
+        crate::common::NearBalance::from_yoctonear(policy.proposal_bond)
+    );
+    sign_option
+        .process_function_call(
+            prepopulated_unsigned_transaction,
+            network_connection_config,
+            "add_proposal",
+            serde_json::json!({
+                "proposal": {
+                    "description": description,
+                    "kind": kind,
+                }
+            }),
+            super::DAO_GAS,
+            policy.proposal_bond,
+        )
+        .await
+}
@@ -0,0 +1,81 @@
+use dialoguer::Input;
+
+/// Specify the receiver and the amount
+#[derive(Debug, Default, clap::Clap)]
+pub struct CliTransferProposal {
+    receiver_account_id: Option<String>,
+    amount: Option<crate::common::NearBalance>,
+    #[clap(subcommand)]
+    sign_option: Option<
+        crate::commands::construct_transaction_command::sign_transaction::CliSignTransaction,
+    >,
+}
+
+#[derive(Debug)]
+pub struct TransferProposal {
+    receiver_account_id: String,
+    amount: near_primitives::types::Balance,
+    sign_option: crate::commands::construct_transaction_command::sign_transaction::SignTransaction,
+}
+
+impl From<CliTransferProposal> for TransferProposal {
+    fn from(item: CliTransferProposal) -> Self {
+        let receiver_account_id: String = match item.receiver_account_id {
+            Some(cli_receiver_account_id) => cli_receiver_account_id,
+            None => TransferProposal::input_receiver_account_id(),
+        };
+        let amount: near_primitives::types::Balance = match item.amount {
+            Some(cli_amount) => cli_amount.to_yoctonear(),
+            None => TransferProposal::input_amount(),
+        };
+        let sign_option = match item.sign_option {
+            Some(cli_sign_transaction) => cli_sign_transaction.into(),
+            None => crate::commands::construct_transaction_command::sign_transaction::SignTransaction::choose_sign_option(),
+        };
+        Self {
+            receiver_account_id,
+            amount,
+            sign_option,
+        }
+    }
+}
+
+impl TransferProposal {
+    fn input_receiver_account_id() -> String {
+        println!();
+        Input::new()
+            .with_prompt("What is the account ID of the receiver?")
+            .interact_text()
+            .unwrap()
+    }
+
+    fn input_amount() -> near_primitives::types::Balance {
+        println!();
+        let amount: crate::common::NearBalance = Input::new()
+            .with_prompt("How many NEAR Tokens do you want to propose to transfer? (example: 10NEAR or 0.5near or 10000yoctonear)")
+            .interact_text()
+            .unwrap();
+        amount.to_yoctonear()
+    }
+
+    pub async fn process(
+        self,
+        prepopulated_unsigned_transaction: near_primitives::transaction::Transaction,
+        network_connection_config: crate::common::ConnectionConfig,
+        description: String,
+    ) -> crate::CliResult {
+        super::add_proposal(
+            self.sign_option,
+            prepopulated_unsigned_transaction,
+            network_connection_config,
+            description,
+            super::super::DaoProposalKind::Transfer {
+                token_id: "".to_string(),
+                receiver_id: self.receiver_account_id,
+                amount: self.amount,
+                msg: None,
+            },
+        )
+        .await
+    }
+}
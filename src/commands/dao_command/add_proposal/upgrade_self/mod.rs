@@ -0,0 +1,62 @@
+use dialoguer::Input;
+
+/// Specify the hash of the code stored in the DAO with `store_blob`
+#[derive(Debug, Default, clap::Clap)]
+pub struct CliUpgradeSelfProposal {
+    code_hash: Option<near_primitives::hash::CryptoHash>,
+    #[clap(subcommand)]
+    sign_option: Option<
+        crate::commands::construct_transaction_command::sign_transaction::CliSignTransaction,
+    >,
+}
+
+#[derive(Debug)]
+pub struct UpgradeSelfProposal {
+    code_hash: near_primitives::hash::CryptoHash,
+    sign_option: crate::commands::construct_transaction_command::sign_transaction::SignTransaction,
+}
+
+impl From<CliUpgradeSelfProposal> for UpgradeSelfProposal {
+    fn from(item: CliUpgradeSelfProposal) -> Self {
+        let code_hash: near_primitives::hash::CryptoHash = match item.code_hash {
+            Some(cli_code_hash) => cli_code_hash,
+            None => UpgradeSelfProposal::input_code_hash(),
+        };
+        let sign_option = match item.sign_option {
+            Some(cli_sign_transaction) => cli_sign_transaction.into(),
+            None => crate::commands::construct_transaction_command::sign_transaction::SignTransaction::choose_sign_option(),
+        };
+        Self {
+            code_hash,
+            sign_option,
+        }
+    }
+}
+
+impl UpgradeSelfProposal {
+    fn input_code_hash() -> near_primitives::hash::CryptoHash {
+        println!();
+        Input::new()
+            .with_prompt("What is the hash of the new code stored in the DAO?")
+            .interact_text()
+            .unwrap()
+    }
+
+    pub async fn process(
+        self,
+        prepopulated_unsigned_transaction: near_primitives::transaction::Transaction,
+        network_connection_config: crate::common::ConnectionConfig,
+        description: String,
+    ) -> crate::CliResult {
+        super::add_proposal(
+            self.sign_option,
+            prepopulated_unsigned_transaction,
+            network_connection_config,
+            description,
+            super::super::DaoProposalKind::UpgradeSelf {
+                hash: self.code_hash,
+            },
+        )
+        .await
+    }
+}
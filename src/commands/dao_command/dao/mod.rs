@@ -0,0 +1,97 @@
+use dialoguer::Input;
+
+#[derive(Debug, clap::Clap)]
+pub enum CliSendTo {
+    /// Specify the DAO account
+    Dao(CliDao),
+}
+
+#[derive(Debug)]
+pub enum SendTo {
+    Dao(Dao),
+}
+
+impl From<CliSendTo> for SendTo {
+    fn from(item: CliSendTo) -> Self {
+        match item {
+            CliSendTo::Dao(cli_dao) => {
+                let dao = Dao::from(cli_dao);
+                Self::Dao(dao)
+            }
+        }
+    }
+}
+
+impl SendTo {
+    pub fn send_to() -> Self {
+        Self::from(CliSendTo::Dao(Default::default()))
+    }
+
+    pub async fn process(
+        self,
+        prepopulated_unsigned_transaction: near_primitives::transaction::Transaction,
+        network_connection_config: crate::common::ConnectionConfig,
+    ) -> crate::CliResult {
+        match self {
+            SendTo::Dao(dao) => {
+                dao.process(prepopulated_unsigned_transaction, network_connection_config)
+                    .await
+            }
+        }
+    }
+}
+
+/// данные о контракте Sputnik DAO
+#[derive(Debug, Default, clap::Clap)]
+pub struct CliDao {
+    dao_account_id: Option<String>,
+    #[clap(subcommand)]
+    dao_action: Option<super::CliDaoAction>,
+}
+
+#[derive(Debug)]
+pub struct Dao {
+    pub dao_account_id: String,
+    pub dao_action: super::DaoAction,
+}
+
+impl From<CliDao> for Dao {
+    fn from(item: CliDao) -> Self {
+        let dao_account_id: String = match item.dao_account_id {
+            Some(cli_dao_account_id) => cli_dao_account_id,
+            None => Dao::input_dao_account_id(),
+        };
+        let dao_action = match item.dao_action {
+            Some(cli_dao_action) => super::DaoAction::from(cli_dao_action),
+            None => super::DaoAction::choose_dao_action(),
+        };
+        Self {
+            dao_account_id,
+            dao_action,
+        }
+    }
+}
+
+impl Dao {
+    pub fn input_dao_account_id() -> String {
+        println!();
+        Input::new()
+            .with_prompt("What is the account ID of the DAO?")
+            .interact_text()
+            .unwrap()
+    }
+
+    pub async fn process(
+        self,
+        prepopulated_unsigned_transaction: near_primitives::transaction::Transaction,
+        network_connection_config: crate::common::ConnectionConfig,
+    ) -> crate::CliResult {
+        let unsigned_transaction = near_primitives::transaction::Transaction {
+            receiver_id: self.dao_account_id.clone(),
+            ..prepopulated_unsigned_transaction
+        };
+        self.dao_action
+            .process(unsigned_transaction, network_connection_config)
+            .await
+    }
+}
@@ -0,0 +1,347 @@
+use dialoguer::{theme::ColorfulTheme, Select};
+use strum::{EnumDiscriminants, EnumIter, EnumMessage, IntoEnumIterator};
+
+mod add_proposal;
+mod dao;
+pub mod operation_mode;
+mod view_proposals;
+mod vote;
+
+/// The gas attached to the calls to the DAO; the vote that approves a proposal executes it, so
+/// the proposal is paid from it as well.
+const DAO_GAS: near_primitives::types::Gas = 200_000_000_000_000;
+
+#[derive(Debug, clap::Clap)]
+pub enum CliDaoAction {
+    /// View the proposals with their status and votes
+    ViewProposals(self::view_proposals::CliViewProposals),
+    /// Add a proposal, paying the proposal bond from the DAO policy
+    AddProposal(self::add_proposal::CliAddProposal),
+    /// Vote to approve, reject or remove a proposal
+    Vote(self::vote::CliVote),
+}
+
+#[derive(Debug, EnumDiscriminants)]
+#[strum_discriminants(derive(EnumMessage, EnumIter))]
+pub enum DaoAction {
+    #[strum_discriminants(strum(message = "View the proposals"))]
+    ViewProposals(self::view_proposals::ViewProposals),
+    #[strum_discriminants(strum(message = "Add a proposal"))]
+    AddProposal(self::add_proposal::AddProposal),
+    #[strum_discriminants(strum(message = "Vote on a proposal"))]
+    Vote(self::vote::Vote),
+}
+
+impl From<CliDaoAction> for DaoAction {
+    fn from(item: CliDaoAction) -> Self {
+        match item {
+            CliDaoAction::ViewProposals(cli_view_proposals) => {
+                Self::ViewProposals(cli_view_proposals.into())
+            }
+            CliDaoAction::AddProposal(cli_add_proposal) => {
+                Self::AddProposal(cli_add_proposal.into())
+            }
+            CliDaoAction::Vote(cli_vote) => Self::Vote(cli_vote.into()),
+        }
+    }
+}
+
+impl DaoAction {
+    fn choose_dao_action() -> Self {
+        println!();
+        let variants = DaoActionDiscriminants::iter().collect::<Vec<_>>();
+        let actions = variants
+            .iter()
+            .map(|p| p.get_message().unwrap().to_owned())
+            .collect::<Vec<_>>();
+        let selected_action = Select::with_theme(&ColorfulTheme::default())
+            .with_prompt("What do you want to do with the DAO?")
+            .items(&actions)
+            .default(0)
+            .interact()
+            .unwrap();
+        let cli_dao_action = match variants[selected_action] {
+            DaoActionDiscriminants::ViewProposals => {
+                CliDaoAction::ViewProposals(Default::default())
+            }
+            DaoActionDiscriminants::AddProposal => CliDaoAction::AddProposal(Default::default()),
+            DaoActionDiscriminants::Vote => CliDaoAction::Vote(Default::default()),
+        };
+        Self::from(cli_dao_action)
+    }
+
+    async fn process(
+        self,
+        prepopulated_unsigned_transaction: near_primitives::transaction::Transaction,
+        network_connection_config: crate::common::ConnectionConfig,
+    ) -> crate::CliResult {
+        match self {
+            DaoAction::ViewProposals(view_proposals) => {
+                view_proposals
+                    .process(prepopulated_unsigned_transaction, network_connection_config)
+                    .await
+            }
+            DaoAction::AddProposal(add_proposal) => {
+                add_proposal
+                    .process(prepopulated_unsigned_transaction, network_connection_config)
+                    .await
+            }
+            DaoAction::Vote(vote) => {
+                vote.process(prepopulated_unsigned_transaction, network_connection_config)
+                    .await
+            }
+        }
+    }
+}
+
+fn print_proposal(proposal: &DaoProposal) {
+    println!(
+        "\nProposal #{} by <{}>: {}\n  {}\n  status: {}",
+        proposal.id,
+        proposal.proposer,
+        dao_proposal_kind_summary(&proposal.kind),
+        proposal.description,
+        proposal.status
+    );
+    for (role, vote_counts) in proposal.vote_counts.iter() {
+        println!(
+            "  votes of {:?} (approve, reject, remove): {}",
+            role, vote_counts
+        );
+    }
+    for (voter, vote) in proposal.votes.iter() {
+        println!("  <{}> voted {}", voter, vote);
+    }
+}
+
+/// The policy of a Sputnik DAO as returned by `get_policy`.
+#[derive(Debug, Clone, serde::Deserialize)]
+pub struct DaoPolicy {
+    pub roles: Vec<DaoRole>,
+    #[serde(with = "near_primitives::serialize::u128_dec_format")]
+    pub proposal_bond: u128,
+}
+
+#[derive(Debug, Clone, serde::Deserialize)]
+pub struct DaoRole {
+    pub name: String,
+}
+
+impl DaoPolicy {
+    pub async fn fetch(
+        network_connection_config: &crate::common::ConnectionConfig,
+        dao_account_id: &str,
+    ) -> color_eyre::eyre::Result<Self> {
+        crate::common::call_view_function(
+            network_connection_config,
+            dao_account_id,
+            "get_policy",
+            serde_json::json!({}),
+        )
+        .await
+    }
+}
+
+/// A proposal of a Sputnik DAO as returned by `get_proposal` and `get_proposals`.
+#[derive(Debug, Clone, serde::Deserialize)]
+pub struct DaoProposal {
+    pub id: u64,
+    pub proposer: String,
+    pub description: String,
+    pub kind: serde_json::Value,
+    pub status: String,
+    #[serde(default)]
+    pub vote_counts: std::collections::BTreeMap<String, serde_json::Value>,
+    #[serde(default)]
+    pub votes: std::collections::BTreeMap<String, String>,
+}
+
+impl DaoProposal {
+    pub async fn fetch(
+        network_connection_config: &crate::common::ConnectionConfig,
+        dao_account_id: &str,
+        proposal_id: u64,
+    ) -> color_eyre::eyre::Result<Self> {
+        crate::common::call_view_function(
+            network_connection_config,
+            dao_account_id,
+            "get_proposal",
+            serde_json::json!({ "id": proposal_id }),
+        )
+        .await
+    }
+}
+
+/// The kind of a Sputnik DAO proposal, serialized as the `kind` argument of `add_proposal`.
+#[derive(Debug, Clone, PartialEq, serde::Serialize)]
+pub enum DaoProposalKind {
+    Transfer {
+        /// The fungible token contract, empty for NEAR
+        token_id: String,
+        receiver_id: String,
+        #[serde(with = "near_primitives::serialize::u128_dec_format")]
+        amount: u128,
+        msg: Option<String>,
+    },
+    FunctionCall {
+        receiver_id: String,
+        actions: Vec<DaoActionCall>,
+    },
+    AddMemberToRole {
+        member_id: String,
+        role: String,
+    },
+    RemoveMemberFromRole {
+        member_id: String,
+        role: String,
+    },
+    UpgradeSelf {
+        hash: near_primitives::hash::CryptoHash,
+    },
+}
+
+/// A function call of a `FunctionCall` proposal.
+#[derive(Debug, Clone, PartialEq, serde::Serialize)]
+pub struct DaoActionCall {
+    pub method_name: String,
+    /// The arguments in base64
+    pub args: String,
+    #[serde(with = "near_primitives::serialize::u128_dec_format")]
+    pub deposit: u128,
+    #[serde(serialize_with = "serialize_u64_as_string")]
+    pub gas: near_primitives::types::Gas,
+}
+
+fn serialize_u64_as_string<S: serde::Serializer>(
+    value: &u64,
+    serializer: S,
+) -> Result<S::Ok, S::Error> {
+    serializer.serialize_str(&value.to_string())
+}
+
+/// Describes the kind of a Sputnik DAO proposal as returned by the views.
+fn dao_proposal_kind_summary(kind: &serde_json::Value) -> String {
+    let (name, details) = match kind {
+        serde_json::Value::String(name) => return name.to_string(),
+        serde_json::Value::Object(kind) => match kind.iter().next() {
+            Some(kind) => kind,
+            None => return "unknown".to_string(),
+        },
+        kind => return kind.to_string(),
+    };
+    let str_field = |field: &str| details[field].as_str().unwrap_or_default().to_string();
+    match name.as_str() {
+        "Transfer" => {
+            let amount = details["amount"].as_str().unwrap_or_default();
+            match details["token_id"].as_str() {
+                Some("") | None => {
+                    format!(
+                        "transfer {} to <{}>",
+                        amount
+                            .parse::<u128>()
+                            .map(|amount| crate::common::NearBalance::from_yoctonear(amount)
+                                .to_string())
+                            .unwrap_or_else(|_| amount.to_string()),
+                        str_field("receiver_id")
+                    )
+                }
+                Some(token_id) => format!(
+                    "transfer {} of <{}> to <{}>",
+                    amount,
+                    token_id,
+                    str_field("receiver_id")
+                ),
+            }
+        }
+        "FunctionCall" => format!(
+            "call {} on <{}>",
+            details["actions"]
+                .as_array()
+                .map(|actions| actions
+                    .iter()
+                    .map(|action| action["method_name"].as_str().unwrap_or_default())
+                    .collect::<Vec<_>>()
+                    .join(", "))
+                .unwrap_or_default(),
+            str_field("receiver_id")
+        ),
+        "AddMemberToRole" => format!(
+            "add <{}> to the role {:?}",
+            str_field("member_id"),
+            str_field("role")
+        ),
+        "RemoveMemberFromRole" => format!(
+            "remove <{}> from the role {:?}",
+            str_field("member_id"),
+            str_field("role")
+        ),
+        "UpgradeSelf" => format!("upgrade the DAO to the code {}", str_field("hash")),
+        name => name.to_string(),
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn dao_proposal_kind() {
+        let kind = DaoProposalKind::Transfer {
+            token_id: "".to_string(),
+            receiver_id: "bob.near".to_string(),
+            amount: 10u128.pow(24),
+            msg: None,
+        };
+        let kind = serde_json::to_value(&kind).unwrap();
+        assert_eq!(
+            kind,
+            serde_json::json!({
+                "Transfer": {
+                    "token_id": "",
+                    "receiver_id": "bob.near",
+                    "amount": "1000000000000000000000000",
+                    "msg": null,
+                }
+            })
+        );
+        assert_eq!(
+            dao_proposal_kind_summary(&kind),
+            "transfer 1.000 NEAR to <bob.near>"
+        );
+        let kind = serde_json::to_value(&DaoProposalKind::FunctionCall {
+            receiver_id: "pool.near".to_string(),
+            actions: vec![DaoActionCall {
+                method_name: "ping".to_string(),
+                args: "e30=".to_string(),
+                deposit: 0,
+                gas: 5_000_000_000_000,
+            }],
+        })
+        .unwrap();
+        assert_eq!(
+            kind,
+            serde_json::json!({
+                "FunctionCall": {
+                    "receiver_id": "pool.near",
+                    "actions": [{
+                        "method_name": "ping",
+                        "args": "e30=",
+                        "deposit": "0",
+                        "gas": "5000000000000",
+                    }],
+                }
+            })
+        );
+        assert_eq!(dao_proposal_kind_summary(&kind), "call ping on <pool.near>");
+        assert_eq!(
+            dao_proposal_kind_summary(&serde_json::json!({
+                "AddMemberToRole": {"member_id": "alice.near", "role": "council"}
+            })),
+            "add <alice.near> to the role \"council\""
+        );
+        assert_eq!(
+            dao_proposal_kind_summary(&serde_json::json!("Vote")),
+            "Vote"
+        );
+    }
+}
@@ -0,0 +1,66 @@
+/// View the proposals
+#[derive(Debug, Default, clap::Clap)]
+pub struct CliViewProposals {
+    /// The ID of the first proposal to view (by default the latest ones are viewed)
+    #[clap(long)]
+    from_index: Option<u64>,
+    /// The number of proposals to view
+    #[clap(long, default_value = "20")]
+    limit: u64,
+}
+
+#[derive(Debug)]
+pub struct ViewProposals {
+    from_index: Option<u64>,
+    limit: u64,
+}
+
+impl From<CliViewProposals> for ViewProposals {
+    fn from(item: CliViewProposals) -> Self {
+        Self {
+            from_index: item.from_index,
+            limit: item.limit,
+        }
+    }
+}
+
+impl ViewProposals {
+    pub async fn process(
+        self,
+        prepopulated_unsigned_transaction: near_primitives::transaction::Transaction,
+        network_connection_config: crate::common::ConnectionConfig,
+    ) -> crate::CliResult {
+        let dao_account_id = &prepopulated_unsigned_transaction.receiver_id;
+        let from_index = match self.from_index {
+            Some(from_index) => from_index,
+            None => {
+                let last_proposal_id = crate::common::call_view_function::<u64>(
+                    &network_connection_config,
+                    dao_account_id,
+                    "get_last_proposal_id",
+                    serde_json::json!({}),
+                )
+                .await?;
+                last_proposal_id.saturating_sub(self.limit)
+            }
+        };
+        let proposals = crate::common::call_view_function::<Vec<super::DaoProposal>>(
+            &network_connection_config,
+            dao_account_id,
+            "get_proposals",
+            serde_json::json!({ "from_index": from_index, "limit": self.limit }),
+        )
+        .await?;
+        if proposals.is_empty() {
+            println!(
+                "\n<{}> has no proposals from #{}.",
+                dao_account_id, from_index
+            );
+            return Ok(());
+        }
+        for proposal in proposals.iter() {
+            super::print_proposal(proposal);
+        }
+        Ok(())
+    }
+}
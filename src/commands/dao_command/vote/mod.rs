@@ -0,0 +1,184 @@
+use dialoguer::{theme::ColorfulTheme, Input, Select};
+use strum::{EnumDiscriminants, EnumIter, EnumMessage, IntoEnumIterator};
+
+/// Specify the voter and the proposal
+#[derive(Debug, Default, clap::Clap)]
+pub struct CliVote {
+    voter_account_id: Option<String>,
+    proposal_id: Option<u64>,
+    #[clap(subcommand)]
+    vote_action: Option<CliVoteAction>,
+}
+
+#[derive(Debug)]
+pub struct Vote {
+    voter_account_id: String,
+    proposal_id: u64,
+    vote_action: VoteAction,
+}
+
+impl From<CliVote> for Vote {
+    fn from(item: CliVote) -> Self {
+        let voter_account_id: String = match item.voter_account_id {
+            Some(cli_voter_account_id) => cli_voter_account_id,
+            None => Vote::input_voter_account_id(),
+        };
+        let proposal_id: u64 = match item.proposal_id {
+            Some(cli_proposal_id) => cli_proposal_id,
+            None => Vote::input_proposal_id(),
+        };
+        let vote_action = match item.vote_action {
+            Some(cli_vote_action) => VoteAction::from(cli_vote_action),
+            None => VoteAction::choose_vote_action(),
+        };
+        Self {
+            voter_account_id,
+            proposal_id,
+            vote_action,
+        }
+    }
+}
+
+impl Vote {
+    fn input_voter_account_id() -> String {
+        println!();
+        Input::new()
+            .with_prompt("What is the account ID of the voter?")
+            .interact_text()
+            .unwrap()
+    }
+
+    fn input_proposal_id() -> u64 {
+        println!();
+        Input::new()
+            .with_prompt("What is the ID of the proposal?")
+            .interact_text()
+            .unwrap()
+    }
+
+    pub async fn process(
+        self,
+        prepopulated_unsigned_transaction: near_primitives::transaction::Transaction,
+        network_connection_config: crate::common::ConnectionConfig,
+    ) -> crate::CliResult {
+        let proposal = super::DaoProposal::fetch(
+            &network_connection_config,
+            &prepopulated_unsigned_transaction.receiver_id,
+            self.proposal_id,
+        )
+        .await?;
+        super::print_proposal(&proposal);
+        if proposal.status != "InProgress" {
+            return Err(color_eyre::Report::msg(format!(
+                "Proposal #{} is {} and can no longer be voted on",
+                proposal.id, proposal.status
+            )));
+        }
+        if let Some(vote) = proposal.votes.get(&self.voter_account_id) {
+            return Err(color_eyre::Report::msg(format!(
+                "<{}> has already voted {} on proposal #{}",
+                self.voter_account_id, vote, proposal.id
+            )));
+        }
+        let (action, sign_option) = match self.vote_action {
+            VoteAction::Approve(cast_vote) => ("VoteApprove", cast_vote.sign_option),
+            VoteAction::Reject(cast_vote) => ("VoteReject", cast_vote.sign_option),
+            VoteAction::Remove(cast_vote) => ("VoteRemove", cast_vote.sign_option),
+        };
+        let unsigned_transaction = near_primitives::transaction::Transaction {
+            signer_id: self.voter_account_id,
+            ..prepopulated_unsigned_transaction
+        };
+        sign_option
+            .process_function_call(
+                unsigned_transaction,
+                network_connection_config,
+                "act_proposal",
+                serde_json::json!({
+                    "id": self.proposal_id,
+                    "action": action,
+                }),
+                super::DAO_GAS,
+                0,
+            )
+            .await
+    }
+}
+
+#[derive(Debug, clap::Clap)]
+pub enum CliVoteAction {
+    /// Vote to approve the proposal; the vote that reaches the threshold executes it
+    Approve(CliCastVote),
+    /// Vote to reject the proposal; the bond is returned to the proposer
+    Reject(CliCastVote),
+    /// Vote to remove the proposal as spam; the bond is kept by the DAO
+    Remove(CliCastVote),
+}
+
+#[derive(Debug, EnumDiscriminants)]
+#[strum_discriminants(derive(EnumMessage, EnumIter))]
+pub enum VoteAction {
+    #[strum_discriminants(strum(message = "Approve"))]
+    Approve(CastVote),
+    #[strum_discriminants(strum(message = "Reject"))]
+    Reject(CastVote),
+    #[strum_discriminants(strum(message = "Remove as spam"))]
+    Remove(CastVote),
+}
+
+impl From<CliVoteAction> for VoteAction {
+    fn from(item: CliVoteAction) -> Self {
+        match item {
+            CliVoteAction::Approve(cli_cast_vote) => Self::Approve(cli_cast_vote.into()),
+            CliVoteAction::Reject(cli_cast_vote) => Self::Reject(cli_cast_vote.into()),
+            CliVoteAction::Remove(cli_cast_vote) => Self::Remove(cli_cast_vote.into()),
+        }
+    }
+}
+
+impl VoteAction {
+    fn choose_vote_action() -> Self {
+        println!();
+        let variants = VoteActionDiscriminants::iter().collect::<Vec<_>>();
+        let actions = variants
+            .iter()
+            .map(|p| p.get_message().unwrap().to_owned())
+            .collect::<Vec<_>>();
+        let selected_action = Select::with_theme(&ColorfulTheme::default())
+            .with_prompt("How do you want to vote?")
+            .items(&actions)
+            .default(0)
+            .interact()
+            .unwrap();
+        let cli_vote_action = match variants[selected_action] {
+            VoteActionDiscriminants::Approve => CliVoteAction::Approve(Default::default()),
+            VoteActionDiscriminants::Reject => CliVoteAction::Reject(Default::default()),
+            VoteActionDiscriminants::Remove => CliVoteAction::Remove(Default::default()),
+        };
+        Self::from(cli_vote_action)
+    }
+}
+
+/// Sign the vote
+#[derive(Debug, Default, clap::Clap)]
+pub struct CliCastVote {
+    #[clap(subcommand)]
+    sign_option: Option<
+        crate::commands::construct_transaction_command::sign_transaction::CliSignTransaction,
+    >,
+}
+
+#[derive(Debug)]
+pub struct CastVote {
+    sign_option: crate::commands::construct_transaction_command::sign_transaction::SignTransaction,
+}
+
+impl From<CliCastVote> for CastVote {
+    fn from(item: CliCastVote) -> Self {
+        let sign_option = match item.sign_option {
+            Some(cli_sign_transaction) => cli_sign_transaction.into(),
+            None => crate::commands::construct_transaction_command::sign_transaction::SignTransaction::choose_sign_option(),
+        };
+        Self { sign_option }
+    }
+}
@@ -5,10 +5,23 @@ use dialoguer::Input;
 pub struct CliCallFunctionAction {
     method_name: Option<String>,
     args: Option<String>,
+    #[clap(flatten)]
+    function_args: crate::common::CliFunctionArgs,
+    /// Read the contract ABI from a JSON file to choose the method and enter typed args
+    #[clap(long)]
+    abi_file: Option<std::path::PathBuf>,
+    /// Read the contract ABI embedded into the deployed contract code
+    #[clap(long)]
+    abi_from_contract: bool,
     #[clap(long = "attached-deposit")]
     deposit: Option<crate::common::NearBalance>,
     #[clap(long = "prepaid-gas")]
     gas: Option<crate::common::NearGas>,
+    /// Simulate the call on behalf of the signer to suggest the prepaid gas (downloads the
+    /// contract code and state)
+    #[cfg(feature = "simulate")]
+    #[clap(long)]
+    estimate_gas: bool,
     #[clap(subcommand)]
     send_from: Option<super::sender::CliSendFrom>,
 }
@@ -16,35 +29,104 @@ pub struct CliCallFunctionAction {
 #[derive(Debug)]
 pub struct CallFunctionAction {
     method_name: String,
-    args: Vec<u8>,
+    args: crate::common::FunctionArgs,
     gas: near_primitives::types::Gas,
     deposit: near_primitives::types::Balance,
     send_from: super::sender::SendFrom,
 }
 
-impl From<CliCallFunctionAction> for CallFunctionAction {
-    fn from(item: CliCallFunctionAction) -> Self {
+impl CallFunctionAction {
+    pub fn from(
+        item: CliCallFunctionAction,
+        network_connection_config: Option<&crate::common::ConnectionConfig>,
+        contract_account_id: &str,
+    ) -> Self {
+        let contract_abi = crate::common::ContractAbi::load(
+            item.abi_file,
+            item.abi_from_contract,
+            item.method_name.is_some(),
+            network_connection_config,
+            contract_account_id,
+        );
         let method_name: String = match item.method_name {
             Some(cli_method_name) => cli_method_name,
-            None => CallFunctionAction::input_method_name(),
+            None => match contract_abi.as_ref().and_then(|contract_abi| {
+                contract_abi.choose_function(crate::common::AbiFunctionKind::Call)
+            }) {
+                Some(abi_function) => abi_function.name.clone(),
+                None => CallFunctionAction::input_method_name(),
+            },
         };
-        let args: Vec<u8> = match item.args {
-            Some(cli_args) => cli_args.into_bytes(),
-            None => CallFunctionAction::input_args(),
+        let args = match item.function_args.into_args() {
+            Some(function_args) => Some(function_args),
+            None => item
+                .args
+                .map(|cli_args| crate::common::FunctionArgs::Bytes(cli_args.into_bytes())),
         };
-        let gas: near_primitives::types::Gas = match item.gas {
-            Some(cli_gas) => match cli_gas {
-                crate::common::NearGas { inner: num } => num,
+        let args = match contract_abi.as_ref().and_then(|contract_abi| {
+            contract_abi
+                .function(&method_name)
+                .map(|abi_function| (contract_abi, abi_function))
+        }) {
+            Some((contract_abi, abi_function)) => match args {
+                Some(crate::common::FunctionArgs::Bytes(args)) => {
+                    match contract_abi.function_args(abi_function, Some(args)) {
+                        Ok(args) => args.map(crate::common::FunctionArgs::Bytes),
+                        Err(err) => Some(crate::common::FunctionArgs::Invalid(err)),
+                    }
+                }
+                None => match contract_abi.function_args(abi_function, None) {
+                    Ok(args) => args.map(crate::common::FunctionArgs::Bytes),
+                    Err(err) => Some(crate::common::FunctionArgs::Invalid(err)),
+                },
+                // Args from stdin and invalid args are checked when the call is processed
+                args => args,
             },
-            None => CallFunctionAction::input_gas(),
+            None => args,
+        };
+        let args = match args {
+            Some(args) => args,
+            None => crate::common::FunctionArgs::Bytes(CallFunctionAction::input_args()),
         };
         let deposit: near_primitives::types::Balance = match item.deposit {
             Some(cli_deposit) => cli_deposit.to_yoctonear(),
             None => CallFunctionAction::input_deposit(),
         };
-        let send_from = match item.send_from {
-            Some(cli_send_from) => super::sender::SendFrom::from(cli_send_from),
-            None => super::sender::SendFrom::choose_send_from(),
+        let (gas, send_from) = match item.gas {
+            Some(cli_gas) => match cli_gas {
+                crate::common::NearGas { inner: num } => {
+                    (num, CallFunctionAction::send_from(item.send_from))
+                }
+            },
+            #[cfg(feature = "simulate")]
+            None if item.estimate_gas => {
+                // The signer is chosen first to simulate the call on its behalf
+                let send_from = CallFunctionAction::send_from(item.send_from);
+                let estimated_gas = match (network_connection_config, args.bytes()) {
+                    (Some(network_connection_config), Some(args)) => {
+                        crate::common::FunctionCallSimulation {
+                            contract_account_id: contract_account_id.to_string(),
+                            signer_account_id: send_from.signer_account_id().to_string(),
+                            method_name: method_name.clone(),
+                            args: args.to_vec(),
+                            deposit,
+                            gas: crate::common::SIMULATION_DEFAULT_PREPAID_GAS,
+                        }
+                        .estimate_gas(network_connection_config)
+                    }
+                    _ => None,
+                };
+                if estimated_gas.is_none() {
+                    println!(
+                        "\nThe gas cannot be estimated: the call cannot be simulated or fails"
+                    );
+                }
+                (CallFunctionAction::input_gas(estimated_gas), send_from)
+            }
+            None => {
+                let gas = CallFunctionAction::input_gas(None);
+                (gas, CallFunctionAction::send_from(item.send_from))
+            }
         };
         Self {
             method_name,
@@ -54,9 +136,14 @@ impl From<CliCallFunctionAction> for CallFunctionAction {
             send_from,
         }
     }
-}
 
-impl CallFunctionAction {
+    fn send_from(cli_send_from: Option<super::sender::CliSendFrom>) -> super::sender::SendFrom {
+        match cli_send_from {
+            Some(cli_send_from) => super::sender::SendFrom::from(cli_send_from),
+            None => super::sender::SendFrom::choose_send_from(),
+        }
+    }
+
     fn input_method_name() -> String {
         println!();
         Input::new()
@@ -65,12 +152,29 @@ impl CallFunctionAction {
             .unwrap()
     }
 
-    fn input_gas() -> near_primitives::types::Gas {
+    fn input_gas(
+        estimated_gas: Option<near_primitives::types::Gas>,
+    ) -> near_primitives::types::Gas {
         println!();
+        let initial_gas = match estimated_gas {
+            Some(estimated_gas) => {
+                println!(
+                    "The local simulation of this call suggests {} (including the gas it attaches to the promises it creates)",
+                    crate::common::NearGas {
+                        inner: estimated_gas
+                    }
+                );
+                format!(
+                    "{} TeraGas",
+                    (estimated_gas + 1_000_000_000_000 - 1) / 1_000_000_000_000
+                )
+            }
+            None => "100 TeraGas".to_string(),
+        };
         let gas: u64 = loop {
             let input_gas: crate::common::NearGas = Input::new()
                 .with_prompt("Enter a gas for function")
-                .with_initial_text("100 TeraGas")
+                .with_initial_text(&initial_gas)
                 .interact_text()
                 .unwrap();
             let gas: u64 = match input_gas {
@@ -114,7 +218,7 @@ impl CallFunctionAction {
         let action = near_primitives::transaction::Action::FunctionCall(
             near_primitives::transaction::FunctionCallAction {
                 method_name: self.method_name.clone(),
-                args: self.args.clone(),
+                args: self.args.into_bytes()?,
                 gas: self.gas.clone(),
                 deposit: self.deposit.clone(),
             },
@@ -19,18 +19,27 @@ pub enum CallFunction {
     Call(self::call_function_type::CallFunctionAction),
 }
 
-impl From<CliCallFunction> for CallFunction {
-    fn from(item: CliCallFunction) -> Self {
+impl CallFunction {
+    fn from(
+        item: CliCallFunction,
+        network_connection_config: Option<&crate::common::ConnectionConfig>,
+        contract_account_id: &str,
+    ) -> Self {
         match item {
             CliCallFunction::Call(cli_call_function_action) => {
-                CallFunction::Call(cli_call_function_action.into())
+                CallFunction::Call(self::call_function_type::CallFunctionAction::from(
+                    cli_call_function_action,
+                    network_connection_config,
+                    contract_account_id,
+                ))
             }
         }
     }
-}
 
-impl CallFunction {
-    fn choose_call_function() -> Self {
+    fn choose_call_function(
+        network_connection_config: Option<&crate::common::ConnectionConfig>,
+        contract_account_id: &str,
+    ) -> Self {
         println!();
         let variants = CallFunctionDiscriminants::iter().collect::<Vec<_>>();
         let commands = variants
@@ -46,7 +55,7 @@ impl CallFunction {
         let cli_call = match variants[selection] {
             CallFunctionDiscriminants::Call => CliCallFunction::Call(Default::default()),
         };
-        Self::from(cli_call)
+        Self::from(cli_call, network_connection_config, contract_account_id)
     }
 
     pub async fn process(
@@ -29,8 +29,8 @@ impl From<CliOfflineArgs> for OfflineArgs {
             None => OfflineArgs::input_block_hash(),
         };
         let send_to = match item.send_to {
-            Some(cli_send_to) => super::super::receiver::SendTo::from(cli_send_to),
-            None => super::super::receiver::SendTo::send_to(),
+            Some(cli_send_to) => super::super::receiver::SendTo::from(cli_send_to, None),
+            None => super::super::receiver::SendTo::send_to(None),
         };
         Self {
             nonce,
@@ -25,8 +25,13 @@ pub struct Server {
 impl CliServer {
     pub fn into_server(self, network_connection_config: crate::common::ConnectionConfig) -> Server {
         let send_to = match self.send_to {
-            Some(cli_send_to) => super::super::super::super::receiver::SendTo::from(cli_send_to),
-            None => super::super::super::super::receiver::SendTo::send_to(),
+            Some(cli_send_to) => super::super::super::super::receiver::SendTo::from(
+                cli_send_to,
+                Some(&network_connection_config),
+            ),
+            None => super::super::super::super::receiver::SendTo::send_to(Some(
+                &network_connection_config,
+            )),
         };
         Server {
             network_connection_config: Some(network_connection_config),
@@ -44,14 +49,18 @@ impl CliCustomServer {
                 .interact_text()
                 .unwrap(),
         };
+        let network_connection_config = crate::common::ConnectionConfig::Custom { url: url.inner };
         let send_to = match self.send_to {
-            Some(cli_send_to) => super::super::super::super::receiver::SendTo::from(cli_send_to),
-            None => super::super::super::super::receiver::SendTo::send_to(),
+            Some(cli_send_to) => super::super::super::super::receiver::SendTo::from(
+                cli_send_to,
+                Some(&network_connection_config),
+            ),
+            None => super::super::super::super::receiver::SendTo::send_to(Some(
+                &network_connection_config,
+            )),
         };
         Server {
-            network_connection_config: Some(crate::common::ConnectionConfig::Custom {
-                url: url.inner,
-            }),
+            network_connection_config: Some(network_connection_config),
             send_to,
         }
     }
@@ -24,6 +24,13 @@ impl SendFrom {
         Self::from(CliSendFrom::Signer(Default::default()))
     }
 
+    #[cfg(feature = "simulate")]
+    pub fn signer_account_id(&self) -> &str {
+        match self {
+            SendFrom::Signer(sender) => &sender.sender_account_id,
+        }
+    }
+
     pub async fn process(
         self,
         prepopulated_unsigned_transaction: near_primitives::transaction::Transaction,
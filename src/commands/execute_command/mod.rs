@@ -2,6 +2,8 @@ use dialoguer::{theme::ColorfulTheme, Select};
 use strum::{EnumDiscriminants, EnumIter, EnumMessage, IntoEnumIterator};
 
 mod change_method;
+#[cfg(feature = "simulate")]
+mod simulate_method;
 mod view_method;
 
 /// выбор метода для выполнения
@@ -41,6 +43,9 @@ enum CliMethod {
     ChangeMethod(self::change_method::operation_mode::CliOperationMode),
     /// Specify a view method
     ViewMethod(self::view_method::operation_mode::CliOperationMode),
+    /// Simulate a method call locally against the contract code and state fetched from the network
+    #[cfg(feature = "simulate")]
+    SimulateMethod(self::simulate_method::operation_mode::CliOperationMode),
 }
 
 #[derive(Debug, EnumDiscriminants)]
@@ -50,6 +55,9 @@ enum Method {
     ChangeMethod(self::change_method::operation_mode::OperationMode),
     #[strum_discriminants(strum(message = "View a method"))]
     ViewMethod(self::view_method::operation_mode::OperationMode),
+    #[cfg(feature = "simulate")]
+    #[strum_discriminants(strum(message = "Simulate a method call (no transaction is sent)"))]
+    SimulateMethod(self::simulate_method::operation_mode::OperationMode),
 }
 
 impl From<CliMethod> for Method {
@@ -61,6 +69,10 @@ impl From<CliMethod> for Method {
             CliMethod::ViewMethod(cli_operation_mode) => {
                 Method::ViewMethod(cli_operation_mode.into())
             }
+            #[cfg(feature = "simulate")]
+            CliMethod::SimulateMethod(cli_operation_mode) => {
+                Method::SimulateMethod(cli_operation_mode.into())
+            }
         }
     }
 }
@@ -82,6 +94,8 @@ impl Method {
         let cli_method = match variants[selected_method] {
             MethodDiscriminants::ChangeMethod => CliMethod::ChangeMethod(Default::default()),
             MethodDiscriminants::ViewMethod => CliMethod::ViewMethod(Default::default()),
+            #[cfg(feature = "simulate")]
+            MethodDiscriminants::SimulateMethod => CliMethod::SimulateMethod(Default::default()),
         };
         Self::from(cli_method)
     }
@@ -97,6 +111,8 @@ impl Method {
                     .await
             }
             Self::ViewMethod(operation_mode) => operation_mode.process().await,
+            #[cfg(feature = "simulate")]
+            Self::SimulateMethod(operation_mode) => operation_mode.process().await,
         }
     }
 }
@@ -0,0 +1,49 @@
+use dialoguer::Input;
+
+/// Specify the block_id hash for this contract to simulate the call at
+#[derive(Debug, Default, clap::Clap)]
+pub struct CliBlockIdHash {
+    block_id_hash: Option<near_primitives::hash::CryptoHash>,
+}
+
+#[derive(Debug)]
+pub struct BlockIdHash {
+    block_id_hash: near_primitives::hash::CryptoHash,
+}
+
+impl From<CliBlockIdHash> for BlockIdHash {
+    fn from(item: CliBlockIdHash) -> Self {
+        let block_id_hash: near_primitives::hash::CryptoHash = match item.block_id_hash {
+            Some(cli_block_id_hash) => cli_block_id_hash,
+            None => BlockIdHash::input_block_id_hash(),
+        };
+        Self { block_id_hash }
+    }
+}
+
+impl BlockIdHash {
+    pub fn input_block_id_hash() -> near_primitives::hash::CryptoHash {
+        Input::new()
+            .with_prompt("Type the block ID hash for this contract")
+            .interact_text()
+            .unwrap()
+    }
+
+    pub async fn process(
+        self,
+        network_connection_config: crate::common::ConnectionConfig,
+        simulation: crate::common::FunctionCallSimulation,
+        result_format: crate::common::ResultFormat,
+    ) -> crate::CliResult {
+        let simulation_outcome = simulation
+            .run(
+                network_connection_config.archival_rpc_url().as_str(),
+                near_primitives::types::BlockReference::BlockId(
+                    near_primitives::types::BlockId::Hash(self.block_id_hash),
+                ),
+            )
+            .await?;
+        simulation_outcome.print(&result_format);
+        Ok(())
+    }
+}
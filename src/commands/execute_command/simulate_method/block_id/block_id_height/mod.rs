@@ -0,0 +1,49 @@
+use dialoguer::Input;
+
+/// Specify the block_id height for this contract to simulate the call at
+#[derive(Debug, Default, clap::Clap)]
+pub struct CliBlockIdHeight {
+    block_id_height: Option<near_primitives::types::BlockHeight>,
+}
+
+#[derive(Debug)]
+pub struct BlockIdHeight {
+    block_id_height: near_primitives::types::BlockHeight,
+}
+
+impl From<CliBlockIdHeight> for BlockIdHeight {
+    fn from(item: CliBlockIdHeight) -> Self {
+        let block_id_height: near_primitives::types::BlockHeight = match item.block_id_height {
+            Some(cli_block_id_hash) => cli_block_id_hash,
+            None => BlockIdHeight::input_block_id_height(),
+        };
+        Self { block_id_height }
+    }
+}
+
+impl BlockIdHeight {
+    pub fn input_block_id_height() -> near_primitives::types::BlockHeight {
+        Input::new()
+            .with_prompt("Type the block ID height for this contract")
+            .interact_text()
+            .unwrap()
+    }
+
+    pub async fn process(
+        self,
+        network_connection_config: crate::common::ConnectionConfig,
+        simulation: crate::common::FunctionCallSimulation,
+        result_format: crate::common::ResultFormat,
+    ) -> crate::CliResult {
+        let simulation_outcome = simulation
+            .run(
+                network_connection_config.archival_rpc_url().as_str(),
+                near_primitives::types::BlockReference::BlockId(
+                    near_primitives::types::BlockId::Height(self.block_id_height),
+                ),
+            )
+            .await?;
+        simulation_outcome.print(&result_format);
+        Ok(())
+    }
+}
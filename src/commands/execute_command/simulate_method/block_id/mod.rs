@@ -0,0 +1,94 @@
+use dialoguer::{theme::ColorfulTheme, Select};
+use strum::{EnumDiscriminants, EnumIter, EnumMessage, IntoEnumIterator};
+
+mod block_id_hash;
+mod block_id_height;
+
+#[derive(Debug, clap::Clap)]
+pub enum CliBlockId {
+    /// Specify a block ID final to simulate the call at
+    AtFinalBlock,
+    /// Specify a block ID height to simulate the call at
+    AtBlockHeight(self::block_id_height::CliBlockIdHeight),
+    /// Specify a block ID hash to simulate the call at
+    AtBlockHash(self::block_id_hash::CliBlockIdHash),
+}
+
+#[derive(Debug, EnumDiscriminants)]
+#[strum_discriminants(derive(EnumMessage, EnumIter))]
+pub enum BlockId {
+    #[strum_discriminants(strum(message = "Simulate the call at final block"))]
+    AtFinalBlock,
+    #[strum_discriminants(strum(message = "Simulate the call at block heigt"))]
+    AtBlockHeight(self::block_id_height::BlockIdHeight),
+    #[strum_discriminants(strum(message = "Simulate the call at block hash"))]
+    AtBlockHash(self::block_id_hash::BlockIdHash),
+}
+
+impl From<CliBlockId> for BlockId {
+    fn from(item: CliBlockId) -> Self {
+        match item {
+            CliBlockId::AtFinalBlock => Self::AtFinalBlock,
+            CliBlockId::AtBlockHeight(cli_block_id_height) => {
+                Self::AtBlockHeight(cli_block_id_height.into())
+            }
+            CliBlockId::AtBlockHash(cli_block_id_hash) => {
+                Self::AtBlockHash(cli_block_id_hash.into())
+            }
+        }
+    }
+}
+
+impl BlockId {
+    pub fn choose_block_id() -> Self {
+        println!();
+        let variants = BlockIdDiscriminants::iter().collect::<Vec<_>>();
+        let blocks = variants
+            .iter()
+            .map(|p| p.get_message().unwrap().to_owned())
+            .collect::<Vec<_>>();
+        let selection = Select::with_theme(&ColorfulTheme::default())
+            .with_prompt("Choose your action")
+            .items(&blocks)
+            .default(0)
+            .interact()
+            .unwrap();
+        let cli_block_id = match variants[selection] {
+            BlockIdDiscriminants::AtFinalBlock => CliBlockId::AtFinalBlock,
+            BlockIdDiscriminants::AtBlockHeight => CliBlockId::AtBlockHeight(Default::default()),
+            BlockIdDiscriminants::AtBlockHash => CliBlockId::AtBlockHash(Default::default()),
+        };
+        Self::from(cli_block_id)
+    }
+
+    pub async fn process(
+        self,
+        network_connection_config: crate::common::ConnectionConfig,
+        simulation: crate::common::FunctionCallSimulation,
+        result_format: crate::common::ResultFormat,
+    ) -> crate::CliResult {
+        println!();
+        match self {
+            Self::AtBlockHeight(block_id_height) => {
+                block_id_height
+                    .process(network_connection_config, simulation, result_format)
+                    .await
+            }
+            Self::AtBlockHash(block_id_hash) => {
+                block_id_hash
+                    .process(network_connection_config, simulation, result_format)
+                    .await
+            }
+            Self::AtFinalBlock => {
+                let simulation_outcome = simulation
+                    .run(
+                        network_connection_config.rpc_url().as_str(),
+                        near_primitives::types::Finality::Final.into(),
+                    )
+                    .await?;
+                simulation_outcome.print(&result_format);
+                Ok(())
+            }
+        }
+    }
+}
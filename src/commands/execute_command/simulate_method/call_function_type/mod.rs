@@ -0,0 +1,131 @@
+use dialoguer::Input;
+
+/// Simulate a function call
+#[derive(Debug, Default, clap::Clap)]
+pub struct CliCallFunctionSimulation {
+    method_name: Option<String>,
+    args: Option<String>,
+    #[clap(flatten)]
+    function_args: crate::common::CliFunctionArgs,
+    /// The account which calls the method (the signer and the predecessor)
+    #[clap(long)]
+    signer_id: Option<String>,
+    #[clap(long = "attached-deposit")]
+    deposit: Option<crate::common::NearBalance>,
+    /// Gas for the call (200 TeraGas by default)
+    #[clap(long = "prepaid-gas")]
+    gas: Option<crate::common::NearGas>,
+    /// How to show the return value: json, utf8, hex, base64 or borsh:<type> (json by default)
+    #[clap(long)]
+    result_format: Option<crate::common::ResultFormat>,
+    #[clap(subcommand)]
+    selected_block_id: Option<super::block_id::CliBlockId>,
+}
+
+#[derive(Debug)]
+pub struct CallFunctionSimulation {
+    signer_account_id: String,
+    method_name: String,
+    args: crate::common::FunctionArgs,
+    deposit: near_primitives::types::Balance,
+    gas: near_primitives::types::Gas,
+    result_format: crate::common::ResultFormat,
+    selected_block_id: super::block_id::BlockId,
+}
+
+impl CallFunctionSimulation {
+    pub fn from(item: CliCallFunctionSimulation, contract_account_id: &str) -> Self {
+        let method_name: String = match item.method_name {
+            Some(cli_method_name) => cli_method_name,
+            None => CallFunctionSimulation::input_method_name(),
+        };
+        let args = match item.function_args.into_args() {
+            Some(function_args) => function_args,
+            None => crate::common::FunctionArgs::Bytes(match item.args {
+                Some(cli_args) => cli_args.into_bytes(),
+                None => CallFunctionSimulation::input_args(),
+            }),
+        };
+        let signer_account_id: String = match item.signer_id {
+            Some(cli_signer_id) => cli_signer_id,
+            None => CallFunctionSimulation::input_signer_account_id(contract_account_id),
+        };
+        let deposit: near_primitives::types::Balance = match item.deposit {
+            Some(cli_deposit) => cli_deposit.to_yoctonear(),
+            None => CallFunctionSimulation::input_deposit(),
+        };
+        let gas: near_primitives::types::Gas = match item.gas {
+            Some(cli_gas) => cli_gas.inner,
+            None => crate::common::SIMULATION_DEFAULT_PREPAID_GAS,
+        };
+        let selected_block_id: super::block_id::BlockId = match item.selected_block_id {
+            Some(cli_block_id) => cli_block_id.into(),
+            None => super::block_id::BlockId::choose_block_id(),
+        };
+        Self {
+            signer_account_id,
+            method_name,
+            args,
+            deposit,
+            gas,
+            result_format: item.result_format.unwrap_or_default(),
+            selected_block_id,
+        }
+    }
+
+    fn input_method_name() -> String {
+        println!();
+        Input::new()
+            .with_prompt("Enter a method name")
+            .interact_text()
+            .unwrap()
+    }
+
+    fn input_args() -> Vec<u8> {
+        println!();
+        let input: String = Input::new()
+            .with_prompt("Enter args for function")
+            .interact_text()
+            .unwrap();
+        input.into_bytes()
+    }
+
+    fn input_signer_account_id(contract_account_id: &str) -> String {
+        println!();
+        Input::new()
+            .with_prompt("What is the account ID which calls the method?")
+            .with_initial_text(contract_account_id)
+            .interact_text()
+            .unwrap()
+    }
+
+    fn input_deposit() -> near_primitives::types::Balance {
+        println!();
+        let deposit: crate::common::NearBalance = Input::new()
+            .with_prompt(
+                "Enter a deposit for function (example: 10NEAR or 0.5near or 10000yoctonear).",
+            )
+            .with_initial_text("0 NEAR")
+            .interact_text()
+            .unwrap();
+        deposit.to_yoctonear()
+    }
+
+    pub async fn process(
+        self,
+        network_connection_config: crate::common::ConnectionConfig,
+        contract_account_id: String,
+    ) -> crate::CliResult {
+        let simulation = crate::common::FunctionCallSimulation {
+            contract_account_id,
+            signer_account_id: self.signer_account_id,
+            method_name: self.method_name,
+            args: self.args.into_bytes()?,
+            deposit: self.deposit,
+            gas: self.gas,
+        };
+        self.selected_block_id
+            .process(network_connection_config, simulation, self.result_format)
+            .await
+    }
+}
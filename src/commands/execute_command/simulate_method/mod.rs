@@ -0,0 +1,66 @@
+use dialoguer::{theme::ColorfulTheme, Select};
+use strum::{EnumDiscriminants, EnumIter, EnumMessage, IntoEnumIterator};
+
+mod block_id;
+mod call_function_type;
+pub mod operation_mode;
+mod receiver;
+
+#[derive(Debug, clap::Clap)]
+pub enum CliCallFunction {
+    /// Simulate a function call
+    Call(self::call_function_type::CliCallFunctionSimulation),
+}
+
+#[derive(Debug, EnumDiscriminants)]
+#[strum_discriminants(derive(EnumMessage, EnumIter))]
+pub enum CallFunction {
+    #[strum_discriminants(strum(message = "Simulate a function call"))]
+    Call(self::call_function_type::CallFunctionSimulation),
+}
+
+impl CallFunction {
+    fn from(item: CliCallFunction, contract_account_id: &str) -> Self {
+        match item {
+            CliCallFunction::Call(cli_call_function_simulation) => {
+                CallFunction::Call(self::call_function_type::CallFunctionSimulation::from(
+                    cli_call_function_simulation,
+                    contract_account_id,
+                ))
+            }
+        }
+    }
+
+    pub fn choose_call_function(contract_account_id: &str) -> Self {
+        println!();
+        let variants = CallFunctionDiscriminants::iter().collect::<Vec<_>>();
+        let commands = variants
+            .iter()
+            .map(|p| p.get_message().unwrap().to_owned())
+            .collect::<Vec<_>>();
+        let selection = Select::with_theme(&ColorfulTheme::default())
+            .with_prompt("Call your function")
+            .items(&commands)
+            .default(0)
+            .interact()
+            .unwrap();
+        let cli_call = match variants[selection] {
+            CallFunctionDiscriminants::Call => CliCallFunction::Call(Default::default()),
+        };
+        Self::from(cli_call, contract_account_id)
+    }
+
+    pub async fn process(
+        self,
+        network_connection_config: crate::common::ConnectionConfig,
+        contract_account_id: String,
+    ) -> crate::CliResult {
+        match self {
+            Self::Call(call_function_simulation) => {
+                call_function_simulation
+                    .process(network_connection_config, contract_account_id)
+                    .await
+            }
+        }
+    }
+}
@@ -39,6 +39,7 @@ impl BlockIdHash {
         contract_account_id: String,
         method_name: String,
         args: Vec<u8>,
+        result_format: crate::common::ResultFormat,
     ) -> crate::CliResult {
         let args: near_primitives::types::FunctionArgs =
             near_primitives::types::FunctionArgs::from(args);
@@ -62,19 +63,11 @@ impl BlockIdHash {
             if let near_jsonrpc_primitives::types::query::QueryResponseKind::CallResult(result) =
                 query_view_method_response.kind
             {
-                result.result
+                result
             } else {
                 return Err(color_eyre::Report::msg(format!("Error call result")));
             };
-        let call_result_str = String::from_utf8(call_result).unwrap();
-        let serde_call_result: serde_json::Value = serde_json::from_str(&call_result_str)
-            .map_err(|err| color_eyre::Report::msg(format!("serde json: {:?}", err)))?;
-        println!("--------------");
-        println!();
-        println!(
-            "{}",
-            serde_json::to_string_pretty(&serde_call_result).unwrap()
-        );
+        crate::common::print_call_function_result(&call_result, &result_format);
         Ok(())
     }
 }
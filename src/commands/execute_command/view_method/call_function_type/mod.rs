@@ -5,6 +5,15 @@ use dialoguer::Input;
 pub struct CliCallFunctionView {
     method_name: Option<String>,
     args: Option<String>,
+    /// How to show the result: json, utf8, hex, base64 or borsh:<type> (json by default)
+    #[clap(long)]
+    result_format: Option<crate::common::ResultFormat>,
+    /// Read the contract ABI from a JSON file to choose the method and enter typed args
+    #[clap(long)]
+    abi_file: Option<std::path::PathBuf>,
+    /// Read the contract ABI embedded into the deployed contract code
+    #[clap(long)]
+    abi_from_contract: bool,
     #[clap(subcommand)]
     selected_block_id: Option<super::block_id::CliBlockId>,
 }
@@ -12,19 +21,54 @@ pub struct CliCallFunctionView {
 #[derive(Debug)]
 pub struct CallFunctionView {
     method_name: String,
-    args: Vec<u8>,
+    args: Result<Vec<u8>, String>,
+    result_format: crate::common::ResultFormat,
     selected_block_id: super::block_id::BlockId,
 }
 
-impl From<CliCallFunctionView> for CallFunctionView {
-    fn from(item: CliCallFunctionView) -> Self {
+impl CallFunctionView {
+    pub fn from(
+        item: CliCallFunctionView,
+        network_connection_config: &crate::common::ConnectionConfig,
+        contract_account_id: &str,
+    ) -> Self {
+        let contract_abi = crate::common::ContractAbi::load(
+            item.abi_file,
+            item.abi_from_contract,
+            item.method_name.is_some(),
+            Some(network_connection_config),
+            contract_account_id,
+        );
         let method_name: String = match item.method_name {
             Some(cli_method_name) => cli_method_name,
-            None => CallFunctionView::input_method_name(),
+            None => match contract_abi.as_ref().and_then(|contract_abi| {
+                contract_abi.choose_function(crate::common::AbiFunctionKind::View)
+            }) {
+                Some(abi_function) => abi_function.name.clone(),
+                None => CallFunctionView::input_method_name(),
+            },
+        };
+        let abi_function = contract_abi.as_ref().and_then(|contract_abi| {
+            contract_abi
+                .function(&method_name)
+                .map(|abi_function| (contract_abi, abi_function))
+        });
+        let args = item.args.map(String::into_bytes);
+        let args = match abi_function {
+            Some((contract_abi, abi_function)) => contract_abi.function_args(abi_function, args),
+            None => Ok(args),
+        };
+        let args: Result<Vec<u8>, String> = match args {
+            Ok(Some(args)) => Ok(args),
+            Ok(None) => Ok(CallFunctionView::input_args()),
+            Err(err) => Err(err),
         };
-        let args: Vec<u8> = match item.args {
-            Some(cli_args) => cli_args.into_bytes(),
-            None => CallFunctionView::input_args(),
+        let result_format = match (item.result_format, abi_function) {
+            (Some(result_format), _) => result_format,
+            (None, Some((contract_abi, abi_function))) => {
+                contract_abi.result_format(abi_function).unwrap_or_default()
+            }
+            (None, None) => Default::default(),
         };
         let selected_block_id: super::block_id::BlockId = match item.selected_block_id {
             Some(cli_block_id) => cli_block_id.into(),
@@ -33,12 +77,11 @@ impl From<CliCallFunctionView> for CallFunctionView {
         Self {
             method_name,
             args,
+            result_format,
             selected_block_id,
         }
     }
-}
 
-impl CallFunctionView {
     fn input_method_name() -> String {
         println!();
         Input::new()
@@ -61,12 +104,14 @@ impl CallFunctionView {
         network_connection_config: crate::common::ConnectionConfig,
         contract_account_id: String,
     ) -> crate::CliResult {
+        let args = self.args.map_err(color_eyre::Report::msg)?;
         self.selected_block_id
             .process(
                 contract_account_id,
                 network_connection_config,
                 self.method_name,
-                self.args,
+                args,
+                self.result_format,
             )
             .await
     }
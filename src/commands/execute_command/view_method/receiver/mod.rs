@@ -11,20 +11,24 @@ pub enum SendTo {
     Contract(Receiver),
 }
 
-impl From<CliSendTo> for SendTo {
-    fn from(item: CliSendTo) -> Self {
+impl SendTo {
+    pub fn from(
+        item: CliSendTo,
+        network_connection_config: &crate::common::ConnectionConfig,
+    ) -> Self {
         match item {
             CliSendTo::Contract(cli_receiver) => {
-                let receiver = Receiver::from(cli_receiver);
+                let receiver = Receiver::from(cli_receiver, network_connection_config);
                 Self::Contract(receiver)
             }
         }
     }
-}
 
-impl SendTo {
-    pub fn send_to() -> Self {
-        Self::from(CliSendTo::Contract(Default::default()))
+    pub fn send_to(network_connection_config: &crate::common::ConnectionConfig) -> Self {
+        Self::from(
+            CliSendTo::Contract(Default::default()),
+            network_connection_config,
+        )
     }
 
     pub async fn process(
@@ -51,24 +55,30 @@ pub struct Receiver {
     pub call: super::CallFunction,
 }
 
-impl From<CliReceiver> for Receiver {
-    fn from(item: CliReceiver) -> Self {
+impl Receiver {
+    pub fn from(
+        item: CliReceiver,
+        network_connection_config: &crate::common::ConnectionConfig,
+    ) -> Self {
         let contract_account_id: String = match item.contract_account_id {
             Some(cli_contract_account_id) => cli_contract_account_id,
             None => Receiver::input_contract_account_id(),
         };
         let call = match item.call {
-            Some(cli_call) => cli_call.into(),
-            None => super::CallFunction::choose_call_function(),
+            Some(cli_call) => {
+                super::CallFunction::from(cli_call, network_connection_config, &contract_account_id)
+            }
+            None => super::CallFunction::choose_call_function(
+                network_connection_config,
+                &contract_account_id,
+            ),
         };
         Self {
             contract_account_id,
             call,
         }
     }
-}
 
-impl Receiver {
     pub fn input_contract_account_id() -> String {
         Input::new()
             .with_prompt("What is the account ID of the contract?")
@@ -0,0 +1,249 @@
+use dialoguer::Input;
+
+/// Specify the amount
+#[derive(Debug, Default, clap::Clap)]
+pub struct CliLockupAmount {
+    amount: Option<crate::common::NearBalance>,
+    #[clap(subcommand)]
+    sign_option: Option<
+        crate::commands::construct_transaction_command::sign_transaction::CliSignTransaction,
+    >,
+}
+
+/// The lockup methods which take an amount
+#[derive(Debug, Clone, Copy, PartialEq)]
+pub enum LockupAmountMethod {
+    DepositAndStake,
+    Unstake,
+    WithdrawFromStakingPool,
+}
+
+impl LockupAmountMethod {
+    fn method_name(&self) -> &'static str {
+        match self {
+            Self::DepositAndStake => "deposit_and_stake",
+            Self::Unstake => "unstake",
+            Self::WithdrawFromStakingPool => "withdraw_from_staking_pool",
+        }
+    }
+
+    /// The view method of the staking pool which reports the balance of the lockup the amount
+    /// is taken from (the pool balances include the rewards, unlike the known deposited balance).
+    fn staking_pool_balance_method_name(&self) -> Option<&'static str> {
+        match self {
+            Self::DepositAndStake => None,
+            Self::Unstake => Some("get_account_staked_balance"),
+            Self::WithdrawFromStakingPool => Some("get_account_unstaked_balance"),
+        }
+    }
+}
+
+#[derive(Debug)]
+pub struct LockupAmount {
+    amount: near_primitives::types::Balance,
+    sign_option: crate::commands::construct_transaction_command::sign_transaction::SignTransaction,
+}
+
+impl LockupAmount {
+    pub fn from(item: CliLockupAmount, amount_prompt: &str) -> Self {
+        let amount: near_primitives::types::Balance = match item.amount {
+            Some(cli_amount) => cli_amount.to_yoctonear(),
+            None => LockupAmount::input_amount(amount_prompt),
+        };
+        let sign_option = match item.sign_option {
+            Some(cli_sign_transaction) => cli_sign_transaction.into(),
+            None => crate::commands::construct_transaction_command::sign_transaction::SignTransaction::choose_sign_option(),
+        };
+        Self {
+            amount,
+            sign_option,
+        }
+    }
+
+    fn input_amount(amount_prompt: &str) -> near_primitives::types::Balance {
+        println!();
+        let amount: crate::common::NearBalance = Input::new()
+            .with_prompt(format!(
+                "{} (example: 10NEAR or 0.5near or 10000yoctonear)",
+                amount_prompt
+            ))
+            .interact_text()
+            .unwrap();
+        amount.to_yoctonear()
+    }
+
+    /// Fetches the balance of the lockup in its staking pool the method takes the amount from.
+    async fn fetch_staking_pool_balance(
+        network_connection_config: &crate::common::ConnectionConfig,
+        lockup: &super::Lockup,
+        lockup_account_id: &str,
+        method: LockupAmountMethod,
+    ) -> color_eyre::eyre::Result<Option<near_primitives::types::Balance>> {
+        let view_method_name = match method.staking_pool_balance_method_name() {
+            Some(view_method_name) => view_method_name,
+            None => return Ok(None),
+        };
+        let staking_pool_account_id = lockup.check_staking_pool_selected()?;
+        let balance: String = crate::common::call_view_function(
+            network_connection_config,
+            staking_pool_account_id,
+            view_method_name,
+            serde_json::json!({ "account_id": lockup_account_id }),
+        )
+        .await?;
+        let balance = balance
+            .parse::<near_primitives::types::Balance>()
+            .map_err(|err| {
+                color_eyre::Report::msg(format!(
+                    "The balance {:?} returned by <{}> is not a number: {}",
+                    balance, staking_pool_account_id, err
+                ))
+            })?;
+        Ok(Some(balance))
+    }
+
+    /// Checks the amount against the balances of the lockup (and, to unstake or withdraw, its
+    /// balance in the staking pool) before sending the call.
+    fn check_amount(
+        amount: near_primitives::types::Balance,
+        lockup: &super::Lockup,
+        staking_pool_balance: Option<near_primitives::types::Balance>,
+        method: LockupAmountMethod,
+    ) -> color_eyre::eyre::Result<()> {
+        let staking_pool_account_id = lockup.check_staking_pool_selected()?;
+        let not_deposited_balance = lockup
+            .balance
+            .saturating_sub(lockup.known_deposited_balance);
+        match (method, staking_pool_balance) {
+            (LockupAmountMethod::DepositAndStake, _) if amount > not_deposited_balance => {
+                Err(color_eyre::Report::msg(format!(
+                    "Cannot deposit {}: only {} is in the lockup",
+                    crate::common::NearBalance::from_yoctonear(amount),
+                    crate::common::NearBalance::from_yoctonear(not_deposited_balance)
+                )))
+            }
+            (LockupAmountMethod::Unstake, Some(staked_balance)) if amount > staked_balance => {
+                Err(color_eyre::Report::msg(format!(
+                    "Cannot unstake {}: only {} is staked in <{}>",
+                    crate::common::NearBalance::from_yoctonear(amount),
+                    crate::common::NearBalance::from_yoctonear(staked_balance),
+                    staking_pool_account_id
+                )))
+            }
+            (LockupAmountMethod::WithdrawFromStakingPool, Some(unstaked_balance))
+                if amount > unstaked_balance =>
+            {
+                Err(color_eyre::Report::msg(format!(
+                    "Cannot withdraw {}: only {} is unstaked in <{}>",
+                    crate::common::NearBalance::from_yoctonear(amount),
+                    crate::common::NearBalance::from_yoctonear(unstaked_balance),
+                    staking_pool_account_id
+                )))
+            }
+            _ => Ok(()),
+        }
+    }
+
+    pub async fn process(
+        self,
+        prepopulated_unsigned_transaction: near_primitives::transaction::Transaction,
+        network_connection_config: crate::common::ConnectionConfig,
+        method: LockupAmountMethod,
+    ) -> crate::CliResult {
+        let lockup = super::Lockup::fetch_for_owner(
+            &network_connection_config,
+            &prepopulated_unsigned_transaction,
+        )
+        .await?;
+        let staking_pool_balance = LockupAmount::fetch_staking_pool_balance(
+            &network_connection_config,
+            &lockup,
+            &prepopulated_unsigned_transaction.receiver_id,
+            method,
+        )
+        .await?;
+        LockupAmount::check_amount(self.amount, &lockup, staking_pool_balance, method)?;
+        self.sign_option
+            .process_function_call(
+                prepopulated_unsigned_transaction,
+                network_connection_config,
+                method.method_name(),
+                serde_json::json!({ "amount": self.amount.to_string() }),
+                super::LOCKUP_GAS,
+                0,
+            )
+            .await
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    const ONE_NEAR: near_primitives::types::Balance = 10u128.pow(24);
+
+    fn lockup(
+        balance: near_primitives::types::Balance,
+        known_deposited_balance: near_primitives::types::Balance,
+    ) -> super::super::Lockup {
+        super::super::Lockup {
+            owner_account_id: "alice.near".to_string(),
+            balance,
+            locked_amount: 0,
+            owners_balance: balance,
+            liquid_owners_balance: balance,
+            staking_pool_account_id: Some("pool.near".to_string()),
+            known_deposited_balance,
+            are_transfers_enabled: true,
+            vesting_information: serde_json::json!("None"),
+        }
+    }
+
+    #[test]
+    fn check_amount() {
+        // 10 NEAR deposited, the staking pool reports 11 NEAR with the rewards
+        let lockup = lockup(40 * ONE_NEAR, 10 * ONE_NEAR);
+        assert!(LockupAmount::check_amount(
+            30 * ONE_NEAR,
+            &lockup,
+            None,
+            LockupAmountMethod::DepositAndStake
+        )
+        .is_ok());
+        assert!(LockupAmount::check_amount(
+            31 * ONE_NEAR,
+            &lockup,
+            None,
+            LockupAmountMethod::DepositAndStake
+        )
+        .is_err());
+        assert!(LockupAmount::check_amount(
+            11 * ONE_NEAR,
+            &lockup,
+            Some(11 * ONE_NEAR),
+            LockupAmountMethod::Unstake
+        )
+        .is_ok());
+        assert!(LockupAmount::check_amount(
+            12 * ONE_NEAR,
+            &lockup,
+            Some(11 * ONE_NEAR),
+            LockupAmountMethod::Unstake
+        )
+        .is_err());
+        assert!(LockupAmount::check_amount(
+            11 * ONE_NEAR,
+            &lockup,
+            Some(11 * ONE_NEAR),
+            LockupAmountMethod::WithdrawFromStakingPool
+        )
+        .is_ok());
+        assert!(LockupAmount::check_amount(
+            ONE_NEAR,
+            &lockup,
+            Some(0),
+            LockupAmountMethod::WithdrawFromStakingPool
+        )
+        .is_err());
+    }
+}
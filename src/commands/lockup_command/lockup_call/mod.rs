@@ -0,0 +1,96 @@
+/// Sign the call to the lockup
+#[derive(Debug, Default, clap::Clap)]
+pub struct CliLockupCall {
+    #[clap(subcommand)]
+    sign_option: Option<
+        crate::commands::construct_transaction_command::sign_transaction::CliSignTransaction,
+    >,
+}
+
+/// The lockup methods which take no arguments
+#[derive(Debug, Clone, Copy, PartialEq)]
+pub enum LockupCallMethod {
+    UnselectStakingPool,
+    UnstakeAll,
+    WithdrawAllFromStakingPool,
+    RefreshStakingPoolBalance,
+    CheckTransfersVote,
+}
+
+impl LockupCallMethod {
+    fn method_name(&self) -> &'static str {
+        match self {
+            Self::UnselectStakingPool => "unselect_staking_pool",
+            Self::UnstakeAll => "unstake_all",
+            Self::WithdrawAllFromStakingPool => "withdraw_all_from_staking_pool",
+            Self::RefreshStakingPoolBalance => "refresh_staking_pool_balance",
+            Self::CheckTransfersVote => "check_transfers_vote",
+        }
+    }
+}
+
+#[derive(Debug)]
+pub struct LockupCall {
+    sign_option: crate::commands::construct_transaction_command::sign_transaction::SignTransaction,
+}
+
+impl From<CliLockupCall> for LockupCall {
+    fn from(item: CliLockupCall) -> Self {
+        let sign_option = match item.sign_option {
+            Some(cli_sign_transaction) => cli_sign_transaction.into(),
+            None => crate::commands::construct_transaction_command::sign_transaction::SignTransaction::choose_sign_option(),
+        };
+        Self { sign_option }
+    }
+}
+
+impl LockupCall {
+    /// Checks that the lockup is in a state the call can succeed in before sending it.
+    fn check_lockup(
+        lockup: &super::Lockup,
+        method: LockupCallMethod,
+    ) -> color_eyre::eyre::Result<()> {
+        match method {
+            LockupCallMethod::UnselectStakingPool => {
+                let staking_pool_account_id = lockup.check_staking_pool_selected()?;
+                if lockup.known_deposited_balance > 0 {
+                    return Err(color_eyre::Report::msg(format!(
+                        "{} is still deposited to <{}>, withdraw it before unselecting the staking pool",
+                        crate::common::NearBalance::from_yoctonear(lockup.known_deposited_balance),
+                        staking_pool_account_id
+                    )));
+                }
+                Ok(())
+            }
+            LockupCallMethod::CheckTransfersVote if lockup.are_transfers_enabled => {
+                Err(color_eyre::Report::msg("The transfers are already enabled"))
+            }
+            LockupCallMethod::CheckTransfersVote => Ok(()),
+            _ => lockup.check_staking_pool_selected().map(|_| ()),
+        }
+    }
+
+    pub async fn process(
+        self,
+        prepopulated_unsigned_transaction: near_primitives::transaction::Transaction,
+        network_connection_config: crate::common::ConnectionConfig,
+        method: LockupCallMethod,
+    ) -> crate::CliResult {
+        let lockup = super::Lockup::fetch_for_owner(
+            &network_connection_config,
+            &prepopulated_unsigned_transaction,
+        )
+        .await?;
+        Self::check_lockup(&lockup, method)?;
+        self.sign_option
+            .process_function_call(
+                prepopulated_unsigned_transaction,
+                network_connection_config,
+                method.method_name(),
+                serde_json::json!({}),
+                super::LOCKUP_GAS,
+                0,
+            )
+            .await
+    }
+}
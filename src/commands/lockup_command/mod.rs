@@ -0,0 +1,517 @@
+use dialoguer::{theme::ColorfulTheme, Select};
+use strum::{EnumDiscriminants, EnumIter, EnumMessage, IntoEnumIterator};
+
+mod lockup_amount;
+mod lockup_call;
+pub mod operation_mode;
+mod owner;
+mod select_staking_pool;
+mod transfer;
+mod view_lockup;
+
+const LOCKUP_GAS: near_primitives::types::Gas = 125_000_000_000_000;
+
+#[derive(Debug, clap::Clap)]
+pub enum CliLockupAction {
+    /// View the balances, the vesting schedule and the staking pool of the lockup
+    ViewBalances(self::view_lockup::CliViewLockup),
+    /// Select a whitelisted staking pool to stake from the lockup
+    SelectStakingPool(self::select_staking_pool::CliSelectStakingPool),
+    /// Unselect the staking pool (nothing may be deposited to it)
+    UnselectStakingPool(self::lockup_call::CliLockupCall),
+    /// Deposit an amount from the lockup to the staking pool and stake it
+    DepositAndStake(self::lockup_amount::CliLockupAmount),
+    /// Unstake an amount in the staking pool
+    Unstake(self::lockup_amount::CliLockupAmount),
+    /// Unstake the whole staked balance in the staking pool
+    UnstakeAll(self::lockup_call::CliLockupCall),
+    /// Withdraw an amount of the unstaked balance from the staking pool to the lockup
+    WithdrawFromStakingPool(self::lockup_amount::CliLockupAmount),
+    /// Withdraw the whole unstaked balance from the staking pool to the lockup
+    WithdrawAllFromStakingPool(self::lockup_call::CliLockupCall),
+    /// Refresh the balance deposited to the staking pool to account for the rewards
+    RefreshStakingPoolBalance(self::lockup_call::CliLockupCall),
+    /// Check whether the transfers have been voted to be enabled
+    CheckTransfersVote(self::lockup_call::CliLockupCall),
+    /// Transfer an amount of the liquid balance to an account
+    Transfer(self::transfer::CliLockupTransfer),
+}
+
+#[derive(Debug, EnumDiscriminants)]
+#[strum_discriminants(derive(EnumMessage, EnumIter))]
+pub enum LockupAction {
+    #[strum_discriminants(strum(message = "View the balances and the vesting schedule"))]
+    ViewBalances(self::view_lockup::ViewLockup),
+    #[strum_discriminants(strum(message = "Select a staking pool"))]
+    SelectStakingPool(self::select_staking_pool::SelectStakingPool),
+    #[strum_discriminants(strum(message = "Unselect the staking pool"))]
+    UnselectStakingPool(self::lockup_call::LockupCall),
+    #[strum_discriminants(strum(message = "Deposit and stake"))]
+    DepositAndStake(self::lockup_amount::LockupAmount),
+    #[strum_discriminants(strum(message = "Unstake an amount"))]
+    Unstake(self::lockup_amount::LockupAmount),
+    #[strum_discriminants(strum(message = "Unstake all"))]
+    UnstakeAll(self::lockup_call::LockupCall),
+    #[strum_discriminants(strum(message = "Withdraw an amount from the staking pool"))]
+    WithdrawFromStakingPool(self::lockup_amount::LockupAmount),
+    #[strum_discriminants(strum(message = "Withdraw all from the staking pool"))]
+    WithdrawAllFromStakingPool(self::lockup_call::LockupCall),
+    #[strum_discriminants(strum(message = "Refresh the staking pool balance"))]
+    RefreshStakingPoolBalance(self::lockup_call::LockupCall),
+    #[strum_discriminants(strum(message = "Check the transfers vote"))]
+    CheckTransfersVote(self::lockup_call::LockupCall),
+    #[strum_discriminants(strum(message = "Transfer liquid tokens"))]
+    Transfer(self::transfer::LockupTransfer),
+}
+
+impl From<CliLockupAction> for LockupAction {
+    fn from(item: CliLockupAction) -> Self {
+        match item {
+            CliLockupAction::ViewBalances(cli_view_lockup) => {
+                Self::ViewBalances(cli_view_lockup.into())
+            }
+            CliLockupAction::SelectStakingPool(cli_select_staking_pool) => {
+                Self::SelectStakingPool(cli_select_staking_pool.into())
+            }
+            CliLockupAction::UnselectStakingPool(cli_lockup_call) => {
+                Self::UnselectStakingPool(cli_lockup_call.into())
+            }
+            CliLockupAction::DepositAndStake(cli_lockup_amount) => {
+                Self::DepositAndStake(self::lockup_amount::LockupAmount::from(
+                    cli_lockup_amount,
+                    "How many NEAR Tokens do you want to deposit and stake?",
+                ))
+            }
+            CliLockupAction::Unstake(cli_lockup_amount) => {
+                Self::Unstake(self::lockup_amount::LockupAmount::from(
+                    cli_lockup_amount,
+                    "How many NEAR Tokens do you want to unstake?",
+                ))
+            }
+            CliLockupAction::UnstakeAll(cli_lockup_call) => {
+                Self::UnstakeAll(cli_lockup_call.into())
+            }
+            CliLockupAction::WithdrawFromStakingPool(cli_lockup_amount) => {
+                Self::WithdrawFromStakingPool(self::lockup_amount::LockupAmount::from(
+                    cli_lockup_amount,
+                    "How many NEAR Tokens do you want to withdraw from the staking pool?",
+                ))
+            }
+            CliLockupAction::WithdrawAllFromStakingPool(cli_lockup_call) => {
+                Self::WithdrawAllFromStakingPool(cli_lockup_call.into())
+            }
+            CliLockupAction::RefreshStakingPoolBalance(cli_lockup_call) => {
+                Self::RefreshStakingPoolBalance(cli_lockup_call.into())
+            }
+            CliLockupAction::CheckTransfersVote(cli_lockup_call) => {
+                Self::CheckTransfersVote(cli_lockup_call.into())
+            }
+            CliLockupAction::Transfer(cli_lockup_transfer) => {
+                Self::Transfer(cli_lockup_transfer.into())
+            }
+        }
+    }
+}
+
+impl LockupAction {
+    fn choose_lockup_action() -> Self {
+        println!();
+        let variants = LockupActionDiscriminants::iter().collect::<Vec<_>>();
+        let actions = variants
+            .iter()
+            .map(|p| p.get_message().unwrap().to_owned())
+            .collect::<Vec<_>>();
+        let selected_action = Select::with_theme(&ColorfulTheme::default())
+            .with_prompt("What do you want to do with the lockup?")
+            .items(&actions)
+            .default(0)
+            .interact()
+            .unwrap();
+        let cli_lockup_action = match variants[selected_action] {
+            LockupActionDiscriminants::ViewBalances => {
+                CliLockupAction::ViewBalances(Default::default())
+            }
+            LockupActionDiscriminants::SelectStakingPool => {
+                CliLockupAction::SelectStakingPool(Default::default())
+            }
+            LockupActionDiscriminants::UnselectStakingPool => {
+                CliLockupAction::UnselectStakingPool(Default::default())
+            }
+            LockupActionDiscriminants::DepositAndStake => {
+                CliLockupAction::DepositAndStake(Default::default())
+            }
+            LockupActionDiscriminants::Unstake => CliLockupAction::Unstake(Default::default()),
+            LockupActionDiscriminants::UnstakeAll => {
+                CliLockupAction::UnstakeAll(Default::default())
+            }
+            LockupActionDiscriminants::WithdrawFromStakingPool => {
+                CliLockupAction::WithdrawFromStakingPool(Default::default())
+            }
+            LockupActionDiscriminants::WithdrawAllFromStakingPool => {
+                CliLockupAction::WithdrawAllFromStakingPool(Default::default())
+            }
+            LockupActionDiscriminants::RefreshStakingPoolBalance => {
+                CliLockupAction::RefreshStakingPoolBalance(Default::default())
+            }
+            LockupActionDiscriminants::CheckTransfersVote => {
+                CliLockupAction::CheckTransfersVote(Default::default())
+            }
+            LockupActionDiscriminants::Transfer => CliLockupAction::Transfer(Default::default()),
+        };
+        Self::from(cli_lockup_action)
+    }
+
+    async fn process(
+        self,
+        prepopulated_unsigned_transaction: near_primitives::transaction::Transaction,
+        network_connection_config: crate::common::ConnectionConfig,
+    ) -> crate::CliResult {
+        match self {
+            LockupAction::ViewBalances(view_lockup) => {
+                view_lockup
+                    .process(prepopulated_unsigned_transaction, network_connection_config)
+                    .await
+            }
+            LockupAction::SelectStakingPool(select_staking_pool) => {
+                select_staking_pool
+                    .process(prepopulated_unsigned_transaction, network_connection_config)
+                    .await
+            }
+            LockupAction::UnselectStakingPool(lockup_call) => {
+                lockup_call
+                    .process(
+                        prepopulated_unsigned_transaction,
+                        network_connection_config,
+                        self::lockup_call::LockupCallMethod::UnselectStakingPool,
+                    )
+                    .await
+            }
+            LockupAction::DepositAndStake(lockup_amount) => {
+                lockup_amount
+                    .process(
+                        prepopulated_unsigned_transaction,
+                        network_connection_config,
+                        self::lockup_amount::LockupAmountMethod::DepositAndStake,
+                    )
+                    .await
+            }
+            LockupAction::Unstake(lockup_amount) => {
+                lockup_amount
+                    .process(
+                        prepopulated_unsigned_transaction,
+                        network_connection_config,
+                        self::lockup_amount::LockupAmountMethod::Unstake,
+                    )
+                    .await
+            }
+            LockupAction::UnstakeAll(lockup_call) => {
+                lockup_call
+                    .process(
+                        prepopulated_unsigned_transaction,
+                        network_connection_config,
+                        self::lockup_call::LockupCallMethod::UnstakeAll,
+                    )
+                    .await
+            }
+            LockupAction::WithdrawFromStakingPool(lockup_amount) => {
+                lockup_amount
+                    .process(
+                        prepopulated_unsigned_transaction,
+                        network_connection_config,
+                        self::lockup_amount::LockupAmountMethod::WithdrawFromStakingPool,
+                    )
+                    .await
+            }
+            LockupAction::WithdrawAllFromStakingPool(lockup_call) => {
+                lockup_call
+                    .process(
+                        prepopulated_unsigned_transaction,
+                        network_connection_config,
+                        self::lockup_call::LockupCallMethod::WithdrawAllFromStakingPool,
+                    )
+                    .await
+            }
+            LockupAction::RefreshStakingPoolBalance(lockup_call) => {
+                lockup_call
+                    .process(
+                        prepopulated_unsigned_transaction,
+                        network_connection_config,
+                        self::lockup_call::LockupCallMethod::RefreshStakingPoolBalance,
+                    )
+                    .await
+            }
+            LockupAction::CheckTransfersVote(lockup_call) => {
+                lockup_call
+                    .process(
+                        prepopulated_unsigned_transaction,
+                        network_connection_config,
+                        self::lockup_call::LockupCallMethod::CheckTransfersVote,
+                    )
+                    .await
+            }
+            LockupAction::Transfer(lockup_transfer) => {
+                lockup_transfer
+                    .process(prepopulated_unsigned_transaction, network_connection_config)
+                    .await
+            }
+        }
+    }
+}
+
+/// The state of a lockup contract as reported by its view methods.
+#[derive(Debug)]
+struct Lockup {
+    owner_account_id: String,
+    balance: near_primitives::types::Balance,
+    locked_amount: near_primitives::types::Balance,
+    owners_balance: near_primitives::types::Balance,
+    liquid_owners_balance: near_primitives::types::Balance,
+    staking_pool_account_id: Option<String>,
+    known_deposited_balance: near_primitives::types::Balance,
+    are_transfers_enabled: bool,
+    vesting_information: serde_json::Value,
+}
+
+impl Lockup {
+    async fn fetch(
+        network_connection_config: &crate::common::ConnectionConfig,
+        lockup_account_id: &str,
+    ) -> color_eyre::eyre::Result<Self> {
+        let view = |method_name| {
+            crate::common::call_view_function::<String>(
+                network_connection_config,
+                lockup_account_id,
+                method_name,
+                serde_json::json!({}),
+            )
+        };
+        let (
+            owner_account_id,
+            balance,
+            locked_amount,
+            owners_balance,
+            liquid_owners_balance,
+            staking_pool_account_id,
+            known_deposited_balance,
+            are_transfers_enabled,
+            vesting_information,
+        ) = futures::try_join!(
+            view("get_owner_account_id"),
+            view("get_balance"),
+            view("get_locked_amount"),
+            view("get_owners_balance"),
+            view("get_liquid_owners_balance"),
+            crate::common::call_view_function::<Option<String>>(
+                network_connection_config,
+                lockup_account_id,
+                "get_staking_pool_account_id",
+                serde_json::json!({}),
+            ),
+            view("get_known_deposited_balance"),
+            crate::common::call_view_function::<bool>(
+                network_connection_config,
+                lockup_account_id,
+                "are_transfers_enabled",
+                serde_json::json!({}),
+            ),
+            crate::common::call_view_function::<serde_json::Value>(
+                network_connection_config,
+                lockup_account_id,
+                "get_vesting_information",
+                serde_json::json!({}),
+            ),
+        )
+        .map_err(|err| {
+            color_eyre::Report::msg(format!(
+                "Failed to fetch the state of the lockup <{}>: {}",
+                lockup_account_id, err
+            ))
+        })?;
+        let parse_balance = |balance: String| {
+            balance
+                .parse::<near_primitives::types::Balance>()
+                .map_err(|err| {
+                    color_eyre::Report::msg(format!(
+                        "The balance {:?} returned by <{}> is not a number: {}",
+                        balance, lockup_account_id, err
+                    ))
+                })
+        };
+        Ok(Self {
+            owner_account_id,
+            balance: parse_balance(balance)?,
+            locked_amount: parse_balance(locked_amount)?,
+            owners_balance: parse_balance(owners_balance)?,
+            liquid_owners_balance: parse_balance(liquid_owners_balance)?,
+            staking_pool_account_id,
+            known_deposited_balance: parse_balance(known_deposited_balance)?,
+            are_transfers_enabled,
+            vesting_information,
+        })
+    }
+
+    /// Fetches the lockup the transaction is sent to and checks that its signer is the owner,
+    /// since the lockup rejects the calls of any other account.
+    async fn fetch_for_owner(
+        network_connection_config: &crate::common::ConnectionConfig,
+        unsigned_transaction: &near_primitives::transaction::Transaction,
+    ) -> color_eyre::eyre::Result<Self> {
+        let lockup =
+            Self::fetch(network_connection_config, &unsigned_transaction.receiver_id).await?;
+        lockup.print(&unsigned_transaction.receiver_id);
+        if lockup.owner_account_id != unsigned_transaction.signer_id {
+            return Err(color_eyre::Report::msg(format!(
+                "<{}> is not the owner of the lockup <{}>, <{}> is",
+                unsigned_transaction.signer_id,
+                unsigned_transaction.receiver_id,
+                lockup.owner_account_id
+            )));
+        }
+        Ok(lockup)
+    }
+
+    /// Checks that a staking pool is selected before a call to it is sent through the lockup.
+    fn check_staking_pool_selected(&self) -> color_eyre::eyre::Result<&str> {
+        self.staking_pool_account_id
+            .as_deref()
+            .ok_or_else(|| color_eyre::Report::msg("No staking pool is selected, select one first"))
+    }
+
+    fn print(&self, lockup_account_id: &str) {
+        println!(
+            "\nLockup <{}> of <{}>:\n  total balance:  {}\n  locked:         {}\n  unlocked:       {}\n  liquid:         {}\n  vesting:        {}",
+            lockup_account_id,
+            self.owner_account_id,
+            crate::common::NearBalance::from_yoctonear(self.balance),
+            crate::common::NearBalance::from_yoctonear(self.locked_amount),
+            crate::common::NearBalance::from_yoctonear(self.owners_balance),
+            crate::common::NearBalance::from_yoctonear(self.liquid_owners_balance),
+            lockup_vesting_summary(&self.vesting_information),
+        );
+        match &self.staking_pool_account_id {
+            Some(staking_pool_account_id) => println!(
+                "  staking pool:   <{}> ({} deposited)",
+                staking_pool_account_id,
+                crate::common::NearBalance::from_yoctonear(self.known_deposited_balance),
+            ),
+            None => println!("  staking pool:   not selected"),
+        }
+        if self.are_transfers_enabled {
+            println!("  transfers:      enabled");
+        } else {
+            println!("  transfers:      disabled until they are voted to be enabled");
+        }
+    }
+}
+
+/// The lockup account of the owner: the first 20 bytes of the SHA-256 hash of the owner account
+/// ID in hex, under the lockup master account.
+fn lockup_account_id(owner_account_id: &str, lockup_master_account_id: &str) -> String {
+    let owner_account_id_hash = near_primitives::hash::hash(owner_account_id.as_bytes());
+    format!(
+        "{}.{}",
+        hex::encode(&owner_account_id_hash.as_ref()[..20]),
+        lockup_master_account_id
+    )
+}
+
+/// Formats a timestamp in nanoseconds since the Unix epoch as a UTC date and time.
+fn format_timestamp_nanos(timestamp_nanos: u64) -> String {
+    let seconds = timestamp_nanos / 1_000_000_000;
+    let (days, seconds_of_day) = ((seconds / 86400) as i64, seconds % 86400);
+    // Converts the days since 1970-01-01 to a civil date (see http://howardhinnant.github.io/date_algorithms.html)
+    let z = days + 719468;
+    let era = z.div_euclid(146097);
+    let day_of_era = z - era * 146097;
+    let year_of_era =
+        (day_of_era - day_of_era / 1460 + day_of_era / 36524 - day_of_era / 146096) / 365;
+    let day_of_year = day_of_era - (365 * year_of_era + year_of_era / 4 - year_of_era / 100);
+    let month_index = (5 * day_of_year + 2) / 153;
+    let day = day_of_year - (153 * month_index + 2) / 5 + 1;
+    let month = if month_index < 10 {
+        month_index + 3
+    } else {
+        month_index - 9
+    };
+    let year = year_of_era + era * 400 + if month <= 2 { 1 } else { 0 };
+    format!(
+        "{:04}-{:02}-{:02} {:02}:{:02}:{:02} UTC",
+        year,
+        month,
+        day,
+        seconds_of_day / 3600,
+        seconds_of_day % 3600 / 60,
+        seconds_of_day % 60
+    )
+}
+
+/// Describes the vesting information returned by `get_vesting_information` of a lockup contract.
+fn lockup_vesting_summary(vesting_information: &serde_json::Value) -> String {
+    let timestamp = |value: &serde_json::Value| {
+        value
+            .as_str()
+            .and_then(|timestamp| timestamp.parse::<u64>().ok())
+            .map(format_timestamp_nanos)
+            .unwrap_or_else(|| value.to_string())
+    };
+    let balance = |value: &serde_json::Value| {
+        value
+            .as_str()
+            .and_then(|balance| balance.parse::<u128>().ok())
+            .map(|balance| crate::common::NearBalance::from_yoctonear(balance).to_string())
+            .unwrap_or_else(|| value.to_string())
+    };
+    match vesting_information {
+        serde_json::Value::String(kind) if kind == "None" => "no vesting".to_string(),
+        serde_json::Value::Object(vesting_information) => match vesting_information.iter().next() {
+            Some((kind, schedule)) if kind == "VestingSchedule" => format!(
+                "vesting from {}, cliff at {}, fully vested at {}",
+                timestamp(&schedule["start_timestamp"]),
+                timestamp(&schedule["cliff_timestamp"]),
+                timestamp(&schedule["end_timestamp"])
+            ),
+            Some((kind, _)) if kind == "VestingHash" => {
+                "private vesting schedule (only its hash is stored)".to_string()
+            }
+            Some((kind, termination)) if kind == "Terminating" => format!(
+                "vesting is being terminated, {} unvested ({})",
+                balance(&termination["unvested_amount"]),
+                termination["status"]
+            ),
+            _ => serde_json::Value::Object(vesting_information.clone()).to_string(),
+        },
+        vesting_information => vesting_information.to_string(),
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn lockup_account() {
+        assert_eq!(
+            lockup_account_id("alice.near", "lockup.near"),
+            "2dd5dda540767b3a1aa33544bcba38042f4df6de.lockup.near"
+        );
+        assert_eq!(format_timestamp_nanos(0), "1970-01-01 00:00:00 UTC");
+        assert_eq!(
+            format_timestamp_nanos(1_609_462_861_000_000_000),
+            "2021-01-01 01:01:01 UTC"
+        );
+        assert_eq!(
+            lockup_vesting_summary(&serde_json::json!("None")),
+            "no vesting"
+        );
+        assert_eq!(
+            lockup_vesting_summary(&serde_json::json!({
+                "VestingSchedule": {
+                    "start_timestamp": "0",
+                    "cliff_timestamp": "1609462861000000000",
+                    "end_timestamp": "1609462861000000000",
+                }
+            })),
+            "vesting from 1970-01-01 00:00:00 UTC, cliff at 2021-01-01 01:01:01 UTC, fully vested at 2021-01-01 01:01:01 UTC"
+        );
+    }
+}
@@ -0,0 +1,129 @@
+use dialoguer::Input;
+
+#[derive(Debug, clap::Clap)]
+pub enum CliSendTo {
+    /// Specify the owner of the lockup contract
+    Owner(CliOwner),
+}
+
+#[derive(Debug)]
+pub enum SendTo {
+    Owner(Owner),
+}
+
+impl SendTo {
+    pub fn from(
+        item: CliSendTo,
+        network_connection_config: &crate::common::ConnectionConfig,
+    ) -> Self {
+        match item {
+            CliSendTo::Owner(cli_owner) => {
+                let owner = Owner::from(cli_owner, network_connection_config);
+                Self::Owner(owner)
+            }
+        }
+    }
+
+    pub fn send_to(network_connection_config: &crate::common::ConnectionConfig) -> Self {
+        Self::from(
+            CliSendTo::Owner(Default::default()),
+            network_connection_config,
+        )
+    }
+
+    pub async fn process(
+        self,
+        prepopulated_unsigned_transaction: near_primitives::transaction::Transaction,
+        network_connection_config: crate::common::ConnectionConfig,
+    ) -> crate::CliResult {
+        match self {
+            SendTo::Owner(owner) => {
+                owner
+                    .process(prepopulated_unsigned_transaction, network_connection_config)
+                    .await
+            }
+        }
+    }
+}
+
+/// данные о владельце lockup-контракта
+#[derive(Debug, Default, clap::Clap)]
+pub struct CliOwner {
+    owner_account_id: Option<String>,
+    /// The lockup account, if it is not the one derived from the owner account ID
+    #[clap(long)]
+    lockup_account_id: Option<String>,
+    #[clap(subcommand)]
+    lockup_action: Option<super::CliLockupAction>,
+}
+
+#[derive(Debug)]
+pub struct Owner {
+    pub owner_account_id: String,
+    pub lockup_account_id: String,
+    pub lockup_action: super::LockupAction,
+}
+
+impl Owner {
+    fn from(item: CliOwner, network_connection_config: &crate::common::ConnectionConfig) -> Self {
+        let owner_account_id: String = match item.owner_account_id {
+            Some(cli_owner_account_id) => cli_owner_account_id,
+            None => Owner::input_owner_account_id(),
+        };
+        let lockup_account_id: String = match item.lockup_account_id {
+            Some(cli_lockup_account_id) => cli_lockup_account_id,
+            None => match network_connection_config.lockup_master_account_id() {
+                Some(lockup_master_account_id) => {
+                    let lockup_account_id =
+                        super::lockup_account_id(&owner_account_id, lockup_master_account_id);
+                    println!(
+                        "\nThe lockup account of <{}> is <{}>",
+                        owner_account_id, lockup_account_id
+                    );
+                    lockup_account_id
+                }
+                None => Owner::input_lockup_account_id(),
+            },
+        };
+        let lockup_action = match item.lockup_action {
+            Some(cli_lockup_action) => super::LockupAction::from(cli_lockup_action),
+            None => super::LockupAction::choose_lockup_action(),
+        };
+        Self {
+            owner_account_id,
+            lockup_account_id,
+            lockup_action,
+        }
+    }
+
+    fn input_owner_account_id() -> String {
+        println!();
+        Input::new()
+            .with_prompt("What is the account ID of the lockup owner?")
+            .interact_text()
+            .unwrap()
+    }
+
+    fn input_lockup_account_id() -> String {
+        println!();
+        Input::new()
+            .with_prompt("What is the account ID of the lockup contract?")
+            .interact_text()
+            .unwrap()
+    }
+
+    pub async fn process(
+        self,
+        prepopulated_unsigned_transaction: near_primitives::transaction::Transaction,
+        network_connection_config: crate::common::ConnectionConfig,
+    ) -> crate::CliResult {
+        let unsigned_transaction = near_primitives::transaction::Transaction {
+            signer_id: self.owner_account_id.clone(),
+            receiver_id: self.lockup_account_id.clone(),
+            ..prepopulated_unsigned_transaction
+        };
+        self.lockup_action
+            .process(unsigned_transaction, network_connection_config)
+            .await
+    }
+}
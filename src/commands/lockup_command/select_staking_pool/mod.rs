@@ -0,0 +1,101 @@
+use dialoguer::Input;
+
+/// Specify the staking pool
+#[derive(Debug, Default, clap::Clap)]
+pub struct CliSelectStakingPool {
+    staking_pool_account_id: Option<String>,
+    #[clap(subcommand)]
+    sign_option: Option<
+        crate::commands::construct_transaction_command::sign_transaction::CliSignTransaction,
+    >,
+}
+
+#[derive(Debug)]
+pub struct SelectStakingPool {
+    staking_pool_account_id: String,
+    sign_option: crate::commands::construct_transaction_command::sign_transaction::SignTransaction,
+}
+
+impl From<CliSelectStakingPool> for SelectStakingPool {
+    fn from(item: CliSelectStakingPool) -> Self {
+        let staking_pool_account_id: String = match item.staking_pool_account_id {
+            Some(cli_staking_pool_account_id) => cli_staking_pool_account_id,
+            None => SelectStakingPool::input_staking_pool_account_id(),
+        };
+        let sign_option = match item.sign_option {
+            Some(cli_sign_transaction) => cli_sign_transaction.into(),
+            None => crate::commands::construct_transaction_command::sign_transaction::SignTransaction::choose_sign_option(),
+        };
+        Self {
+            staking_pool_account_id,
+            sign_option,
+        }
+    }
+}
+
+impl SelectStakingPool {
+    fn input_staking_pool_account_id() -> String {
+        println!();
+        Input::new()
+            .with_prompt("What is the account ID of the staking pool?")
+            .interact_text()
+            .unwrap()
+    }
+
+    /// Checks that the staking pool is whitelisted for lockups, since the lockup refuses any other.
+    async fn check_whitelisted(
+        &self,
+        network_connection_config: &crate::common::ConnectionConfig,
+    ) -> crate::CliResult {
+        let whitelist_account_id = match network_connection_config.lockup_whitelist_account_id() {
+            Some(whitelist_account_id) => whitelist_account_id,
+            None => {
+                println!("\nWARNING: The staking pool whitelist of this network is unknown, so it is not checked.");
+                return Ok(());
+            }
+        };
+        let is_whitelisted = crate::common::call_view_function::<bool>(
+            network_connection_config,
+            whitelist_account_id,
+            "is_whitelisted",
+            serde_json::json!({ "staking_pool_account_id": self.staking_pool_account_id }),
+        )
+        .await?;
+        if !is_whitelisted {
+            return Err(color_eyre::Report::msg(format!(
+                "The staking pool <{}> is not whitelisted in <{}>",
+                self.staking_pool_account_id, whitelist_account_id
+            )));
+        }
+        Ok(())
+    }
+
+    pub async fn process(
+        self,
+        prepopulated_unsigned_transaction: near_primitives::transaction::Transaction,
+        network_connection_config: crate::common::ConnectionConfig,
+    ) -> crate::CliResult {
+        let lockup = super::Lockup::fetch_for_owner(
+            &network_connection_config,
+            &prepopulated_unsigned_transaction,
+        )
+        .await?;
+        if let Some(staking_pool_account_id) = &lockup.staking_pool_account_id {
+            return Err(color_eyre::Report::msg(format!(
+                "The staking pool <{}> is already selected, unselect it first",
+                staking_pool_account_id
+            )));
+        }
+        self.check_whitelisted(&network_connection_config).await?;
+        self.sign_option
+            .process_function_call(
+                prepopulated_unsigned_transaction,
+                network_connection_config,
+                "select_staking_pool",
+                serde_json::json!({ "staking_pool_account_id": self.staking_pool_account_id }),
+                super::LOCKUP_GAS,
+                0,
+            )
+            .await
+    }
+}
@@ -0,0 +1,97 @@
+use dialoguer::Input;
+
+/// Specify the receiver and the amount
+#[derive(Debug, Default, clap::Clap)]
+pub struct CliLockupTransfer {
+    receiver_account_id: Option<String>,
+    amount: Option<crate::common::NearBalance>,
+    #[clap(subcommand)]
+    sign_option: Option<
+        crate::commands::construct_transaction_command::sign_transaction::CliSignTransaction,
+    >,
+}
+
+#[derive(Debug)]
+pub struct LockupTransfer {
+    receiver_account_id: String,
+    amount: near_primitives::types::Balance,
+    sign_option: crate::commands::construct_transaction_command::sign_transaction::SignTransaction,
+}
+
+impl From<CliLockupTransfer> for LockupTransfer {
+    fn from(item: CliLockupTransfer) -> Self {
+        let receiver_account_id: String = match item.receiver_account_id {
+            Some(cli_receiver_account_id) => cli_receiver_account_id,
+            None => LockupTransfer::input_receiver_account_id(),
+        };
+        let amount: near_primitives::types::Balance = match item.amount {
+            Some(cli_amount) => cli_amount.to_yoctonear(),
+            None => LockupTransfer::input_amount(),
+        };
+        let sign_option = match item.sign_option {
+            Some(cli_sign_transaction) => cli_sign_transaction.into(),
+            None => crate::commands::construct_transaction_command::sign_transaction::SignTransaction::choose_sign_option(),
+        };
+        Self {
+            receiver_account_id,
+            amount,
+            sign_option,
+        }
+    }
+}
+
+impl LockupTransfer {
+    fn input_receiver_account_id() -> String {
+        println!();
+        Input::new()
+            .with_prompt("What is the account ID of the receiver?")
+            .interact_text()
+            .unwrap()
+    }
+
+    fn input_amount() -> near_primitives::types::Balance {
+        println!();
+        let amount: crate::common::NearBalance = Input::new()
+            .with_prompt("How many NEAR Tokens do you want to transfer? (example: 10NEAR or 0.5near or 10000yoctonear)")
+            .interact_text()
+            .unwrap();
+        amount.to_yoctonear()
+    }
+
+    pub async fn process(
+        self,
+        prepopulated_unsigned_transaction: near_primitives::transaction::Transaction,
+        network_connection_config: crate::common::ConnectionConfig,
+    ) -> crate::CliResult {
+        let lockup = super::Lockup::fetch_for_owner(
+            &network_connection_config,
+            &prepopulated_unsigned_transaction,
+        )
+        .await?;
+        if !lockup.are_transfers_enabled {
+            return Err(color_eyre::Report::msg(
+                "The transfers are not enabled yet, check the transfers vote with `check-transfers-vote`",
+            ));
+        }
+        if self.amount > lockup.liquid_owners_balance {
+            return Err(color_eyre::Report::msg(format!(
+                "Cannot transfer {}: only {} is liquid",
+                crate::common::NearBalance::from_yoctonear(self.amount),
+                crate::common::NearBalance::from_yoctonear(lockup.liquid_owners_balance)
+            )));
+        }
+        self.sign_option
+            .process_function_call(
+                prepopulated_unsigned_transaction,
+                network_connection_config,
+                "transfer",
+                serde_json::json!({
+                    "amount": self.amount.to_string(),
+                    "receiver_id": self.receiver_account_id,
+                }),
+                super::LOCKUP_GAS,
+                0,
+            )
+            .await
+    }
+}
@@ -0,0 +1,31 @@
+/// View the state of the lockup
+#[derive(Debug, Default, clap::Clap)]
+pub struct CliViewLockup {}
+
+#[derive(Debug)]
+pub struct ViewLockup {}
+
+impl From<CliViewLockup> for ViewLockup {
+    fn from(_item: CliViewLockup) -> Self {
+        Self {}
+    }
+}
+
+impl ViewLockup {
+    pub async fn process(
+        self,
+        prepopulated_unsigned_transaction: near_primitives::transaction::Transaction,
+        network_connection_config: crate::common::ConnectionConfig,
+    ) -> crate::CliResult {
+        let lockup_account_id = &prepopulated_unsigned_transaction.receiver_id;
+        let lockup = super::Lockup::fetch(&network_connection_config, lockup_account_id).await?;
+        lockup.print(lockup_account_id);
+        if lockup.owner_account_id != prepopulated_unsigned_transaction.signer_id {
+            println!(
+                "\nWARNING: The owner of the lockup is <{}>, not <{}>",
+                lockup.owner_account_id, prepopulated_unsigned_transaction.signer_id
+            );
+        }
+        Ok(())
+    }
+}
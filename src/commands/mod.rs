@@ -3,10 +3,15 @@ use strum::{EnumDiscriminants, EnumIter, EnumMessage, IntoEnumIterator};
 
 pub mod add_command;
 pub mod construct_transaction_command;
+pub mod dao_command;
 pub mod delete_command;
 pub mod execute_command;
 pub mod generate_shell_completions_command;
+pub mod lockup_command;
 pub mod login;
+pub mod multisig_command;
+pub mod staking_command;
+pub mod storage_command;
 pub mod transfer_command;
 pub mod utils_command;
 pub mod view_command;
@@ -17,14 +22,24 @@ pub enum CliTopLevelCommand {
     Add(self::add_command::CliAddAction),
     /// Prepare and, optionally, submit a new transaction
     ConstructTransaction(self::construct_transaction_command::operation_mode::CliOperationMode),
+    /// Use these to manage proposals of a Sputnik DAO
+    Dao(self::dao_command::operation_mode::CliOperationMode),
     /// Use these to delete access key, sub-account
     Delete(self::delete_command::CliDeleteAction),
     /// Execute function (contract method)
     Execute(self::execute_command::CliOptionMethod),
     /// Use these to generate static shell completions
     GenerateShellCompletions(self::generate_shell_completions_command::CliGenerateShellCompletions),
+    /// Use these to manage the tokens of an owner in a lockup contract
+    Lockup(self::lockup_command::operation_mode::CliOperationMode),
     /// Use these to login with wallet authorization
     Login(self::login::operation_mode::CliOperationMode),
+    /// Use these to add, confirm and delete requests of a multisig contract
+    Multisig(self::multisig_command::operation_mode::CliOperationMode),
+    /// Use these to delegate to staking pools
+    Staking(self::staking_command::operation_mode::CliOperationMode),
+    /// Use these to manage storage deposits (NEP-145) of accounts in contracts
+    Storage(self::storage_command::operation_mode::CliOperationMode),
     /// Use these to transfer tokens
     Transfer(self::transfer_command::CliCurrency),
     /// Helpers
@@ -52,6 +67,16 @@ pub enum TopLevelCommand {
     Add(self::add_command::AddAction),
     #[strum_discriminants(strum(message = "Delete access key, account"))]
     Delete(self::delete_command::DeleteAction),
+    #[strum_discriminants(strum(message = "Delegate to staking pools"))]
+    Staking(self::staking_command::operation_mode::OperationMode),
+    #[strum_discriminants(strum(message = "Manage storage deposits (NEP-145)"))]
+    Storage(self::storage_command::operation_mode::OperationMode),
+    #[strum_discriminants(strum(message = "Manage tokens in a lockup contract"))]
+    Lockup(self::lockup_command::operation_mode::OperationMode),
+    #[strum_discriminants(strum(message = "Manage requests of a multisig contract"))]
+    Multisig(self::multisig_command::operation_mode::OperationMode),
+    #[strum_discriminants(strum(message = "Manage proposals of a Sputnik DAO"))]
+    Dao(self::dao_command::operation_mode::OperationMode),
     #[strum_discriminants(strum(message = "Construct a new transaction"))]
     ConstructTransaction(self::construct_transaction_command::operation_mode::OperationMode),
     #[strum_discriminants(strum(message = "Helpers"))]
@@ -65,6 +90,9 @@ impl From<CliTopLevelCommand> for TopLevelCommand {
             CliTopLevelCommand::ConstructTransaction(cli_operation_mode) => {
                 TopLevelCommand::ConstructTransaction(cli_operation_mode.into())
             }
+            CliTopLevelCommand::Dao(cli_operation_mode) => {
+                TopLevelCommand::Dao(cli_operation_mode.into())
+            }
             CliTopLevelCommand::Delete(cli_delete_action) => {
                 TopLevelCommand::Delete(cli_delete_action.into())
             }
@@ -74,9 +102,21 @@ impl From<CliTopLevelCommand> for TopLevelCommand {
             CliTopLevelCommand::GenerateShellCompletions(_) => {
                 unreachable!("This variant is handled in the main function")
             }
+            CliTopLevelCommand::Lockup(cli_operation_mode) => {
+                TopLevelCommand::Lockup(cli_operation_mode.into())
+            }
             CliTopLevelCommand::Login(cli_option_method) => {
                 TopLevelCommand::Login(cli_option_method.into())
             }
+            CliTopLevelCommand::Multisig(cli_operation_mode) => {
+                TopLevelCommand::Multisig(cli_operation_mode.into())
+            }
+            CliTopLevelCommand::Staking(cli_operation_mode) => {
+                TopLevelCommand::Staking(cli_operation_mode.into())
+            }
+            CliTopLevelCommand::Storage(cli_operation_mode) => {
+                TopLevelCommand::Storage(cli_operation_mode.into())
+            }
             CliTopLevelCommand::Transfer(cli_currency) => {
                 TopLevelCommand::Transfer(cli_currency.into())
             }
@@ -107,11 +147,22 @@ impl TopLevelCommand {
             TopLevelCommandDiscriminants::ConstructTransaction => {
                 CliTopLevelCommand::ConstructTransaction(Default::default())
             }
+            TopLevelCommandDiscriminants::Dao => CliTopLevelCommand::Dao(Default::default()),
             TopLevelCommandDiscriminants::Delete => CliTopLevelCommand::Delete(Default::default()),
             TopLevelCommandDiscriminants::Execute => {
                 CliTopLevelCommand::Execute(Default::default())
             }
+            TopLevelCommandDiscriminants::Lockup => CliTopLevelCommand::Lockup(Default::default()),
             TopLevelCommandDiscriminants::Login => CliTopLevelCommand::Login(Default::default()),
+            TopLevelCommandDiscriminants::Multisig => {
+                CliTopLevelCommand::Multisig(Default::default())
+            }
+            TopLevelCommandDiscriminants::Staking => {
+                CliTopLevelCommand::Staking(Default::default())
+            }
+            TopLevelCommandDiscriminants::Storage => {
+                CliTopLevelCommand::Storage(Default::default())
+            }
             TopLevelCommandDiscriminants::Transfer => {
                 CliTopLevelCommand::Transfer(Default::default())
             }
@@ -133,9 +184,14 @@ impl TopLevelCommand {
         match self {
             Self::Add(add_action) => add_action.process(unsigned_transaction).await,
             Self::ConstructTransaction(mode) => mode.process(unsigned_transaction).await,
+            Self::Dao(mode) => mode.process(unsigned_transaction).await,
             Self::Delete(delete_action) => delete_action.process(unsigned_transaction).await,
             Self::Execute(option_method) => option_method.process(unsigned_transaction).await,
+            Self::Lockup(mode) => mode.process(unsigned_transaction).await,
             Self::Login(mode) => mode.process().await,
+            Self::Multisig(mode) => mode.process(unsigned_transaction).await,
+            Self::Staking(mode) => mode.process(unsigned_transaction).await,
+            Self::Storage(mode) => mode.process(unsigned_transaction).await,
             Self::Transfer(currency) => currency.process(unsigned_transaction).await,
             Self::Utils(util_type) => util_type.process().await,
             Self::View(view_query_request) => view_query_request.process().await,
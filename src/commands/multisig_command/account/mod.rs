@@ -0,0 +1,100 @@
+use dialoguer::Input;
+
+#[derive(Debug, clap::Clap)]
+pub enum CliSendTo {
+    /// Specify the multisig account
+    Account(CliAccount),
+}
+
+#[derive(Debug)]
+pub enum SendTo {
+    Account(Account),
+}
+
+impl From<CliSendTo> for SendTo {
+    fn from(item: CliSendTo) -> Self {
+        match item {
+            CliSendTo::Account(cli_account) => {
+                let account = Account::from(cli_account);
+                Self::Account(account)
+            }
+        }
+    }
+}
+
+impl SendTo {
+    pub fn send_to() -> Self {
+        Self::from(CliSendTo::Account(Default::default()))
+    }
+
+    pub async fn process(
+        self,
+        prepopulated_unsigned_transaction: near_primitives::transaction::Transaction,
+        network_connection_config: crate::common::ConnectionConfig,
+    ) -> crate::CliResult {
+        match self {
+            SendTo::Account(account) => {
+                account
+                    .process(prepopulated_unsigned_transaction, network_connection_config)
+                    .await
+            }
+        }
+    }
+}
+
+/// данные о multisig-аккаунте
+#[derive(Debug, Default, clap::Clap)]
+pub struct CliAccount {
+    multisig_account_id: Option<String>,
+    #[clap(subcommand)]
+    multisig_action: Option<super::CliMultisigAction>,
+}
+
+#[derive(Debug)]
+pub struct Account {
+    pub multisig_account_id: String,
+    pub multisig_action: super::MultisigAction,
+}
+
+impl From<CliAccount> for Account {
+    fn from(item: CliAccount) -> Self {
+        let multisig_account_id: String = match item.multisig_account_id {
+            Some(cli_multisig_account_id) => cli_multisig_account_id,
+            None => Account::input_multisig_account_id(),
+        };
+        let multisig_action = match item.multisig_action {
+            Some(cli_multisig_action) => super::MultisigAction::from(cli_multisig_action),
+            None => super::MultisigAction::choose_multisig_action(),
+        };
+        Self {
+            multisig_account_id,
+            multisig_action,
+        }
+    }
+}
+
+impl Account {
+    pub fn input_multisig_account_id() -> String {
+        println!();
+        Input::new()
+            .with_prompt("What is the account ID of the multisig contract?")
+            .interact_text()
+            .unwrap()
+    }
+
+    pub async fn process(
+        self,
+        prepopulated_unsigned_transaction: near_primitives::transaction::Transaction,
+        network_connection_config: crate::common::ConnectionConfig,
+    ) -> crate::CliResult {
+        // The requests are added and confirmed with the keys of the multisig account itself.
+        let unsigned_transaction = near_primitives::transaction::Transaction {
+            signer_id: self.multisig_account_id.clone(),
+            receiver_id: self.multisig_account_id.clone(),
+            ..prepopulated_unsigned_transaction
+        };
+        self.multisig_action
+            .process(unsigned_transaction, network_connection_config)
+            .await
+    }
+}
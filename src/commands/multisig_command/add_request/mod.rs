@@ -0,0 +1,212 @@
+use dialoguer::{theme::ColorfulTheme, Input, Select};
+use strum::{EnumDiscriminants, EnumIter, EnumMessage, IntoEnumIterator};
+
+use crate::commands::construct_transaction_command::transaction_actions;
+
+/// Specify the receiver and the actions of the request
+#[derive(Debug, Default, clap::Clap)]
+pub struct CliAddRequest {
+    receiver_account_id: Option<String>,
+    #[clap(subcommand)]
+    action: Option<CliRequestAction>,
+}
+
+#[derive(Debug)]
+pub struct AddRequest {
+    receiver_account_id: String,
+    action: RequestAction,
+}
+
+impl From<CliAddRequest> for AddRequest {
+    fn from(item: CliAddRequest) -> Self {
+        let receiver_account_id: String = match item.receiver_account_id {
+            Some(cli_receiver_account_id) => cli_receiver_account_id,
+            None => AddRequest::input_receiver_account_id(),
+        };
+        // The actions are entered with the transaction builders, whose next-action prompts also
+        // offer the actions a request cannot contain
+        println!(
+            "\nA multisig request cannot contain Stake and DeleteAccount actions: do not add them as the next actions, the request is rejected before it is signed"
+        );
+        let action = match item.action {
+            Some(cli_request_action) => RequestAction::from(cli_request_action),
+            None => RequestAction::choose_request_action(),
+        };
+        Self {
+            receiver_account_id,
+            action,
+        }
+    }
+}
+
+impl AddRequest {
+    fn input_receiver_account_id() -> String {
+        println!();
+        Input::new()
+            .with_prompt("What is the account ID of the receiver of the request?")
+            .interact_text()
+            .unwrap()
+    }
+
+    pub async fn process(
+        self,
+        prepopulated_unsigned_transaction: near_primitives::transaction::Transaction,
+        network_connection_config: crate::common::ConnectionConfig,
+    ) -> crate::CliResult {
+        let multisig_account_id = prepopulated_unsigned_transaction.receiver_id.clone();
+        let unsigned_transaction = near_primitives::transaction::Transaction {
+            receiver_id: self.receiver_account_id.clone(),
+            ..prepopulated_unsigned_transaction
+        };
+        let (request_transaction, skip_action) = self
+            .action
+            .process(
+                unsigned_transaction,
+                Some(network_connection_config.clone()),
+            )
+            .await?;
+        let request = super::multisig_request(
+            &request_transaction.receiver_id,
+            &request_transaction.actions,
+        )
+        .map_err(color_eyre::Report::msg)?;
+        let unsigned_transaction = near_primitives::transaction::Transaction {
+            receiver_id: multisig_account_id,
+            actions: vec![],
+            ..request_transaction
+        };
+        skip_action
+            .sign_option
+            .process_function_call(
+                unsigned_transaction,
+                network_connection_config,
+                "add_request",
+                serde_json::json!({ "request": request }),
+                super::MULTISIG_GAS,
+                0,
+            )
+            .await
+    }
+}
+
+/// The actions a multisig contract request can contain: the contract does not support
+/// Stake and DeleteAccount actions.
+#[derive(Debug, clap::Clap)]
+pub enum CliRequestAction {
+    /// Transfer NEAR Tokens
+    TransferNEARTokens(transaction_actions::transfer_near_tokens_type::CliTransferNEARTokensAction),
+    /// Call a function
+    CallFunction(transaction_actions::call_function_type::CliCallFunctionAction),
+    /// Create an account
+    CreateAccount(transaction_actions::create_account_type::CliCreateAccountAction),
+    /// Add an access key
+    AddAccessKey(transaction_actions::add_access_key_mode::CliAddAccessKeyMode),
+    /// Delete an access key
+    DeleteAccessKey(transaction_actions::delete_access_key_type::CliDeleteAccessKeyAction),
+}
+
+#[derive(Debug, EnumDiscriminants)]
+#[strum_discriminants(derive(EnumMessage, EnumIter))]
+pub enum RequestAction {
+    #[strum_discriminants(strum(message = "Transfer NEAR Tokens"))]
+    TransferNEARTokens(transaction_actions::transfer_near_tokens_type::TransferNEARTokensAction),
+    #[strum_discriminants(strum(message = "Call a Function"))]
+    CallFunction(transaction_actions::call_function_type::CallFunctionAction),
+    #[strum_discriminants(strum(message = "Create an Account"))]
+    CreateAccount(transaction_actions::create_account_type::CreateAccountAction),
+    #[strum_discriminants(strum(message = "Add an Access Key"))]
+    AddAccessKey(transaction_actions::add_access_key_mode::AddAccessKeyMode),
+    #[strum_discriminants(strum(message = "Delete an Access Key"))]
+    DeleteAccessKey(transaction_actions::delete_access_key_type::DeleteAccessKeyAction),
+}
+
+impl From<CliRequestAction> for RequestAction {
+    fn from(item: CliRequestAction) -> Self {
+        match item {
+            CliRequestAction::TransferNEARTokens(cli_transfer_near_token) => {
+                Self::TransferNEARTokens(cli_transfer_near_token.into())
+            }
+            CliRequestAction::CallFunction(cli_call_function) => {
+                Self::CallFunction(cli_call_function.into())
+            }
+            CliRequestAction::CreateAccount(cli_create_account) => {
+                Self::CreateAccount(cli_create_account.into())
+            }
+            CliRequestAction::AddAccessKey(cli_add_access_key) => {
+                Self::AddAccessKey(cli_add_access_key.into())
+            }
+            CliRequestAction::DeleteAccessKey(cli_delete_access_key) => {
+                Self::DeleteAccessKey(cli_delete_access_key.into())
+            }
+        }
+    }
+}
+
+impl RequestAction {
+    fn choose_request_action() -> Self {
+        println!();
+        let variants = RequestActionDiscriminants::iter().collect::<Vec<_>>();
+        let actions = variants
+            .iter()
+            .map(|p| p.get_message().unwrap().to_owned())
+            .collect::<Vec<_>>();
+        let selected_action = Select::with_theme(&ColorfulTheme::default())
+            .with_prompt("Select an action that you want to request:")
+            .items(&actions)
+            .default(0)
+            .interact()
+            .unwrap();
+        let cli_request_action = match variants[selected_action] {
+            RequestActionDiscriminants::TransferNEARTokens => {
+                CliRequestAction::TransferNEARTokens(Default::default())
+            }
+            RequestActionDiscriminants::CallFunction => {
+                CliRequestAction::CallFunction(Default::default())
+            }
+            RequestActionDiscriminants::CreateAccount => {
+                CliRequestAction::CreateAccount(Default::default())
+            }
+            RequestActionDiscriminants::AddAccessKey => {
+                CliRequestAction::AddAccessKey(Default::default())
+            }
+            RequestActionDiscriminants::DeleteAccessKey => {
+                CliRequestAction::DeleteAccessKey(Default::default())
+            }
+        };
+        Self::from(cli_request_action)
+    }
+
+    async fn process(
+        self,
+        prepopulated_unsigned_transaction: near_primitives::transaction::Transaction,
+        network_connection_config: Option<crate::common::ConnectionConfig>,
+    ) -> transaction_actions::CollectedTransaction {
+        match self {
+            RequestAction::TransferNEARTokens(args_transfer) => {
+                args_transfer
+                    .process(prepopulated_unsigned_transaction, network_connection_config)
+                    .await
+            }
+            RequestAction::CallFunction(args_function) => {
+                args_function
+                    .process(prepopulated_unsigned_transaction, network_connection_config)
+                    .await
+            }
+            RequestAction::CreateAccount(args_create_account) => {
+                args_create_account
+                    .process(prepopulated_unsigned_transaction, network_connection_config)
+                    .await
+            }
+            RequestAction::AddAccessKey(args_add_access_key) => {
+                args_add_access_key
+                    .process(prepopulated_unsigned_transaction, network_connection_config)
+                    .await
+            }
+            RequestAction::DeleteAccessKey(args_delete_access_key) => {
+                args_delete_access_key
+                    .process(prepopulated_unsigned_transaction, network_connection_config)
+                    .await
+            }
+        }
+    }
+}
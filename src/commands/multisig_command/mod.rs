@@ -0,0 +1,413 @@
+use dialoguer::{theme::ColorfulTheme, Select};
+use strum::{EnumDiscriminants, EnumIter, EnumMessage, IntoEnumIterator};
+
+mod account;
+mod add_request;
+pub mod operation_mode;
+mod request_call;
+mod view_requests;
+
+/// The gas attached to the calls to a multisig contract; a confirmation that completes a request
+/// executes it, so the request actions are paid from it as well.
+const MULTISIG_GAS: near_primitives::types::Gas = 250_000_000_000_000;
+
+#[derive(Debug, clap::Clap)]
+pub enum CliMultisigAction {
+    /// View the pending requests and their confirmations
+    ViewRequests(self::view_requests::CliViewRequests),
+    /// Add a request to send a transaction from the multisig account
+    AddRequest(self::add_request::CliAddRequest),
+    /// Confirm a request
+    Confirm(self::request_call::CliRequestCall),
+    /// Delete a request
+    DeleteRequest(self::request_call::CliRequestCall),
+}
+
+#[derive(Debug, EnumDiscriminants)]
+#[strum_discriminants(derive(EnumMessage, EnumIter))]
+pub enum MultisigAction {
+    #[strum_discriminants(strum(message = "View the pending requests"))]
+    ViewRequests(self::view_requests::ViewRequests),
+    #[strum_discriminants(strum(message = "Add a request"))]
+    AddRequest(self::add_request::AddRequest),
+    #[strum_discriminants(strum(message = "Confirm a request"))]
+    Confirm(self::request_call::RequestCall),
+    #[strum_discriminants(strum(message = "Delete a request"))]
+    DeleteRequest(self::request_call::RequestCall),
+}
+
+impl From<CliMultisigAction> for MultisigAction {
+    fn from(item: CliMultisigAction) -> Self {
+        match item {
+            CliMultisigAction::ViewRequests(cli_view_requests) => {
+                Self::ViewRequests(cli_view_requests.into())
+            }
+            CliMultisigAction::AddRequest(cli_add_request) => {
+                Self::AddRequest(cli_add_request.into())
+            }
+            CliMultisigAction::Confirm(cli_request_call) => {
+                Self::Confirm(self::request_call::RequestCall::from(
+                    cli_request_call,
+                    "Which request do you want to confirm?",
+                ))
+            }
+            CliMultisigAction::DeleteRequest(cli_request_call) => {
+                Self::DeleteRequest(self::request_call::RequestCall::from(
+                    cli_request_call,
+                    "Which request do you want to delete?",
+                ))
+            }
+        }
+    }
+}
+
+impl MultisigAction {
+    fn choose_multisig_action() -> Self {
+        println!();
+        let variants = MultisigActionDiscriminants::iter().collect::<Vec<_>>();
+        let actions = variants
+            .iter()
+            .map(|p| p.get_message().unwrap().to_owned())
+            .collect::<Vec<_>>();
+        let selected_action = Select::with_theme(&ColorfulTheme::default())
+            .with_prompt("What do you want to do with the multisig account?")
+            .items(&actions)
+            .default(0)
+            .interact()
+            .unwrap();
+        let cli_multisig_action = match variants[selected_action] {
+            MultisigActionDiscriminants::ViewRequests => {
+                CliMultisigAction::ViewRequests(Default::default())
+            }
+            MultisigActionDiscriminants::AddRequest => {
+                CliMultisigAction::AddRequest(Default::default())
+            }
+            MultisigActionDiscriminants::Confirm => CliMultisigAction::Confirm(Default::default()),
+            MultisigActionDiscriminants::DeleteRequest => {
+                CliMultisigAction::DeleteRequest(Default::default())
+            }
+        };
+        Self::from(cli_multisig_action)
+    }
+
+    async fn process(
+        self,
+        prepopulated_unsigned_transaction: near_primitives::transaction::Transaction,
+        network_connection_config: crate::common::ConnectionConfig,
+    ) -> crate::CliResult {
+        match self {
+            MultisigAction::ViewRequests(view_requests) => {
+                view_requests
+                    .process(prepopulated_unsigned_transaction, network_connection_config)
+                    .await
+            }
+            MultisigAction::AddRequest(add_request) => {
+                add_request
+                    .process(prepopulated_unsigned_transaction, network_connection_config)
+                    .await
+            }
+            MultisigAction::Confirm(request_call) => {
+                request_call
+                    .process(
+                        prepopulated_unsigned_transaction,
+                        network_connection_config,
+                        "confirm",
+                    )
+                    .await
+            }
+            MultisigAction::DeleteRequest(request_call) => {
+                request_call
+                    .process(
+                        prepopulated_unsigned_transaction,
+                        network_connection_config,
+                        "delete_request",
+                    )
+                    .await
+            }
+        }
+    }
+}
+
+async fn fetch_num_confirmations(
+    network_connection_config: &crate::common::ConnectionConfig,
+    multisig_account_id: &str,
+) -> color_eyre::eyre::Result<u32> {
+    crate::common::call_view_function(
+        network_connection_config,
+        multisig_account_id,
+        "get_num_confirmations",
+        serde_json::json!({}),
+    )
+    .await
+}
+
+/// A pending request of a multisig contract with the keys that have confirmed it.
+#[derive(Debug)]
+struct PendingRequest {
+    request_id: u32,
+    request: MultisigRequest,
+    confirmations: Vec<String>,
+}
+
+impl PendingRequest {
+    async fn fetch(
+        network_connection_config: &crate::common::ConnectionConfig,
+        multisig_account_id: &str,
+        request_id: u32,
+    ) -> color_eyre::eyre::Result<Self> {
+        let args = serde_json::json!({ "request_id": request_id });
+        let (request, confirmations) = futures::try_join!(
+            crate::common::call_view_function::<MultisigRequest>(
+                network_connection_config,
+                multisig_account_id,
+                "get_request",
+                args.clone(),
+            ),
+            crate::common::call_view_function::<Vec<String>>(
+                network_connection_config,
+                multisig_account_id,
+                "get_confirmations",
+                args.clone(),
+            ),
+        )
+        .map_err(|err| {
+            color_eyre::Report::msg(format!(
+                "Failed to fetch the request #{} of <{}>: {}",
+                request_id, multisig_account_id, err
+            ))
+        })?;
+        Ok(Self {
+            request_id,
+            request,
+            confirmations,
+        })
+    }
+
+    fn print(&self, num_confirmations: u32) {
+        println!(
+            "\nRequest #{} to <{}>:",
+            self.request_id, self.request.receiver_id
+        );
+        for action in self.request.actions.iter() {
+            println!("  {}", multisig_request_action_summary(action));
+        }
+        println!(
+            "Confirmations: {} of {} ({} more needed)",
+            self.confirmations.len(),
+            num_confirmations,
+            (num_confirmations as usize).saturating_sub(self.confirmations.len())
+        );
+        for public_key in self.confirmations.iter() {
+            println!("  confirmed by {}", public_key);
+        }
+    }
+}
+
+/// Converts a transaction action to an action of a multisig contract request (`MultiSigRequestAction`).
+fn multisig_request_action(
+    action: &near_primitives::transaction::Action,
+) -> Result<serde_json::Value, String> {
+    match action {
+        near_primitives::transaction::Action::CreateAccount(_) => {
+            Ok(serde_json::json!({ "type": "CreateAccount" }))
+        }
+        near_primitives::transaction::Action::DeployContract(deploy_contract_action) => {
+            Ok(serde_json::json!({
+                "type": "DeployContract",
+                "code": near_primitives::serialize::to_base64(&deploy_contract_action.code),
+            }))
+        }
+        near_primitives::transaction::Action::FunctionCall(function_call_action) => {
+            Ok(serde_json::json!({
+                "type": "FunctionCall",
+                "method_name": function_call_action.method_name,
+                "args": near_primitives::serialize::to_base64(&function_call_action.args),
+                "deposit": function_call_action.deposit.to_string(),
+                "gas": function_call_action.gas.to_string(),
+            }))
+        }
+        near_primitives::transaction::Action::Transfer(transfer_action) => Ok(serde_json::json!({
+            "type": "Transfer",
+            "amount": transfer_action.deposit.to_string(),
+        })),
+        near_primitives::transaction::Action::AddKey(add_key_action) => {
+            let permission = match &add_key_action.access_key.permission {
+                near_primitives::account::AccessKeyPermission::FullAccess => {
+                    serde_json::Value::Null
+                }
+                near_primitives::account::AccessKeyPermission::FunctionCall(
+                    function_call_permission,
+                ) => serde_json::json!({
+                    "allowance": function_call_permission
+                        .allowance
+                        .map(|allowance| allowance.to_string()),
+                    "receiver_id": function_call_permission.receiver_id,
+                    "method_names": function_call_permission.method_names,
+                }),
+            };
+            Ok(serde_json::json!({
+                "type": "AddKey",
+                "public_key": add_key_action.public_key.to_string(),
+                "permission": permission,
+            }))
+        }
+        near_primitives::transaction::Action::DeleteKey(delete_key_action) => {
+            Ok(serde_json::json!({
+                "type": "DeleteKey",
+                "public_key": delete_key_action.public_key.to_string(),
+            }))
+        }
+        near_primitives::transaction::Action::Stake(_) => {
+            Err("Stake actions cannot be requested from a multisig contract".to_string())
+        }
+        near_primitives::transaction::Action::DeleteAccount(_) => {
+            Err("Delete account actions cannot be requested from a multisig contract".to_string())
+        }
+    }
+}
+
+/// A request of a multisig contract as returned by `get_request`.
+#[derive(Debug, Clone, serde::Deserialize)]
+struct MultisigRequest {
+    pub receiver_id: String,
+    pub actions: Vec<serde_json::Value>,
+}
+
+/// Builds the `request` argument of `add_request` to the receiver from the actions of a transaction.
+fn multisig_request(
+    receiver_id: &str,
+    actions: &[near_primitives::transaction::Action],
+) -> Result<serde_json::Value, String> {
+    if actions.is_empty() {
+        return Err("A multisig request needs at least one action".to_string());
+    }
+    Ok(serde_json::json!({
+        "receiver_id": receiver_id,
+        "actions": actions
+            .iter()
+            .map(multisig_request_action)
+            .collect::<Result<Vec<_>, _>>()?,
+    }))
+}
+
+/// Describes an action of a multisig contract request.
+fn multisig_request_action_summary(action: &serde_json::Value) -> String {
+    let balance = |value: &serde_json::Value| {
+        value
+            .as_str()
+            .and_then(|balance| balance.parse::<u128>().ok())
+            .map(|balance| crate::common::NearBalance::from_yoctonear(balance).to_string())
+            .unwrap_or_else(|| value.to_string())
+    };
+    match action["type"].as_str() {
+        Some("Transfer") => format!("transfer {}", balance(&action["amount"])),
+        Some("FunctionCall") => {
+            let args = action["args"]
+                .as_str()
+                .and_then(|args| near_primitives::serialize::from_base64(args).ok())
+                .map(|args| String::from_utf8_lossy(&args).into_owned())
+                .unwrap_or_default();
+            format!(
+                "call {}({}) with deposit {}",
+                action["method_name"].as_str().unwrap_or_default(),
+                args,
+                balance(&action["deposit"])
+            )
+        }
+        Some("AddKey") if action["permission"].is_null() => format!(
+            "add full access key {}",
+            action["public_key"].as_str().unwrap_or_default()
+        ),
+        Some("AddKey") => format!(
+            "add function call key {} for <{}>",
+            action["public_key"].as_str().unwrap_or_default(),
+            action["permission"]["receiver_id"]
+                .as_str()
+                .unwrap_or_default()
+        ),
+        Some("DeleteKey") => format!(
+            "delete key {}",
+            action["public_key"].as_str().unwrap_or_default()
+        ),
+        Some("CreateAccount") => "create account".to_string(),
+        Some("DeployContract") => "deploy contract".to_string(),
+        Some("SetNumConfirmations") => format!(
+            "set the number of confirmations to {}",
+            action["num_confirmations"]
+        ),
+        Some("SetActiveRequestsLimit") => format!(
+            "set the active requests limit to {}",
+            action["active_requests_limit"]
+        ),
+        _ => action.to_string(),
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn multisig_request_payload() {
+        let public_key: near_crypto::PublicKey =
+            "ed25519:DReZmNmnGhpsYcCFFeYgPsJ9YCm9xH16GGujCPe3KQEq"
+                .parse()
+                .unwrap();
+        let request = multisig_request(
+            "bob.near",
+            &[
+                near_primitives::transaction::Action::Transfer(
+                    near_primitives::transaction::TransferAction {
+                        deposit: 10u128.pow(24),
+                    },
+                ),
+                near_primitives::transaction::Action::FunctionCall(
+                    near_primitives::transaction::FunctionCallAction {
+                        method_name: "ping".to_string(),
+                        args: b"{}".to_vec(),
+                        gas: 5_000_000_000_000,
+                        deposit: 0,
+                    },
+                ),
+                near_primitives::transaction::Action::DeleteKey(
+                    near_primitives::transaction::DeleteKeyAction {
+                        public_key: public_key.clone(),
+                    },
+                ),
+            ],
+        )
+        .unwrap();
+        assert_eq!(
+            request,
+            serde_json::json!({
+                "receiver_id": "bob.near",
+                "actions": [
+                    {"type": "Transfer", "amount": "1000000000000000000000000"},
+                    {
+                        "type": "FunctionCall",
+                        "method_name": "ping",
+                        "args": "e30=",
+                        "deposit": "0",
+                        "gas": "5000000000000",
+                    },
+                    {"type": "DeleteKey", "public_key": public_key.to_string()},
+                ],
+            })
+        );
+        assert_eq!(
+            multisig_request_action_summary(&request["actions"][1]),
+            "call ping({}) with deposit 0 NEAR"
+        );
+        assert!(multisig_request("bob.near", &[]).is_err());
+        assert!(multisig_request(
+            "bob.near",
+            &[near_primitives::transaction::Action::Stake(
+                near_primitives::transaction::StakeAction {
+                    stake: 0,
+                    public_key,
+                },
+            )],
+        )
+        .is_err());
+    }
+}
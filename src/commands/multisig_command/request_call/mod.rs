@@ -0,0 +1,75 @@
+use dialoguer::Input;
+
+/// Specify the request
+#[derive(Debug, Default, clap::Clap)]
+pub struct CliRequestCall {
+    request_id: Option<u32>,
+    #[clap(subcommand)]
+    sign_option: Option<
+        crate::commands::construct_transaction_command::sign_transaction::CliSignTransaction,
+    >,
+}
+
+#[derive(Debug)]
+pub struct RequestCall {
+    request_id: u32,
+    sign_option: crate::commands::construct_transaction_command::sign_transaction::SignTransaction,
+}
+
+impl RequestCall {
+    pub fn from(item: CliRequestCall, request_id_prompt: &str) -> Self {
+        let request_id: u32 = match item.request_id {
+            Some(cli_request_id) => cli_request_id,
+            None => RequestCall::input_request_id(request_id_prompt),
+        };
+        let sign_option = match item.sign_option {
+            Some(cli_sign_transaction) => cli_sign_transaction.into(),
+            None => crate::commands::construct_transaction_command::sign_transaction::SignTransaction::choose_sign_option(),
+        };
+        Self {
+            request_id,
+            sign_option,
+        }
+    }
+
+    fn input_request_id(request_id_prompt: &str) -> u32 {
+        println!();
+        Input::new()
+            .with_prompt(format!("{} (request ID)", request_id_prompt))
+            .interact_text()
+            .unwrap()
+    }
+
+    pub async fn process(
+        self,
+        prepopulated_unsigned_transaction: near_primitives::transaction::Transaction,
+        network_connection_config: crate::common::ConnectionConfig,
+        method_name: &str,
+    ) -> crate::CliResult {
+        let multisig_account_id = &prepopulated_unsigned_transaction.receiver_id;
+        let (pending_request, num_confirmations) = futures::try_join!(
+            super::PendingRequest::fetch(
+                &network_connection_config,
+                multisig_account_id,
+                self.request_id,
+            ),
+            super::fetch_num_confirmations(&network_connection_config, multisig_account_id),
+        )?;
+        pending_request.print(num_confirmations);
+        if method_name == "confirm"
+            && pending_request.confirmations.len() + 1 >= num_confirmations as usize
+        {
+            println!("\nThis confirmation completes the request, so it is executed right away.");
+        }
+        self.sign_option
+            .process_function_call(
+                prepopulated_unsigned_transaction,
+                network_connection_config,
+                method_name,
+                serde_json::json!({ "request_id": self.request_id }),
+                super::MULTISIG_GAS,
+                0,
+            )
+            .await
+    }
+}
@@ -0,0 +1,55 @@
+/// View the pending requests
+#[derive(Debug, Default, clap::Clap)]
+pub struct CliViewRequests {}
+
+#[derive(Debug)]
+pub struct ViewRequests {}
+
+impl From<CliViewRequests> for ViewRequests {
+    fn from(_item: CliViewRequests) -> Self {
+        Self {}
+    }
+}
+
+impl ViewRequests {
+    pub async fn process(
+        self,
+        prepopulated_unsigned_transaction: near_primitives::transaction::Transaction,
+        network_connection_config: crate::common::ConnectionConfig,
+    ) -> crate::CliResult {
+        let multisig_account_id = &prepopulated_unsigned_transaction.receiver_id;
+        let (mut request_ids, num_confirmations) = futures::try_join!(
+            crate::common::call_view_function::<Vec<u32>>(
+                &network_connection_config,
+                multisig_account_id,
+                "list_request_ids",
+                serde_json::json!({}),
+            ),
+            super::fetch_num_confirmations(&network_connection_config, multisig_account_id),
+        )?;
+        if request_ids.is_empty() {
+            println!("\n<{}> has no pending requests.", multisig_account_id);
+            return Ok(());
+        }
+        request_ids.sort();
+        let pending_requests =
+            futures::future::try_join_all(request_ids.into_iter().map(|request_id| {
+                super::PendingRequest::fetch(
+                    &network_connection_config,
+                    multisig_account_id,
+                    request_id,
+                )
+            }))
+            .await?;
+        println!(
+            "\n<{}> has {} pending requests, each needs {} confirmations:",
+            multisig_account_id,
+            pending_requests.len(),
+            num_confirmations
+        );
+        for pending_request in pending_requests.iter() {
+            pending_request.print(num_confirmations);
+        }
+        Ok(())
+    }
+}
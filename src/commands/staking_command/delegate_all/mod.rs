@@ -0,0 +1,146 @@
+use dialoguer::Input;
+
+/// Specify the delegator account
+#[derive(Debug, Default, clap::Clap)]
+pub struct CliDelegateAll {
+    account_id: Option<String>,
+    #[clap(subcommand)]
+    sign_option: Option<
+        crate::commands::construct_transaction_command::sign_transaction::CliSignTransaction,
+    >,
+}
+
+/// The staking pool methods which take the whole balance
+#[derive(Debug, Clone, Copy, PartialEq)]
+pub enum DelegateAllMethod {
+    UnstakeAll,
+    WithdrawAll,
+}
+
+impl DelegateAllMethod {
+    fn method_name(&self) -> &'static str {
+        match self {
+            Self::UnstakeAll => "unstake_all",
+            Self::WithdrawAll => "withdraw_all",
+        }
+    }
+}
+
+#[derive(Debug)]
+pub struct DelegateAll {
+    account_id: String,
+    sign_option: crate::commands::construct_transaction_command::sign_transaction::SignTransaction,
+}
+
+impl From<CliDelegateAll> for DelegateAll {
+    fn from(item: CliDelegateAll) -> Self {
+        let account_id: String = match item.account_id {
+            Some(cli_account_id) => cli_account_id,
+            None => DelegateAll::input_account_id(),
+        };
+        let sign_option = match item.sign_option {
+            Some(cli_sign_transaction) => cli_sign_transaction.into(),
+            None => crate::commands::construct_transaction_command::sign_transaction::SignTransaction::choose_sign_option(),
+        };
+        Self {
+            account_id,
+            sign_option,
+        }
+    }
+}
+
+impl DelegateAll {
+    fn input_account_id() -> String {
+        println!();
+        Input::new()
+            .with_prompt("What is the account ID of the delegator?")
+            .interact_text()
+            .unwrap()
+    }
+
+    /// Checks that there is something to unstake or withdraw before sending the call.
+    fn check_balances(
+        delegation: &super::Delegation,
+        method: DelegateAllMethod,
+    ) -> color_eyre::eyre::Result<()> {
+        match method {
+            DelegateAllMethod::UnstakeAll if delegation.staked_balance == 0 => {
+                Err(color_eyre::Report::msg("There is nothing to unstake"))
+            }
+            DelegateAllMethod::WithdrawAll if delegation.unstaked_balance == 0 => {
+                Err(color_eyre::Report::msg("There is nothing to withdraw"))
+            }
+            DelegateAllMethod::WithdrawAll if !delegation.is_unstaked_balance_available => {
+                Err(color_eyre::Report::msg(format!(
+                    "The unstaked balance cannot be withdrawn in the current epoch yet (it is unlocked {} epochs after the unstake)",
+                    super::NUM_EPOCHS_TO_UNLOCK
+                )))
+            }
+            _ => Ok(()),
+        }
+    }
+
+    pub async fn process(
+        self,
+        prepopulated_unsigned_transaction: near_primitives::transaction::Transaction,
+        network_connection_config: crate::common::ConnectionConfig,
+        method: DelegateAllMethod,
+    ) -> crate::CliResult {
+        match super::Delegation::fetch(
+            &network_connection_config,
+            &prepopulated_unsigned_transaction.receiver_id,
+            &self.account_id,
+        )
+        .await
+        {
+            Ok(delegation) => {
+                delegation.print(
+                    &prepopulated_unsigned_transaction.receiver_id,
+                    &self.account_id,
+                );
+                DelegateAll::check_balances(&delegation, method)?;
+            }
+            Err(err) => println!("\nWARNING: Failed to fetch the balances: {}", err),
+        }
+        let unsigned_transaction = near_primitives::transaction::Transaction {
+            signer_id: self.account_id.clone(),
+            ..prepopulated_unsigned_transaction
+        };
+        self.sign_option
+            .process_function_call(
+                unsigned_transaction,
+                network_connection_config,
+                method.method_name(),
+                serde_json::json!({}),
+                super::STAKING_POOL_GAS,
+                0,
+            )
+            .await
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn check_balances() {
+        let delegation = super::super::delegation(100, 50, true);
+        assert!(DelegateAll::check_balances(&delegation, DelegateAllMethod::UnstakeAll).is_ok());
+        assert!(DelegateAll::check_balances(&delegation, DelegateAllMethod::WithdrawAll).is_ok());
+    }
+
+    #[test]
+    fn check_empty_balances() {
+        let delegation = super::super::delegation(0, 0, true);
+        assert!(DelegateAll::check_balances(&delegation, DelegateAllMethod::UnstakeAll).is_err());
+        assert!(DelegateAll::check_balances(&delegation, DelegateAllMethod::WithdrawAll).is_err());
+    }
+
+    #[test]
+    fn check_locked_unstaked_balance() {
+        let delegation = super::super::delegation(100, 50, false);
+        assert!(DelegateAll::check_balances(&delegation, DelegateAllMethod::UnstakeAll).is_ok());
+        assert!(DelegateAll::check_balances(&delegation, DelegateAllMethod::WithdrawAll).is_err());
+    }
+}
@@ -0,0 +1,191 @@
+use dialoguer::Input;
+
+/// Specify the delegator account and the amount
+#[derive(Debug, Default, clap::Clap)]
+pub struct CliDelegateAmount {
+    account_id: Option<String>,
+    amount: Option<crate::common::NearBalance>,
+    #[clap(subcommand)]
+    sign_option: Option<
+        crate::commands::construct_transaction_command::sign_transaction::CliSignTransaction,
+    >,
+}
+
+/// The staking pool methods which take an amount
+#[derive(Debug, Clone, Copy, PartialEq)]
+pub enum DelegateAmountMethod {
+    DepositAndStake,
+    Unstake,
+    Withdraw,
+}
+
+impl DelegateAmountMethod {
+    fn method_name(&self) -> &'static str {
+        match self {
+            Self::DepositAndStake => "deposit_and_stake",
+            Self::Unstake => "unstake",
+            Self::Withdraw => "withdraw",
+        }
+    }
+}
+
+#[derive(Debug)]
+pub struct DelegateAmount {
+    account_id: String,
+    amount: near_primitives::types::Balance,
+    sign_option: crate::commands::construct_transaction_command::sign_transaction::SignTransaction,
+}
+
+impl DelegateAmount {
+    pub fn from(item: CliDelegateAmount, amount_prompt: &str) -> Self {
+        let account_id: String = match item.account_id {
+            Some(cli_account_id) => cli_account_id,
+            None => DelegateAmount::input_account_id(),
+        };
+        let amount: near_primitives::types::Balance = match item.amount {
+            Some(cli_amount) => cli_amount.to_yoctonear(),
+            None => DelegateAmount::input_amount(amount_prompt),
+        };
+        let sign_option = match item.sign_option {
+            Some(cli_sign_transaction) => cli_sign_transaction.into(),
+            None => crate::commands::construct_transaction_command::sign_transaction::SignTransaction::choose_sign_option(),
+        };
+        Self {
+            account_id,
+            amount,
+            sign_option,
+        }
+    }
+
+    fn input_account_id() -> String {
+        println!();
+        Input::new()
+            .with_prompt("What is the account ID of the delegator?")
+            .interact_text()
+            .unwrap()
+    }
+
+    fn input_amount(amount_prompt: &str) -> near_primitives::types::Balance {
+        println!();
+        let amount: crate::common::NearBalance = Input::new()
+            .with_prompt(format!(
+                "{} (example: 10NEAR or 0.5near or 10000yoctonear)",
+                amount_prompt
+            ))
+            .interact_text()
+            .unwrap();
+        amount.to_yoctonear()
+    }
+
+    /// Checks the amount against the current balances of the delegator before sending the call.
+    fn check_amount(
+        amount: near_primitives::types::Balance,
+        delegation: &super::Delegation,
+        method: DelegateAmountMethod,
+    ) -> color_eyre::eyre::Result<()> {
+        match method {
+            DelegateAmountMethod::Unstake if amount > delegation.staked_balance => {
+                Err(color_eyre::Report::msg(format!(
+                    "Cannot unstake {}: only {} is staked",
+                    crate::common::NearBalance::from_yoctonear(amount),
+                    crate::common::NearBalance::from_yoctonear(delegation.staked_balance)
+                )))
+            }
+            DelegateAmountMethod::Withdraw if !delegation.is_unstaked_balance_available => {
+                Err(color_eyre::Report::msg(format!(
+                    "The unstaked balance cannot be withdrawn in the current epoch yet (it is unlocked {} epochs after the unstake)",
+                    super::NUM_EPOCHS_TO_UNLOCK
+                )))
+            }
+            DelegateAmountMethod::Withdraw if amount > delegation.unstaked_balance => {
+                Err(color_eyre::Report::msg(format!(
+                    "Cannot withdraw {}: only {} is unstaked",
+                    crate::common::NearBalance::from_yoctonear(amount),
+                    crate::common::NearBalance::from_yoctonear(delegation.unstaked_balance)
+                )))
+            }
+            _ => Ok(()),
+        }
+    }
+
+    pub async fn process(
+        self,
+        prepopulated_unsigned_transaction: near_primitives::transaction::Transaction,
+        network_connection_config: crate::common::ConnectionConfig,
+        method: DelegateAmountMethod,
+    ) -> crate::CliResult {
+        match super::Delegation::fetch(
+            &network_connection_config,
+            &prepopulated_unsigned_transaction.receiver_id,
+            &self.account_id,
+        )
+        .await
+        {
+            Ok(delegation) => {
+                delegation.print(
+                    &prepopulated_unsigned_transaction.receiver_id,
+                    &self.account_id,
+                );
+                DelegateAmount::check_amount(self.amount, &delegation, method)?;
+            }
+            Err(err) => println!("\nWARNING: Failed to fetch the balances: {}", err),
+        }
+        let unsigned_transaction = near_primitives::transaction::Transaction {
+            signer_id: self.account_id.clone(),
+            ..prepopulated_unsigned_transaction
+        };
+        let (args, deposit) = match method {
+            DelegateAmountMethod::DepositAndStake => (serde_json::json!({}), self.amount),
+            _ => (serde_json::json!({ "amount": self.amount.to_string() }), 0),
+        };
+        self.sign_option
+            .process_function_call(
+                unsigned_transaction,
+                network_connection_config,
+                method.method_name(),
+                args,
+                super::STAKING_POOL_GAS,
+                deposit,
+            )
+            .await
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn check_amount() {
+        let delegation = super::super::delegation(100, 50, true);
+        assert!(DelegateAmount::check_amount(
+            1_000,
+            &delegation,
+            DelegateAmountMethod::DepositAndStake
+        )
+        .is_ok());
+        assert!(
+            DelegateAmount::check_amount(100, &delegation, DelegateAmountMethod::Unstake).is_ok()
+        );
+        assert!(
+            DelegateAmount::check_amount(101, &delegation, DelegateAmountMethod::Unstake).is_err()
+        );
+        assert!(
+            DelegateAmount::check_amount(50, &delegation, DelegateAmountMethod::Withdraw).is_ok()
+        );
+        assert!(
+            DelegateAmount::check_amount(51, &delegation, DelegateAmountMethod::Withdraw).is_err()
+        );
+    }
+
+    #[test]
+    fn check_amount_of_locked_unstaked_balance() {
+        let delegation = super::super::delegation(100, 50, false);
+        assert!(
+            DelegateAmount::check_amount(1, &delegation, DelegateAmountMethod::Withdraw).is_err()
+        );
+        assert!(
+            DelegateAmount::check_amount(1, &delegation, DelegateAmountMethod::Unstake).is_ok()
+        );
+    }
+}
@@ -0,0 +1,269 @@
+use dialoguer::{theme::ColorfulTheme, Select};
+use strum::{EnumDiscriminants, EnumIter, EnumMessage, IntoEnumIterator};
+
+mod delegate_all;
+mod delegate_amount;
+pub mod operation_mode;
+mod pool;
+mod view_delegation;
+
+const STAKING_POOL_GAS: near_primitives::types::Gas = 125_000_000_000_000;
+/// The number of epochs the unstaked balance stays locked in a staking pool.
+const NUM_EPOCHS_TO_UNLOCK: u64 = 4;
+
+#[derive(Debug, clap::Clap)]
+pub enum CliStakingAction {
+    /// View the staked, unstaked and total balances of an account in the pool
+    ViewBalances(self::view_delegation::CliViewDelegation),
+    /// Deposit an amount to the pool and stake it
+    DepositAndStake(self::delegate_amount::CliDelegateAmount),
+    /// Unstake an amount
+    Unstake(self::delegate_amount::CliDelegateAmount),
+    /// Unstake the whole staked balance
+    UnstakeAll(self::delegate_all::CliDelegateAll),
+    /// Withdraw an amount of the unstaked balance
+    Withdraw(self::delegate_amount::CliDelegateAmount),
+    /// Withdraw the whole unstaked balance
+    WithdrawAll(self::delegate_all::CliDelegateAll),
+}
+
+#[derive(Debug, EnumDiscriminants)]
+#[strum_discriminants(derive(EnumMessage, EnumIter))]
+pub enum StakingAction {
+    #[strum_discriminants(strum(message = "View the balances of an account in the pool"))]
+    ViewBalances(self::view_delegation::ViewDelegation),
+    #[strum_discriminants(strum(message = "Deposit and stake"))]
+    DepositAndStake(self::delegate_amount::DelegateAmount),
+    #[strum_discriminants(strum(message = "Unstake an amount"))]
+    Unstake(self::delegate_amount::DelegateAmount),
+    #[strum_discriminants(strum(message = "Unstake all"))]
+    UnstakeAll(self::delegate_all::DelegateAll),
+    #[strum_discriminants(strum(message = "Withdraw an amount"))]
+    Withdraw(self::delegate_amount::DelegateAmount),
+    #[strum_discriminants(strum(message = "Withdraw all"))]
+    WithdrawAll(self::delegate_all::DelegateAll),
+}
+
+impl From<CliStakingAction> for StakingAction {
+    fn from(item: CliStakingAction) -> Self {
+        match item {
+            CliStakingAction::ViewBalances(cli_view_delegation) => {
+                Self::ViewBalances(cli_view_delegation.into())
+            }
+            CliStakingAction::DepositAndStake(cli_delegate_amount) => {
+                Self::DepositAndStake(self::delegate_amount::DelegateAmount::from(
+                    cli_delegate_amount,
+                    "How many NEAR Tokens do you want to deposit and stake?",
+                ))
+            }
+            CliStakingAction::Unstake(cli_delegate_amount) => {
+                Self::Unstake(self::delegate_amount::DelegateAmount::from(
+                    cli_delegate_amount,
+                    "How many NEAR Tokens do you want to unstake?",
+                ))
+            }
+            CliStakingAction::UnstakeAll(cli_delegate_all) => {
+                Self::UnstakeAll(cli_delegate_all.into())
+            }
+            CliStakingAction::Withdraw(cli_delegate_amount) => {
+                Self::Withdraw(self::delegate_amount::DelegateAmount::from(
+                    cli_delegate_amount,
+                    "How many NEAR Tokens do you want to withdraw?",
+                ))
+            }
+            CliStakingAction::WithdrawAll(cli_delegate_all) => {
+                Self::WithdrawAll(cli_delegate_all.into())
+            }
+        }
+    }
+}
+
+impl StakingAction {
+    fn choose_staking_action() -> Self {
+        println!();
+        let variants = StakingActionDiscriminants::iter().collect::<Vec<_>>();
+        let actions = variants
+            .iter()
+            .map(|p| p.get_message().unwrap().to_owned())
+            .collect::<Vec<_>>();
+        let selected_action = Select::with_theme(&ColorfulTheme::default())
+            .with_prompt("What do you want to do with the staking pool?")
+            .items(&actions)
+            .default(0)
+            .interact()
+            .unwrap();
+        let cli_staking_action = match variants[selected_action] {
+            StakingActionDiscriminants::ViewBalances => {
+                CliStakingAction::ViewBalances(Default::default())
+            }
+            StakingActionDiscriminants::DepositAndStake => {
+                CliStakingAction::DepositAndStake(Default::default())
+            }
+            StakingActionDiscriminants::Unstake => CliStakingAction::Unstake(Default::default()),
+            StakingActionDiscriminants::UnstakeAll => {
+                CliStakingAction::UnstakeAll(Default::default())
+            }
+            StakingActionDiscriminants::Withdraw => CliStakingAction::Withdraw(Default::default()),
+            StakingActionDiscriminants::WithdrawAll => {
+                CliStakingAction::WithdrawAll(Default::default())
+            }
+        };
+        Self::from(cli_staking_action)
+    }
+
+    async fn process(
+        self,
+        prepopulated_unsigned_transaction: near_primitives::transaction::Transaction,
+        network_connection_config: crate::common::ConnectionConfig,
+    ) -> crate::CliResult {
+        match self {
+            StakingAction::ViewBalances(view_delegation) => {
+                view_delegation
+                    .process(prepopulated_unsigned_transaction, network_connection_config)
+                    .await
+            }
+            StakingAction::DepositAndStake(delegate_amount) => {
+                delegate_amount
+                    .process(
+                        prepopulated_unsigned_transaction,
+                        network_connection_config,
+                        self::delegate_amount::DelegateAmountMethod::DepositAndStake,
+                    )
+                    .await
+            }
+            StakingAction::Unstake(delegate_amount) => {
+                delegate_amount
+                    .process(
+                        prepopulated_unsigned_transaction,
+                        network_connection_config,
+                        self::delegate_amount::DelegateAmountMethod::Unstake,
+                    )
+                    .await
+            }
+            StakingAction::UnstakeAll(delegate_all) => {
+                delegate_all
+                    .process(
+                        prepopulated_unsigned_transaction,
+                        network_connection_config,
+                        self::delegate_all::DelegateAllMethod::UnstakeAll,
+                    )
+                    .await
+            }
+            StakingAction::Withdraw(delegate_amount) => {
+                delegate_amount
+                    .process(
+                        prepopulated_unsigned_transaction,
+                        network_connection_config,
+                        self::delegate_amount::DelegateAmountMethod::Withdraw,
+                    )
+                    .await
+            }
+            StakingAction::WithdrawAll(delegate_all) => {
+                delegate_all
+                    .process(
+                        prepopulated_unsigned_transaction,
+                        network_connection_config,
+                        self::delegate_all::DelegateAllMethod::WithdrawAll,
+                    )
+                    .await
+            }
+        }
+    }
+}
+
+/// The balances of a delegator in a staking pool.
+#[derive(Debug)]
+struct Delegation {
+    staked_balance: near_primitives::types::Balance,
+    unstaked_balance: near_primitives::types::Balance,
+    total_balance: near_primitives::types::Balance,
+    is_unstaked_balance_available: bool,
+}
+
+impl Delegation {
+    async fn fetch(
+        network_connection_config: &crate::common::ConnectionConfig,
+        pool_account_id: &str,
+        account_id: &str,
+    ) -> color_eyre::eyre::Result<Self> {
+        let args = serde_json::json!({ "account_id": account_id });
+        let (staked_balance, unstaked_balance, total_balance, is_unstaked_balance_available) = futures::try_join!(
+            crate::common::call_view_function::<String>(
+                network_connection_config,
+                pool_account_id,
+                "get_account_staked_balance",
+                args.clone(),
+            ),
+            crate::common::call_view_function::<String>(
+                network_connection_config,
+                pool_account_id,
+                "get_account_unstaked_balance",
+                args.clone(),
+            ),
+            crate::common::call_view_function::<String>(
+                network_connection_config,
+                pool_account_id,
+                "get_account_total_balance",
+                args.clone(),
+            ),
+            crate::common::call_view_function::<bool>(
+                network_connection_config,
+                pool_account_id,
+                "is_account_unstaked_balance_available",
+                args.clone(),
+            ),
+        )?;
+        let parse_balance = |balance: String| {
+            balance
+                .parse::<near_primitives::types::Balance>()
+                .map_err(|err| {
+                    color_eyre::Report::msg(format!(
+                        "The balance {:?} returned by <{}> is not a number: {}",
+                        balance, pool_account_id, err
+                    ))
+                })
+        };
+        Ok(Self {
+            staked_balance: parse_balance(staked_balance)?,
+            unstaked_balance: parse_balance(unstaked_balance)?,
+            total_balance: parse_balance(total_balance)?,
+            is_unstaked_balance_available,
+        })
+    }
+
+    fn print(&self, pool_account_id: &str, account_id: &str) {
+        println!(
+            "\nBalances of <{}> in <{}>:\n  staked:   {}\n  unstaked: {}\n  total:    {}",
+            account_id,
+            pool_account_id,
+            crate::common::NearBalance::from_yoctonear(self.staked_balance),
+            crate::common::NearBalance::from_yoctonear(self.unstaked_balance),
+            crate::common::NearBalance::from_yoctonear(self.total_balance),
+        );
+        if self.unstaked_balance > 0 {
+            if self.is_unstaked_balance_available {
+                println!("The unstaked balance can be withdrawn in the current epoch.");
+            } else {
+                println!(
+                    "The unstaked balance cannot be withdrawn in the current epoch yet (it is unlocked {} epochs after the unstake).",
+                    NUM_EPOCHS_TO_UNLOCK
+                );
+            }
+        }
+    }
+}
+
+/// The delegation the tests of the staking actions check the amounts against.
+#[cfg(test)]
+fn delegation(
+    staked_balance: near_primitives::types::Balance,
+    unstaked_balance: near_primitives::types::Balance,
+    is_unstaked_balance_available: bool,
+) -> Delegation {
+    Delegation {
+        staked_balance,
+        unstaked_balance,
+        total_balance: staked_balance + unstaked_balance,
+        is_unstaked_balance_available,
+    }
+}
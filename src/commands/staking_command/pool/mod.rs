@@ -0,0 +1,97 @@
+use dialoguer::Input;
+
+#[derive(Debug, clap::Clap)]
+pub enum CliSendTo {
+    /// Specify a staking pool
+    Pool(CliPool),
+}
+
+#[derive(Debug)]
+pub enum SendTo {
+    Pool(Pool),
+}
+
+impl From<CliSendTo> for SendTo {
+    fn from(item: CliSendTo) -> Self {
+        match item {
+            CliSendTo::Pool(cli_pool) => {
+                let pool = Pool::from(cli_pool);
+                Self::Pool(pool)
+            }
+        }
+    }
+}
+
+impl SendTo {
+    pub fn send_to() -> Self {
+        Self::from(CliSendTo::Pool(Default::default()))
+    }
+
+    pub async fn process(
+        self,
+        prepopulated_unsigned_transaction: near_primitives::transaction::Transaction,
+        network_connection_config: crate::common::ConnectionConfig,
+    ) -> crate::CliResult {
+        match self {
+            SendTo::Pool(pool) => {
+                pool.process(prepopulated_unsigned_transaction, network_connection_config)
+                    .await
+            }
+        }
+    }
+}
+
+/// данные о контракте staking pool
+#[derive(Debug, Default, clap::Clap)]
+pub struct CliPool {
+    pool_account_id: Option<String>,
+    #[clap(subcommand)]
+    staking_action: Option<super::CliStakingAction>,
+}
+
+#[derive(Debug)]
+pub struct Pool {
+    pub pool_account_id: String,
+    pub staking_action: super::StakingAction,
+}
+
+impl From<CliPool> for Pool {
+    fn from(item: CliPool) -> Self {
+        let pool_account_id: String = match item.pool_account_id {
+            Some(cli_pool_account_id) => cli_pool_account_id,
+            None => Pool::input_pool_account_id(),
+        };
+        let staking_action = match item.staking_action {
+            Some(cli_staking_action) => super::StakingAction::from(cli_staking_action),
+            None => super::StakingAction::choose_staking_action(),
+        };
+        Self {
+            pool_account_id,
+            staking_action,
+        }
+    }
+}
+
+impl Pool {
+    pub fn input_pool_account_id() -> String {
+        println!();
+        Input::new()
+            .with_prompt("What is the account ID of the staking pool?")
+            .interact_text()
+            .unwrap()
+    }
+
+    pub async fn process(
+        self,
+        prepopulated_unsigned_transaction: near_primitives::transaction::Transaction,
+        network_connection_config: crate::common::ConnectionConfig,
+    ) -> crate::CliResult {
+        let unsigned_transaction = near_primitives::transaction::Transaction {
+            receiver_id: self.pool_account_id.clone(),
+            ..prepopulated_unsigned_transaction
+        };
+        self.staking_action
+            .process(unsigned_transaction, network_connection_config)
+            .await
+    }
+}
@@ -0,0 +1,48 @@
+use dialoguer::Input;
+
+/// Specify the account to view the balances in the staking pool for
+#[derive(Debug, Default, clap::Clap)]
+pub struct CliViewDelegation {
+    account_id: Option<String>,
+}
+
+#[derive(Debug)]
+pub struct ViewDelegation {
+    account_id: String,
+}
+
+impl From<CliViewDelegation> for ViewDelegation {
+    fn from(item: CliViewDelegation) -> Self {
+        let account_id: String = match item.account_id {
+            Some(cli_account_id) => cli_account_id,
+            None => ViewDelegation::input_account_id(),
+        };
+        Self { account_id }
+    }
+}
+
+impl ViewDelegation {
+    fn input_account_id() -> String {
+        println!();
+        Input::new()
+            .with_prompt("What is the account ID of the delegator?")
+            .interact_text()
+            .unwrap()
+    }
+
+    pub async fn process(
+        self,
+        prepopulated_unsigned_transaction: near_primitives::transaction::Transaction,
+        network_connection_config: crate::common::ConnectionConfig,
+    ) -> crate::CliResult {
+        let pool_account_id = &prepopulated_unsigned_transaction.receiver_id;
+        super::Delegation::fetch(
+            &network_connection_config,
+            pool_account_id,
+            &self.account_id,
+        )
+        .await?
+        .print(pool_account_id, &self.account_id);
+        Ok(())
+    }
+}
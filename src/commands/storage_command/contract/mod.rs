@@ -0,0 +1,110 @@
+use dialoguer::Input;
+
+#[derive(Debug, clap::Clap)]
+pub enum CliSendTo {
+    /// Specify a contract
+    Contract(CliContract),
+}
+
+#[derive(Debug)]
+pub enum SendTo {
+    Contract(Contract),
+}
+
+impl SendTo {
+    pub fn from(
+        item: CliSendTo,
+        network_connection_config: &crate::common::ConnectionConfig,
+    ) -> Self {
+        match item {
+            CliSendTo::Contract(cli_contract) => {
+                let contract = Contract::from(cli_contract, network_connection_config);
+                Self::Contract(contract)
+            }
+        }
+    }
+
+    pub fn send_to(network_connection_config: &crate::common::ConnectionConfig) -> Self {
+        Self::from(
+            CliSendTo::Contract(Default::default()),
+            network_connection_config,
+        )
+    }
+
+    pub async fn process(
+        self,
+        prepopulated_unsigned_transaction: near_primitives::transaction::Transaction,
+        network_connection_config: crate::common::ConnectionConfig,
+    ) -> crate::CliResult {
+        match self {
+            SendTo::Contract(contract) => {
+                contract
+                    .process(prepopulated_unsigned_transaction, network_connection_config)
+                    .await
+            }
+        }
+    }
+}
+
+/// данные о контракте, поддерживающем NEP-145
+#[derive(Debug, Default, clap::Clap)]
+pub struct CliContract {
+    contract_account_id: Option<String>,
+    #[clap(subcommand)]
+    storage_action: Option<super::CliStorageAction>,
+}
+
+#[derive(Debug)]
+pub struct Contract {
+    pub contract_account_id: String,
+    pub storage_action: super::StorageAction,
+}
+
+impl Contract {
+    fn from(
+        item: CliContract,
+        network_connection_config: &crate::common::ConnectionConfig,
+    ) -> Self {
+        let contract_account_id: String = match item.contract_account_id {
+            Some(cli_contract_account_id) => cli_contract_account_id,
+            None => Contract::input_contract_account_id(),
+        };
+        let storage_action = match item.storage_action {
+            Some(cli_storage_action) => super::StorageAction::from(
+                cli_storage_action,
+                network_connection_config,
+                &contract_account_id,
+            ),
+            None => super::StorageAction::choose_storage_action(
+                network_connection_config,
+                &contract_account_id,
+            ),
+        };
+        Self {
+            contract_account_id,
+            storage_action,
+        }
+    }
+
+    pub fn input_contract_account_id() -> String {
+        println!();
+        Input::new()
+            .with_prompt("What is the account ID of the contract?")
+            .interact_text()
+            .unwrap()
+    }
+
+    pub async fn process(
+        self,
+        prepopulated_unsigned_transaction: near_primitives::transaction::Transaction,
+        network_connection_config: crate::common::ConnectionConfig,
+    ) -> crate::CliResult {
+        let unsigned_transaction = near_primitives::transaction::Transaction {
+            receiver_id: self.contract_account_id.clone(),
+            ..prepopulated_unsigned_transaction
+        };
+        self.storage_action
+            .process(unsigned_transaction, network_connection_config)
+            .await
+    }
+}
@@ -0,0 +1,163 @@
+use dialoguer::{theme::ColorfulTheme, Select};
+use strum::{EnumDiscriminants, EnumIter, EnumMessage, IntoEnumIterator};
+
+mod contract;
+pub mod operation_mode;
+mod storage_deposit;
+mod storage_unregister;
+mod storage_withdraw;
+mod view_storage_balance;
+
+const STORAGE_MANAGEMENT_GAS: near_primitives::types::Gas = 30_000_000_000_000;
+
+#[derive(Debug, clap::Clap)]
+pub enum CliStorageAction {
+    /// View the storage deposit bounds of the contract and the storage balance of an account
+    ViewBalance(self::view_storage_balance::CliViewStorageBalance),
+    /// Pay a storage deposit for an account (registers it in the contract)
+    Deposit(self::storage_deposit::CliStorageDeposit),
+    /// Withdraw the available storage balance of an account
+    Withdraw(self::storage_withdraw::CliStorageWithdraw),
+    /// Unregister an account and get its storage deposit back
+    Unregister(self::storage_unregister::CliStorageUnregister),
+}
+
+#[derive(Debug, EnumDiscriminants)]
+#[strum_discriminants(derive(EnumMessage, EnumIter))]
+pub enum StorageAction {
+    #[strum_discriminants(strum(message = "View the storage balance of an account"))]
+    ViewBalance(self::view_storage_balance::ViewStorageBalance),
+    #[strum_discriminants(strum(message = "Pay a storage deposit for an account"))]
+    Deposit(self::storage_deposit::StorageDeposit),
+    #[strum_discriminants(strum(message = "Withdraw the available storage balance"))]
+    Withdraw(self::storage_withdraw::StorageWithdraw),
+    #[strum_discriminants(strum(message = "Unregister an account"))]
+    Unregister(self::storage_unregister::StorageUnregister),
+}
+
+impl StorageAction {
+    fn from(
+        item: CliStorageAction,
+        network_connection_config: &crate::common::ConnectionConfig,
+        contract_account_id: &str,
+    ) -> Self {
+        match item {
+            CliStorageAction::ViewBalance(cli_view_storage_balance) => {
+                Self::ViewBalance(cli_view_storage_balance.into())
+            }
+            CliStorageAction::Deposit(cli_storage_deposit) => {
+                Self::Deposit(self::storage_deposit::StorageDeposit::from(
+                    cli_storage_deposit,
+                    network_connection_config,
+                    contract_account_id,
+                ))
+            }
+            CliStorageAction::Withdraw(cli_storage_withdraw) => {
+                Self::Withdraw(cli_storage_withdraw.into())
+            }
+            CliStorageAction::Unregister(cli_storage_unregister) => {
+                Self::Unregister(cli_storage_unregister.into())
+            }
+        }
+    }
+
+    fn choose_storage_action(
+        network_connection_config: &crate::common::ConnectionConfig,
+        contract_account_id: &str,
+    ) -> Self {
+        println!();
+        let variants = StorageActionDiscriminants::iter().collect::<Vec<_>>();
+        let actions = variants
+            .iter()
+            .map(|p| p.get_message().unwrap().to_owned())
+            .collect::<Vec<_>>();
+        let selected_action = Select::with_theme(&ColorfulTheme::default())
+            .with_prompt("What do you want to do with the storage?")
+            .items(&actions)
+            .default(0)
+            .interact()
+            .unwrap();
+        let cli_storage_action = match variants[selected_action] {
+            StorageActionDiscriminants::ViewBalance => {
+                CliStorageAction::ViewBalance(Default::default())
+            }
+            StorageActionDiscriminants::Deposit => CliStorageAction::Deposit(Default::default()),
+            StorageActionDiscriminants::Withdraw => CliStorageAction::Withdraw(Default::default()),
+            StorageActionDiscriminants::Unregister => {
+                CliStorageAction::Unregister(Default::default())
+            }
+        };
+        Self::from(
+            cli_storage_action,
+            network_connection_config,
+            contract_account_id,
+        )
+    }
+
+    async fn process(
+        self,
+        prepopulated_unsigned_transaction: near_primitives::transaction::Transaction,
+        network_connection_config: crate::common::ConnectionConfig,
+    ) -> crate::CliResult {
+        match self {
+            StorageAction::ViewBalance(view_storage_balance) => {
+                view_storage_balance
+                    .process(prepopulated_unsigned_transaction, network_connection_config)
+                    .await
+            }
+            StorageAction::Deposit(storage_deposit) => {
+                storage_deposit
+                    .process(prepopulated_unsigned_transaction, network_connection_config)
+                    .await
+            }
+            StorageAction::Withdraw(storage_withdraw) => {
+                storage_withdraw
+                    .process(prepopulated_unsigned_transaction, network_connection_config)
+                    .await
+            }
+            StorageAction::Unregister(storage_unregister) => {
+                storage_unregister
+                    .process(prepopulated_unsigned_transaction, network_connection_config)
+                    .await
+            }
+        }
+    }
+}
+
+/// Prints the storage deposit bounds of a contract and the storage balance of an account in it.
+async fn print_storage_balance(
+    network_connection_config: &crate::common::ConnectionConfig,
+    contract_account_id: &str,
+    account_id: &str,
+) -> crate::CliResult {
+    let (storage_balance_bounds, storage_balance) = futures::try_join!(
+        crate::common::StorageBalanceBounds::fetch(network_connection_config, contract_account_id),
+        crate::common::StorageBalance::fetch(
+            network_connection_config,
+            contract_account_id,
+            account_id
+        ),
+    )?;
+    println!(
+        "\nStorage deposit bounds of <{}>: min {}, max {}",
+        contract_account_id,
+        crate::common::NearBalance::from_yoctonear(storage_balance_bounds.min),
+        match storage_balance_bounds.max {
+            Some(max) => crate::common::NearBalance::from_yoctonear(max).to_string(),
+            None => "unlimited".to_string(),
+        }
+    );
+    match storage_balance {
+        Some(storage_balance) => println!(
+            "Storage balance of <{}>: total {}, available {}",
+            account_id,
+            crate::common::NearBalance::from_yoctonear(storage_balance.total),
+            crate::common::NearBalance::from_yoctonear(storage_balance.available)
+        ),
+        None => println!(
+            "<{}> is not registered in <{}>",
+            account_id, contract_account_id
+        ),
+    }
+    Ok(())
+}
@@ -0,0 +1,297 @@
+use dialoguer::Input;
+
+/// Specify the account to pay the storage deposit for and the amount
+#[derive(Debug, Default, clap::Clap)]
+pub struct CliStorageDeposit {
+    account_id: Option<String>,
+    amount: Option<crate::common::NearBalance>,
+    /// The account which pays the deposit (the account itself by default)
+    #[clap(long)]
+    signer_id: Option<String>,
+    /// Only register the account: the deposit above the minimum is refunded
+    #[clap(long)]
+    registration_only: bool,
+    #[clap(subcommand)]
+    sign_option: Option<
+        crate::commands::construct_transaction_command::sign_transaction::CliSignTransaction,
+    >,
+}
+
+#[derive(Debug)]
+pub struct StorageDeposit {
+    account_id: String,
+    signer_id: String,
+    /// A deposit given on the command line that does not fit the bounds is reported when it is processed
+    amount: Result<near_primitives::types::Balance, String>,
+    registration_only: bool,
+    sign_option: crate::commands::construct_transaction_command::sign_transaction::SignTransaction,
+}
+
+impl StorageDeposit {
+    pub fn from(
+        item: CliStorageDeposit,
+        network_connection_config: &crate::common::ConnectionConfig,
+        contract_account_id: &str,
+    ) -> Self {
+        let is_interactive = item.account_id.is_none();
+        let account_id: String = match item.account_id {
+            Some(cli_account_id) => cli_account_id,
+            None => StorageDeposit::input_account_id(),
+        };
+        let signer_id: String = match item.signer_id {
+            Some(cli_signer_id) => cli_signer_id,
+            None if is_interactive => StorageDeposit::input_signer_id(&account_id),
+            None => account_id.clone(),
+        };
+        let storage_balance = StorageDeposit::fetch_storage_balance(
+            network_connection_config,
+            contract_account_id,
+            &account_id,
+        );
+        let amount = match item.amount {
+            Some(cli_amount) => StorageDeposit::check_amount(
+                cli_amount.to_yoctonear(),
+                storage_balance.as_ref(),
+                item.registration_only,
+            ),
+            None => Ok(StorageDeposit::input_amount(
+                storage_balance.as_ref(),
+                item.registration_only,
+            )),
+        };
+        let sign_option = match item.sign_option {
+            Some(cli_sign_transaction) => cli_sign_transaction.into(),
+            None => crate::commands::construct_transaction_command::sign_transaction::SignTransaction::choose_sign_option(),
+        };
+        Self {
+            account_id,
+            signer_id,
+            amount,
+            registration_only: item.registration_only,
+            sign_option,
+        }
+    }
+
+    /// Shows the current storage balance of the account and returns it with the deposit bounds of the contract.
+    fn fetch_storage_balance(
+        network_connection_config: &crate::common::ConnectionConfig,
+        contract_account_id: &str,
+        account_id: &str,
+    ) -> Option<(
+        crate::common::StorageBalanceBounds,
+        Option<crate::common::StorageBalance>,
+    )> {
+        let storage_balance = actix::System::new().block_on(async {
+            futures::try_join!(
+                crate::common::StorageBalanceBounds::fetch(
+                    network_connection_config,
+                    contract_account_id
+                ),
+                crate::common::StorageBalance::fetch(
+                    network_connection_config,
+                    contract_account_id,
+                    account_id
+                ),
+            )
+        });
+        match storage_balance {
+            Ok((storage_balance_bounds, None)) => {
+                println!(
+                    "\n<{}> is not registered in <{}>; the minimum deposit is {}",
+                    account_id,
+                    contract_account_id,
+                    crate::common::NearBalance::from_yoctonear(storage_balance_bounds.min)
+                );
+                Some((storage_balance_bounds, None))
+            }
+            Ok((storage_balance_bounds, Some(storage_balance))) => {
+                println!(
+                    "\n<{}> is already registered in <{}>: total {}, available {}",
+                    account_id,
+                    contract_account_id,
+                    crate::common::NearBalance::from_yoctonear(storage_balance.total),
+                    crate::common::NearBalance::from_yoctonear(storage_balance.available)
+                );
+                Some((storage_balance_bounds, Some(storage_balance)))
+            }
+            Err(err) => {
+                println!(
+                    "\nWARNING: Failed to fetch the storage balance of <{}> in <{}>: {}",
+                    account_id, contract_account_id, err
+                );
+                None
+            }
+        }
+    }
+
+    /// Checks the deposit against the bounds of the contract: an account that is not registered
+    /// needs at least the minimum, and the storage balance must not exceed the maximum (the
+    /// deposit above the minimum is refunded with `registration_only`).
+    fn check_amount(
+        amount: near_primitives::types::Balance,
+        storage_balance: Option<&(
+            crate::common::StorageBalanceBounds,
+            Option<crate::common::StorageBalance>,
+        )>,
+        registration_only: bool,
+    ) -> Result<near_primitives::types::Balance, String> {
+        let (storage_balance_bounds, storage_balance) = match storage_balance {
+            Some(storage_balance) => storage_balance,
+            None => return Ok(amount),
+        };
+        let total = match storage_balance {
+            Some(_) if registration_only => return Ok(amount),
+            Some(storage_balance) => storage_balance.total.saturating_add(amount),
+            None if amount < storage_balance_bounds.min => {
+                return Err(format!(
+                    "The deposit must be at least {} to register the account",
+                    crate::common::NearBalance::from_yoctonear(storage_balance_bounds.min)
+                ))
+            }
+            None if registration_only => storage_balance_bounds.min,
+            None => amount,
+        };
+        match storage_balance_bounds.max {
+            Some(max) if total > max => Err(format!(
+                "The storage balance would be {}, but the contract allows at most {}",
+                crate::common::NearBalance::from_yoctonear(total),
+                crate::common::NearBalance::from_yoctonear(max)
+            )),
+            _ => Ok(amount),
+        }
+    }
+
+    fn input_account_id() -> String {
+        println!();
+        Input::new()
+            .with_prompt("What is the account ID to pay the storage deposit for?")
+            .interact_text()
+            .unwrap()
+    }
+
+    fn input_signer_id(account_id: &str) -> String {
+        println!();
+        Input::new()
+            .with_prompt("What is the account ID which pays the deposit?")
+            .with_initial_text(account_id)
+            .interact_text()
+            .unwrap()
+    }
+
+    fn input_amount(
+        storage_balance: Option<&(
+            crate::common::StorageBalanceBounds,
+            Option<crate::common::StorageBalance>,
+        )>,
+        registration_only: bool,
+    ) -> near_primitives::types::Balance {
+        println!();
+        let min_deposit = match storage_balance {
+            Some((storage_balance_bounds, None)) => Some(storage_balance_bounds.min),
+            _ => None,
+        };
+        loop {
+            let amount: crate::common::NearBalance = Input::new()
+                .with_prompt("How many NEAR Tokens do you want to deposit? (example: 10NEAR or 0.5near or 10000yoctonear)")
+                .with_initial_text(&match min_deposit {
+                    Some(min_deposit) => format!("{} yoctoNEAR", min_deposit),
+                    None => "0.1 NEAR".to_string(),
+                })
+                .interact_text()
+                .unwrap();
+            match StorageDeposit::check_amount(
+                amount.to_yoctonear(),
+                storage_balance,
+                registration_only,
+            ) {
+                Ok(amount) => break amount,
+                Err(err) => println!("{}", err),
+            }
+        }
+    }
+
+    pub async fn process(
+        self,
+        prepopulated_unsigned_transaction: near_primitives::transaction::Transaction,
+        network_connection_config: crate::common::ConnectionConfig,
+    ) -> crate::CliResult {
+        let amount = self.amount.map_err(color_eyre::Report::msg)?;
+        let unsigned_transaction = near_primitives::transaction::Transaction {
+            signer_id: self.signer_id.clone(),
+            ..prepopulated_unsigned_transaction
+        };
+        let mut args = serde_json::json!({ "account_id": self.account_id });
+        if self.registration_only {
+            args["registration_only"] = serde_json::json!(true);
+        }
+        self.sign_option
+            .process_function_call(
+                unsigned_transaction,
+                network_connection_config,
+                "storage_deposit",
+                args,
+                super::STORAGE_MANAGEMENT_GAS,
+                amount,
+            )
+            .await
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn storage_balance_bounds(
+        min: near_primitives::types::Balance,
+        max: Option<near_primitives::types::Balance>,
+    ) -> crate::common::StorageBalanceBounds {
+        crate::common::StorageBalanceBounds { min, max }
+    }
+
+    #[test]
+    fn check_amount_of_unregistered_account() {
+        let storage_balance = (storage_balance_bounds(100, Some(1_000)), None);
+        assert_eq!(
+            StorageDeposit::check_amount(100, Some(&storage_balance), false),
+            Ok(100)
+        );
+        assert!(StorageDeposit::check_amount(99, Some(&storage_balance), false).is_err());
+        assert!(StorageDeposit::check_amount(99, Some(&storage_balance), true).is_err());
+        assert!(StorageDeposit::check_amount(1_001, Some(&storage_balance), false).is_err());
+        // The deposit above the minimum is refunded
+        assert_eq!(
+            StorageDeposit::check_amount(1_001, Some(&storage_balance), true),
+            Ok(1_001)
+        );
+    }
+
+    #[test]
+    fn check_amount_of_registered_account() {
+        let storage_balance = (
+            storage_balance_bounds(100, Some(1_000)),
+            Some(crate::common::StorageBalance {
+                total: 900,
+                available: 0,
+            }),
+        );
+        assert_eq!(
+            StorageDeposit::check_amount(10, Some(&storage_balance), false),
+            Ok(10)
+        );
+        assert_eq!(
+            StorageDeposit::check_amount(100, Some(&storage_balance), false),
+            Ok(100)
+        );
+        assert!(StorageDeposit::check_amount(101, Some(&storage_balance), false).is_err());
+        let unlimited_storage_balance = (storage_balance_bounds(100, None), storage_balance.1);
+        assert_eq!(
+            StorageDeposit::check_amount(10_000, Some(&unlimited_storage_balance), false),
+            Ok(10_000)
+        );
+    }
+
+    #[test]
+    fn check_amount_without_bounds() {
+        assert_eq!(StorageDeposit::check_amount(1, None, false), Ok(1));
+    }
+}
@@ -0,0 +1,81 @@
+use dialoguer::Input;
+
+/// Specify the account to unregister
+#[derive(Debug, Default, clap::Clap)]
+pub struct CliStorageUnregister {
+    account_id: Option<String>,
+    /// Unregister the account even if it still holds tokens (they are burnt)
+    #[clap(long)]
+    force: bool,
+    #[clap(subcommand)]
+    sign_option: Option<
+        crate::commands::construct_transaction_command::sign_transaction::CliSignTransaction,
+    >,
+}
+
+#[derive(Debug)]
+pub struct StorageUnregister {
+    account_id: String,
+    force: bool,
+    sign_option: crate::commands::construct_transaction_command::sign_transaction::SignTransaction,
+}
+
+impl From<CliStorageUnregister> for StorageUnregister {
+    fn from(item: CliStorageUnregister) -> Self {
+        let account_id: String = match item.account_id {
+            Some(cli_account_id) => cli_account_id,
+            None => StorageUnregister::input_account_id(),
+        };
+        let sign_option = match item.sign_option {
+            Some(cli_sign_transaction) => cli_sign_transaction.into(),
+            None => crate::commands::construct_transaction_command::sign_transaction::SignTransaction::choose_sign_option(),
+        };
+        Self {
+            account_id,
+            force: item.force,
+            sign_option,
+        }
+    }
+}
+
+impl StorageUnregister {
+    fn input_account_id() -> String {
+        println!();
+        Input::new()
+            .with_prompt("What is the account ID to unregister?")
+            .interact_text()
+            .unwrap()
+    }
+
+    pub async fn process(
+        self,
+        prepopulated_unsigned_transaction: near_primitives::transaction::Transaction,
+        network_connection_config: crate::common::ConnectionConfig,
+    ) -> crate::CliResult {
+        if self.force {
+            println!(
+                "\nWARNING: <{}> is unregistered with force: the tokens it holds in <{}> are burnt.",
+                self.account_id, prepopulated_unsigned_transaction.receiver_id
+            );
+        }
+        let unsigned_transaction = near_primitives::transaction::Transaction {
+            signer_id: self.account_id.clone(),
+            ..prepopulated_unsigned_transaction
+        };
+        let args = if self.force {
+            serde_json::json!({ "force": true })
+        } else {
+            serde_json::json!({})
+        };
+        self.sign_option
+            .process_function_call(
+                unsigned_transaction,
+                network_connection_config,
+                "storage_unregister",
+                args,
+                super::STORAGE_MANAGEMENT_GAS,
+                crate::consts::ONE_YOCTONEAR,
+            )
+            .await
+    }
+}
@@ -0,0 +1,83 @@
+use dialoguer::Input;
+
+/// Specify the account to withdraw the storage balance of
+#[derive(Debug, Default, clap::Clap)]
+pub struct CliStorageWithdraw {
+    account_id: Option<String>,
+    /// The amount to withdraw (all the available balance by default)
+    #[clap(long)]
+    amount: Option<crate::common::NearBalance>,
+    #[clap(subcommand)]
+    sign_option: Option<
+        crate::commands::construct_transaction_command::sign_transaction::CliSignTransaction,
+    >,
+}
+
+#[derive(Debug)]
+pub struct StorageWithdraw {
+    account_id: String,
+    amount: Option<near_primitives::types::Balance>,
+    sign_option: crate::commands::construct_transaction_command::sign_transaction::SignTransaction,
+}
+
+impl From<CliStorageWithdraw> for StorageWithdraw {
+    fn from(item: CliStorageWithdraw) -> Self {
+        let account_id: String = match item.account_id {
+            Some(cli_account_id) => cli_account_id,
+            None => StorageWithdraw::input_account_id(),
+        };
+        let sign_option = match item.sign_option {
+            Some(cli_sign_transaction) => cli_sign_transaction.into(),
+            None => crate::commands::construct_transaction_command::sign_transaction::SignTransaction::choose_sign_option(),
+        };
+        Self {
+            account_id,
+            amount: item.amount.map(|cli_amount| cli_amount.to_yoctonear()),
+            sign_option,
+        }
+    }
+}
+
+impl StorageWithdraw {
+    fn input_account_id() -> String {
+        println!();
+        Input::new()
+            .with_prompt("What is the account ID to withdraw the storage balance of?")
+            .interact_text()
+            .unwrap()
+    }
+
+    pub async fn process(
+        self,
+        prepopulated_unsigned_transaction: near_primitives::transaction::Transaction,
+        network_connection_config: crate::common::ConnectionConfig,
+    ) -> crate::CliResult {
+        if let Err(err) = super::print_storage_balance(
+            &network_connection_config,
+            &prepopulated_unsigned_transaction.receiver_id,
+            &self.account_id,
+        )
+        .await
+        {
+            println!("\nWARNING: Failed to fetch the storage balance: {}", err);
+        }
+        let unsigned_transaction = near_primitives::transaction::Transaction {
+            signer_id: self.account_id.clone(),
+            ..prepopulated_unsigned_transaction
+        };
+        let args = match self.amount {
+            Some(amount) => serde_json::json!({ "amount": amount.to_string() }),
+            None => serde_json::json!({}),
+        };
+        self.sign_option
+            .process_function_call(
+                unsigned_transaction,
+                network_connection_config,
+                "storage_withdraw",
+                args,
+                super::STORAGE_MANAGEMENT_GAS,
+                crate::consts::ONE_YOCTONEAR,
+            )
+            .await
+    }
+}
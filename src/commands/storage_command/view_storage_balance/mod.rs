@@ -0,0 +1,45 @@
+use dialoguer::Input;
+
+/// Specify the account to view the storage balance for
+#[derive(Debug, Default, clap::Clap)]
+pub struct CliViewStorageBalance {
+    account_id: Option<String>,
+}
+
+#[derive(Debug)]
+pub struct ViewStorageBalance {
+    account_id: String,
+}
+
+impl From<CliViewStorageBalance> for ViewStorageBalance {
+    fn from(item: CliViewStorageBalance) -> Self {
+        let account_id: String = match item.account_id {
+            Some(cli_account_id) => cli_account_id,
+            None => ViewStorageBalance::input_account_id(),
+        };
+        Self { account_id }
+    }
+}
+
+impl ViewStorageBalance {
+    fn input_account_id() -> String {
+        println!();
+        Input::new()
+            .with_prompt("What is the account ID to view the storage balance for?")
+            .interact_text()
+            .unwrap()
+    }
+
+    pub async fn process(
+        self,
+        prepopulated_unsigned_transaction: near_primitives::transaction::Transaction,
+        network_connection_config: crate::common::ConnectionConfig,
+    ) -> crate::CliResult {
+        super::print_storage_balance(
+            &network_connection_config,
+            &prepopulated_unsigned_transaction.receiver_id,
+            &self.account_id,
+        )
+        .await
+    }
+}
@@ -0,0 +1,108 @@
+use dialoguer::Input;
+
+#[derive(Debug, clap::Clap)]
+pub enum CliSelectFtContract {
+    /// Specify the fungible token contract
+    FtContract(CliFtContract),
+}
+
+#[derive(Debug)]
+pub enum SelectFtContract {
+    FtContract(FtContract),
+}
+
+impl SelectFtContract {
+    pub fn from(
+        item: CliSelectFtContract,
+        network_connection_config: &crate::common::ConnectionConfig,
+    ) -> Self {
+        match item {
+            CliSelectFtContract::FtContract(cli_ft_contract) => {
+                Self::FtContract(FtContract::from(cli_ft_contract, network_connection_config))
+            }
+        }
+    }
+
+    pub fn select_ft_contract(network_connection_config: &crate::common::ConnectionConfig) -> Self {
+        Self::from(
+            CliSelectFtContract::FtContract(Default::default()),
+            network_connection_config,
+        )
+    }
+
+    pub async fn process(
+        self,
+        prepopulated_unsigned_transaction: near_primitives::transaction::Transaction,
+        network_connection_config: crate::common::ConnectionConfig,
+    ) -> crate::CliResult {
+        match self {
+            SelectFtContract::FtContract(ft_contract) => {
+                ft_contract
+                    .process(prepopulated_unsigned_transaction, network_connection_config)
+                    .await
+            }
+        }
+    }
+}
+
+/// данные о контракте токена (NEP-141)
+#[derive(Debug, Default, clap::Clap)]
+pub struct CliFtContract {
+    ft_contract_account_id: Option<String>,
+    #[clap(subcommand)]
+    send_to: Option<super::receiver::CliSendTo>,
+}
+
+#[derive(Debug)]
+pub struct FtContract {
+    pub ft_contract_account_id: String,
+    pub send_to: super::receiver::SendTo,
+}
+
+impl FtContract {
+    fn from(
+        item: CliFtContract,
+        network_connection_config: &crate::common::ConnectionConfig,
+    ) -> Self {
+        let ft_contract_account_id: String = match item.ft_contract_account_id {
+            Some(cli_ft_contract_account_id) => cli_ft_contract_account_id,
+            None => FtContract::input_ft_contract_account_id(),
+        };
+        let send_to = match item.send_to {
+            Some(cli_send_to) => super::receiver::SendTo::from(
+                cli_send_to,
+                network_connection_config,
+                &ft_contract_account_id,
+            ),
+            None => {
+                super::receiver::SendTo::send_to(network_connection_config, &ft_contract_account_id)
+            }
+        };
+        Self {
+            ft_contract_account_id,
+            send_to,
+        }
+    }
+
+    pub fn input_ft_contract_account_id() -> String {
+        println!();
+        Input::new()
+            .with_prompt("What is the account ID of the fungible token contract?")
+            .interact_text()
+            .unwrap()
+    }
+
+    pub async fn process(
+        self,
+        prepopulated_unsigned_transaction: near_primitives::transaction::Transaction,
+        network_connection_config: crate::common::ConnectionConfig,
+    ) -> crate::CliResult {
+        let unsigned_transaction = near_primitives::transaction::Transaction {
+            receiver_id: self.ft_contract_account_id.clone(),
+            ..prepopulated_unsigned_transaction
+        };
+        self.send_to
+            .process(unsigned_transaction, network_connection_config)
+            .await
+    }
+}
@@ -0,0 +1,5 @@
+mod ft_contract;
+pub mod operation_mode;
+mod receiver;
+mod sender;
+mod transfer_ft_tokens_type;
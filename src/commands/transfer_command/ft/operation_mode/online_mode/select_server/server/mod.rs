@@ -0,0 +1,119 @@
+use dialoguer::Input;
+
+/// предустановленный RPC-сервер
+#[derive(Debug, Default, clap::Clap)]
+pub struct CliServer {
+    #[clap(subcommand)]
+    pub send_from: Option<CliSendFrom>,
+}
+
+/// данные для custom server
+#[derive(Debug, Default, clap::Clap)]
+pub struct CliCustomServer {
+    #[clap(long)]
+    pub url: Option<crate::common::AvailableRpcServerUrl>,
+    #[clap(subcommand)]
+    send_from: Option<CliSendFrom>,
+}
+
+#[derive(Debug)]
+pub struct Server {
+    pub network_connection_config: crate::common::ConnectionConfig,
+    pub send_from: SendFrom,
+}
+
+impl CliServer {
+    pub fn into_server(self, network_connection_config: crate::common::ConnectionConfig) -> Server {
+        let send_from = match self.send_from {
+            Some(cli_send_from) => SendFrom::from(cli_send_from, &network_connection_config),
+            None => SendFrom::choose_send_from(&network_connection_config),
+        };
+        Server {
+            network_connection_config,
+            send_from,
+        }
+    }
+}
+
+impl CliCustomServer {
+    pub fn into_server(self) -> Server {
+        let url: crate::common::AvailableRpcServerUrl = match self.url {
+            Some(url) => url,
+            None => Input::new()
+                .with_prompt("What is the RPC endpoint?")
+                .interact_text()
+                .unwrap(),
+        };
+        let network_connection_config = crate::common::ConnectionConfig::Custom { url: url.inner };
+        let send_from = match self.send_from {
+            Some(cli_send_from) => SendFrom::from(cli_send_from, &network_connection_config),
+            None => SendFrom::choose_send_from(&network_connection_config),
+        };
+        Server {
+            network_connection_config,
+            send_from,
+        }
+    }
+}
+
+impl Server {
+    pub async fn process(
+        self,
+        prepopulated_unsigned_transaction: near_primitives::transaction::Transaction,
+    ) -> crate::CliResult {
+        self.send_from
+            .process(
+                prepopulated_unsigned_transaction,
+                self.network_connection_config,
+            )
+            .await
+    }
+}
+
+#[derive(Debug, clap::Clap)]
+pub enum CliSendFrom {
+    /// Specify a sender
+    Sender(super::super::super::super::sender::CliSender),
+}
+
+#[derive(Debug)]
+pub enum SendFrom {
+    Sender(super::super::super::super::sender::Sender),
+}
+
+impl SendFrom {
+    fn from(
+        item: CliSendFrom,
+        network_connection_config: &crate::common::ConnectionConfig,
+    ) -> Self {
+        match item {
+            CliSendFrom::Sender(cli_sender) => {
+                Self::Sender(super::super::super::super::sender::Sender::from(
+                    cli_sender,
+                    network_connection_config,
+                ))
+            }
+        }
+    }
+
+    pub fn choose_send_from(network_connection_config: &crate::common::ConnectionConfig) -> Self {
+        Self::from(
+            CliSendFrom::Sender(Default::default()),
+            network_connection_config,
+        )
+    }
+
+    pub async fn process(
+        self,
+        prepopulated_unsigned_transaction: near_primitives::transaction::Transaction,
+        network_connection_config: crate::common::ConnectionConfig,
+    ) -> crate::CliResult {
+        match self {
+            SendFrom::Sender(sender) => {
+                sender
+                    .process(prepopulated_unsigned_transaction, network_connection_config)
+                    .await
+            }
+        }
+    }
+}
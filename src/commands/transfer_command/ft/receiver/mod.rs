@@ -0,0 +1,114 @@
+use dialoguer::Input;
+
+#[derive(Debug, clap::Clap)]
+pub enum CliSendTo {
+    /// Specify a receiver
+    Receiver(CliReceiver),
+}
+
+#[derive(Debug)]
+pub enum SendTo {
+    Receiver(Receiver),
+}
+
+impl SendTo {
+    pub fn from(
+        item: CliSendTo,
+        network_connection_config: &crate::common::ConnectionConfig,
+        ft_contract_account_id: &str,
+    ) -> Self {
+        match item {
+            CliSendTo::Receiver(cli_receiver) => {
+                let receiver = Receiver::from(
+                    cli_receiver,
+                    network_connection_config,
+                    ft_contract_account_id,
+                );
+                Self::Receiver(receiver)
+            }
+        }
+    }
+
+    pub fn send_to(
+        network_connection_config: &crate::common::ConnectionConfig,
+        ft_contract_account_id: &str,
+    ) -> Self {
+        Self::from(
+            CliSendTo::Receiver(Default::default()),
+            network_connection_config,
+            ft_contract_account_id,
+        )
+    }
+
+    pub async fn process(
+        self,
+        prepopulated_unsigned_transaction: near_primitives::transaction::Transaction,
+        network_connection_config: crate::common::ConnectionConfig,
+    ) -> crate::CliResult {
+        match self {
+            SendTo::Receiver(receiver) => {
+                receiver
+                    .process(prepopulated_unsigned_transaction, network_connection_config)
+                    .await
+            }
+        }
+    }
+}
+
+/// данные о получателе токенов
+#[derive(Debug, Default, clap::Clap)]
+pub struct CliReceiver {
+    receiver_account_id: Option<String>,
+    #[clap(subcommand)]
+    transfer: Option<super::transfer_ft_tokens_type::CliTransfer>,
+}
+
+#[derive(Debug)]
+pub struct Receiver {
+    pub transfer: super::transfer_ft_tokens_type::Transfer,
+}
+
+impl Receiver {
+    fn from(
+        item: CliReceiver,
+        network_connection_config: &crate::common::ConnectionConfig,
+        ft_contract_account_id: &str,
+    ) -> Self {
+        let receiver_account_id: String = match item.receiver_account_id {
+            Some(cli_receiver_account_id) => cli_receiver_account_id,
+            None => Receiver::input_receiver_account_id(),
+        };
+        let transfer = match item.transfer {
+            Some(cli_transfer) => super::transfer_ft_tokens_type::Transfer::from(
+                cli_transfer,
+                network_connection_config,
+                ft_contract_account_id,
+                &receiver_account_id,
+            ),
+            None => super::transfer_ft_tokens_type::Transfer::choose_transfer_ft(
+                network_connection_config,
+                ft_contract_account_id,
+                &receiver_account_id,
+            ),
+        };
+        Self { transfer }
+    }
+
+    pub fn input_receiver_account_id() -> String {
+        println!();
+        Input::new()
+            .with_prompt("What is the account ID of the receiver?")
+            .interact_text()
+            .unwrap()
+    }
+
+    pub async fn process(
+        self,
+        prepopulated_unsigned_transaction: near_primitives::transaction::Transaction,
+        network_connection_config: crate::common::ConnectionConfig,
+    ) -> crate::CliResult {
+        self.transfer
+            .process(prepopulated_unsigned_transaction, network_connection_config)
+            .await
+    }
+}
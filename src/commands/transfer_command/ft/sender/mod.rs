@@ -0,0 +1,62 @@
+use dialoguer::Input;
+
+/// данные об отправителе транзакции
+#[derive(Debug, Default, clap::Clap)]
+pub struct CliSender {
+    pub sender_account_id: Option<String>,
+    #[clap(subcommand)]
+    select_ft_contract: Option<super::ft_contract::CliSelectFtContract>,
+}
+
+#[derive(Debug)]
+pub struct Sender {
+    pub sender_account_id: String,
+    pub select_ft_contract: super::ft_contract::SelectFtContract,
+}
+
+impl Sender {
+    pub fn from(
+        item: CliSender,
+        network_connection_config: &crate::common::ConnectionConfig,
+    ) -> Self {
+        let sender_account_id: String = match item.sender_account_id {
+            Some(cli_sender_account_id) => cli_sender_account_id,
+            None => Sender::input_sender_account_id(),
+        };
+        let select_ft_contract = match item.select_ft_contract {
+            Some(cli_select_ft_contract) => super::ft_contract::SelectFtContract::from(
+                cli_select_ft_contract,
+                network_connection_config,
+            ),
+            None => {
+                super::ft_contract::SelectFtContract::select_ft_contract(network_connection_config)
+            }
+        };
+        Self {
+            sender_account_id,
+            select_ft_contract,
+        }
+    }
+
+    pub fn input_sender_account_id() -> String {
+        println!();
+        Input::new()
+            .with_prompt("What is the account ID of the sender?")
+            .interact_text()
+            .unwrap()
+    }
+
+    pub async fn process(
+        self,
+        prepopulated_unsigned_transaction: near_primitives::transaction::Transaction,
+        network_connection_config: crate::common::ConnectionConfig,
+    ) -> crate::CliResult {
+        let unsigned_transaction = near_primitives::transaction::Transaction {
+            signer_id: self.sender_account_id.clone(),
+            ..prepopulated_unsigned_transaction
+        };
+        self.select_ft_contract
+            .process(unsigned_transaction, network_connection_config)
+            .await
+    }
+}
@@ -0,0 +1,345 @@
+use dialoguer::{theme::ColorfulTheme, Input, Select};
+
+const FT_TRANSFER_GAS: near_primitives::types::Gas = 30_000_000_000_000;
+const FT_TRANSFER_CALL_GAS: near_primitives::types::Gas = 100_000_000_000_000;
+const STORAGE_DEPOSIT_GAS: near_primitives::types::Gas = 10_000_000_000_000;
+
+#[derive(Debug, clap::Clap)]
+pub enum CliTransfer {
+    /// Enter an amount
+    Amount(CliTransferFtTokensAction),
+}
+
+#[derive(Debug)]
+pub enum Transfer {
+    Amount(TransferFtTokensAction),
+}
+
+impl Transfer {
+    pub fn from(
+        item: CliTransfer,
+        network_connection_config: &crate::common::ConnectionConfig,
+        ft_contract_account_id: &str,
+        receiver_account_id: &str,
+    ) -> Self {
+        match item {
+            CliTransfer::Amount(cli_transfer_ft_action) => {
+                Self::Amount(TransferFtTokensAction::from(
+                    cli_transfer_ft_action,
+                    network_connection_config,
+                    ft_contract_account_id,
+                    receiver_account_id,
+                ))
+            }
+        }
+    }
+
+    pub fn choose_transfer_ft(
+        network_connection_config: &crate::common::ConnectionConfig,
+        ft_contract_account_id: &str,
+        receiver_account_id: &str,
+    ) -> Self {
+        Self::from(
+            CliTransfer::Amount(Default::default()),
+            network_connection_config,
+            ft_contract_account_id,
+            receiver_account_id,
+        )
+    }
+
+    pub async fn process(
+        self,
+        prepopulated_unsigned_transaction: near_primitives::transaction::Transaction,
+        network_connection_config: crate::common::ConnectionConfig,
+    ) -> crate::CliResult {
+        match self {
+            Transfer::Amount(transfer_ft_action) => {
+                transfer_ft_action
+                    .process(prepopulated_unsigned_transaction, network_connection_config)
+                    .await
+            }
+        }
+    }
+}
+
+/// создание перевода fungible tokens (NEP-141)
+#[derive(Debug, Default, clap::Clap)]
+pub struct CliTransferFtTokensAction {
+    /// The amount in tokens (e.g. 10.5 or 10.5 USDC); the decimals are taken from ft_metadata
+    amount: Option<String>,
+    /// Send the tokens with ft_transfer_call and pass this message to the receiver contract
+    #[clap(long)]
+    msg: Option<String>,
+    /// Register the receiver with storage_deposit without asking when it is not registered yet
+    #[clap(long)]
+    storage_deposit: bool,
+    /// Gas for ft_transfer (30 TeraGas) or ft_transfer_call (100 TeraGas) by default
+    #[clap(long = "prepaid-gas")]
+    gas: Option<crate::common::NearGas>,
+    #[clap(subcommand)]
+    sign_option: Option<
+        crate::commands::construct_transaction_command::sign_transaction::CliSignTransaction,
+    >,
+}
+
+#[derive(Debug)]
+pub struct TransferFtTokensAction {
+    pub ft_metadata: Option<crate::common::FungibleTokenMetadata>,
+    pub receiver_account_id: String,
+    /// An invalid amount given on the command line is reported when the transfer is processed
+    pub amount: Result<u128, String>,
+    pub msg: Option<String>,
+    pub storage_deposit: Option<near_primitives::types::Balance>,
+    pub gas: near_primitives::types::Gas,
+    pub sign_option:
+        crate::commands::construct_transaction_command::sign_transaction::SignTransaction,
+}
+
+impl TransferFtTokensAction {
+    fn from(
+        item: CliTransferFtTokensAction,
+        network_connection_config: &crate::common::ConnectionConfig,
+        ft_contract_account_id: &str,
+        receiver_account_id: &str,
+    ) -> Self {
+        let ft_metadata = match actix::System::new().block_on(
+            crate::common::FungibleTokenMetadata::fetch(
+                network_connection_config,
+                ft_contract_account_id,
+            ),
+        ) {
+            Ok(ft_metadata) => Some(ft_metadata),
+            Err(err) => {
+                println!(
+                    "\nFailed to fetch the metadata of <{}>: {}\nThe amount is taken in the smallest token units.",
+                    ft_contract_account_id, err
+                );
+                None
+            }
+        };
+        let amount = match item.amount {
+            Some(amount) => TransferFtTokensAction::parse_amount(&amount, ft_metadata.as_ref()),
+            None => Ok(TransferFtTokensAction::input_amount(ft_metadata.as_ref())),
+        };
+        let storage_deposit = TransferFtTokensAction::receiver_storage_deposit(
+            network_connection_config,
+            ft_contract_account_id,
+            receiver_account_id,
+            item.storage_deposit,
+        );
+        let gas: near_primitives::types::Gas = match item.gas {
+            Some(cli_gas) => cli_gas.inner,
+            None => match item.msg {
+                Some(_) => FT_TRANSFER_CALL_GAS,
+                None => FT_TRANSFER_GAS,
+            },
+        };
+        let sign_option = match item.sign_option {
+            Some(cli_sign_transaction) => cli_sign_transaction.into(),
+            None => crate::commands::construct_transaction_command::sign_transaction::SignTransaction::choose_sign_option(),
+        };
+        Self {
+            ft_metadata,
+            receiver_account_id: receiver_account_id.to_string(),
+            amount,
+            msg: item.msg,
+            storage_deposit,
+            gas,
+            sign_option,
+        }
+    }
+
+    fn parse_amount(
+        amount: &str,
+        ft_metadata: Option<&crate::common::FungibleTokenMetadata>,
+    ) -> Result<u128, String> {
+        match ft_metadata {
+            Some(ft_metadata) => ft_metadata.parse_amount(amount),
+            None => amount
+                .trim()
+                .parse::<u128>()
+                .map_err(|err| format!("Fungible token amount: {}", err)),
+        }
+    }
+
+    fn format_amount(
+        ft_metadata: Option<&crate::common::FungibleTokenMetadata>,
+        amount: u128,
+    ) -> String {
+        match ft_metadata {
+            Some(ft_metadata) => ft_metadata.format_amount(amount),
+            None => format!("{} (in the smallest token units)", amount),
+        }
+    }
+
+    pub fn input_amount(ft_metadata: Option<&crate::common::FungibleTokenMetadata>) -> u128 {
+        println!();
+        let prompt = match ft_metadata {
+            Some(ft_metadata) => format!(
+                "How many {} tokens do you want to transfer? (example: 10.5 or 10.5 {})",
+                ft_metadata.symbol, ft_metadata.symbol
+            ),
+            None => "How many tokens (in the smallest units) do you want to transfer?".to_string(),
+        };
+        loop {
+            let amount: String = Input::new().with_prompt(&prompt).interact_text().unwrap();
+            match TransferFtTokensAction::parse_amount(&amount, ft_metadata) {
+                Ok(amount) => break amount,
+                Err(err) => println!("{}", err),
+            }
+        }
+    }
+
+    /// Returns the storage deposit to attach when the receiver is not registered in the token contract.
+    fn receiver_storage_deposit(
+        network_connection_config: &crate::common::ConnectionConfig,
+        ft_contract_account_id: &str,
+        receiver_account_id: &str,
+        storage_deposit: bool,
+    ) -> Option<near_primitives::types::Balance> {
+        let storage_deposit_min = actix::System::new().block_on(async {
+            match crate::common::StorageBalance::fetch(
+                network_connection_config,
+                ft_contract_account_id,
+                receiver_account_id,
+            )
+            .await?
+            {
+                Some(_) => Ok(None),
+                None => crate::common::StorageBalanceBounds::fetch(
+                    network_connection_config,
+                    ft_contract_account_id,
+                )
+                .await
+                .map(|storage_balance_bounds| Some(storage_balance_bounds.min)),
+            }
+        });
+        let storage_deposit_min: near_primitives::types::Balance = match storage_deposit_min {
+            Ok(Some(storage_deposit_min)) => storage_deposit_min,
+            Ok(None) => return None,
+            Err(err) => {
+                println!(
+                    "\nWARNING: Failed to check the storage balance of <{}> in <{}>: {}",
+                    receiver_account_id, ft_contract_account_id, err
+                );
+                return None;
+            }
+        };
+        println!(
+            "\n<{}> is not registered in <{}>, so the tokens cannot be received until a storage deposit of {} is paid for it.",
+            receiver_account_id,
+            ft_contract_account_id,
+            crate::common::NearBalance::from_yoctonear(storage_deposit_min)
+        );
+        if storage_deposit {
+            return Some(storage_deposit_min);
+        }
+        let selection = Select::with_theme(&ColorfulTheme::default())
+            .with_prompt("Do you want to pay the storage deposit in the same transaction?")
+            .items(&[
+                "Yes, add storage_deposit to the transaction",
+                "No, transfer the tokens only",
+            ])
+            .default(0)
+            .interact()
+            .unwrap();
+        if selection == 0 {
+            Some(storage_deposit_min)
+        } else {
+            None
+        }
+    }
+
+    pub async fn process(
+        self,
+        prepopulated_unsigned_transaction: near_primitives::transaction::Transaction,
+        network_connection_config: crate::common::ConnectionConfig,
+    ) -> crate::CliResult {
+        let amount = self
+            .amount
+            .map_err(|err| color_eyre::Report::msg(format!("Invalid amount of tokens: {}", err)))?;
+        let mut actions = prepopulated_unsigned_transaction.actions.clone();
+        if let Some(storage_deposit) = self.storage_deposit {
+            actions.push(near_primitives::transaction::Action::FunctionCall(
+                near_primitives::transaction::FunctionCallAction {
+                    method_name: "storage_deposit".to_string(),
+                    args: serde_json::json!({
+                        "account_id": self.receiver_account_id,
+                        "registration_only": true,
+                    })
+                    .to_string()
+                    .into_bytes(),
+                    gas: STORAGE_DEPOSIT_GAS,
+                    deposit: storage_deposit,
+                },
+            ));
+        }
+        let prepopulated_unsigned_transaction = near_primitives::transaction::Transaction {
+            actions,
+            ..prepopulated_unsigned_transaction
+        };
+        let (method_name, args) = match &self.msg {
+            Some(msg) => (
+                "ft_transfer_call",
+                serde_json::json!({
+                    "receiver_id": self.receiver_account_id,
+                    "amount": amount.to_string(),
+                    "msg": msg,
+                }),
+            ),
+            None => (
+                "ft_transfer",
+                serde_json::json!({
+                    "receiver_id": self.receiver_account_id,
+                    "amount": amount.to_string(),
+                }),
+            ),
+        };
+        let transaction_info = match self
+            .sign_option
+            .send_function_call(
+                prepopulated_unsigned_transaction,
+                network_connection_config,
+                method_name,
+                args,
+                self.gas,
+                crate::consts::ONE_YOCTONEAR,
+            )
+            .await?
+        {
+            Some(transaction_info) => transaction_info,
+            None => return Ok(()),
+        };
+        // ft_transfer_call returns the amount actually used by the receiver contract
+        let used_amount = match transaction_info.status {
+            near_primitives::views::FinalExecutionStatus::SuccessValue(ref value) => {
+                near_primitives::serialize::from_base64(value)
+                    .ok()
+                    .and_then(|value| serde_json::from_slice::<String>(&value).ok())
+                    .and_then(|value| value.parse::<u128>().ok())
+            }
+            _ => None,
+        };
+        let ft_metadata = self.ft_metadata.as_ref();
+        match (&self.msg, used_amount) {
+            (Some(_), Some(used_amount)) if used_amount < amount => {
+                println!(
+                    "<{}> has transferred {} to <{}>; {} were refunded by the receiver.",
+                    transaction_info.transaction.signer_id,
+                    TransferFtTokensAction::format_amount(ft_metadata, used_amount),
+                    self.receiver_account_id,
+                    TransferFtTokensAction::format_amount(ft_metadata, amount - used_amount),
+                );
+            }
+            _ => {
+                println!(
+                    "<{}> has transferred {} to <{}> successfully.",
+                    transaction_info.transaction.signer_id,
+                    TransferFtTokensAction::format_amount(ft_metadata, amount),
+                    self.receiver_account_id,
+                );
+            }
+        }
+        Ok(())
+    }
+}
@@ -1,6 +1,8 @@
 use dialoguer::{theme::ColorfulTheme, Select};
 use strum::{EnumDiscriminants, EnumIter, EnumMessage, IntoEnumIterator};
 
+mod ft;
+mod nft;
 pub mod operation_mode;
 mod receiver;
 mod sender;
@@ -43,6 +45,10 @@ impl Currency {
 enum CliCurrencySelection {
     /// отправка трансфера в NEAR tokens
     NEAR(self::operation_mode::CliOperationMode),
+    /// отправка трансфера в fungible tokens (NEP-141)
+    FT(self::ft::operation_mode::CliOperationMode),
+    /// отправка трансфера non-fungible token (NEP-171)
+    NFT(self::nft::operation_mode::CliOperationMode),
 }
 
 #[derive(Debug, EnumDiscriminants)]
@@ -50,12 +56,18 @@ enum CliCurrencySelection {
 enum CurrencySelection {
     #[strum_discriminants(strum(message = "NEAR tokens"))]
     NEAR(self::operation_mode::OperationMode),
+    #[strum_discriminants(strum(message = "Fungible tokens (NEP-141)"))]
+    FT(self::ft::operation_mode::OperationMode),
+    #[strum_discriminants(strum(message = "A non-fungible token (NEP-171)"))]
+    NFT(self::nft::operation_mode::OperationMode),
 }
 
 impl From<CliCurrencySelection> for CurrencySelection {
     fn from(item: CliCurrencySelection) -> Self {
         match item {
             CliCurrencySelection::NEAR(cli_operation_mode) => Self::NEAR(cli_operation_mode.into()),
+            CliCurrencySelection::FT(cli_operation_mode) => Self::FT(cli_operation_mode.into()),
+            CliCurrencySelection::NFT(cli_operation_mode) => Self::NFT(cli_operation_mode.into()),
         }
     }
 }
@@ -76,6 +88,8 @@ impl CurrencySelection {
             .unwrap();
         let cli_currency = match variants[selected_currency] {
             CurrencySelectionDiscriminants::NEAR => CliCurrencySelection::NEAR(Default::default()),
+            CurrencySelectionDiscriminants::FT => CliCurrencySelection::FT(Default::default()),
+            CurrencySelectionDiscriminants::NFT => CliCurrencySelection::NFT(Default::default()),
         };
         Self::from(cli_currency)
     }
@@ -90,6 +104,16 @@ impl CurrencySelection {
                     .process(prepopulated_unsigned_transaction)
                     .await
             }
+            Self::FT(operation_mode) => {
+                operation_mode
+                    .process(prepopulated_unsigned_transaction)
+                    .await
+            }
+            Self::NFT(operation_mode) => {
+                operation_mode
+                    .process(prepopulated_unsigned_transaction)
+                    .await
+            }
         }
     }
 }
@@ -0,0 +1,5 @@
+mod nft_contract;
+pub mod operation_mode;
+mod receiver;
+mod sender;
+mod transfer_nft_type;
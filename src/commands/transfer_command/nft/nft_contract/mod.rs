@@ -0,0 +1,97 @@
+use dialoguer::Input;
+
+#[derive(Debug, clap::Clap)]
+pub enum CliSelectNftContract {
+    /// Specify the non-fungible token contract
+    NftContract(CliNftContract),
+}
+
+#[derive(Debug)]
+pub enum SelectNftContract {
+    NftContract(NftContract),
+}
+
+impl From<CliSelectNftContract> for SelectNftContract {
+    fn from(item: CliSelectNftContract) -> Self {
+        match item {
+            CliSelectNftContract::NftContract(cli_nft_contract) => {
+                Self::NftContract(cli_nft_contract.into())
+            }
+        }
+    }
+}
+
+impl SelectNftContract {
+    pub fn select_nft_contract() -> Self {
+        Self::from(CliSelectNftContract::NftContract(Default::default()))
+    }
+
+    pub async fn process(
+        self,
+        prepopulated_unsigned_transaction: near_primitives::transaction::Transaction,
+        network_connection_config: crate::common::ConnectionConfig,
+    ) -> crate::CliResult {
+        match self {
+            SelectNftContract::NftContract(nft_contract) => {
+                nft_contract
+                    .process(prepopulated_unsigned_transaction, network_connection_config)
+                    .await
+            }
+        }
+    }
+}
+
+/// данные о контракте токенов (NEP-171)
+#[derive(Debug, Default, clap::Clap)]
+pub struct CliNftContract {
+    nft_contract_account_id: Option<String>,
+    #[clap(subcommand)]
+    send_to: Option<super::receiver::CliSendTo>,
+}
+
+#[derive(Debug)]
+pub struct NftContract {
+    pub nft_contract_account_id: String,
+    pub send_to: super::receiver::SendTo,
+}
+
+impl From<CliNftContract> for NftContract {
+    fn from(item: CliNftContract) -> Self {
+        let nft_contract_account_id: String = match item.nft_contract_account_id {
+            Some(cli_nft_contract_account_id) => cli_nft_contract_account_id,
+            None => NftContract::input_nft_contract_account_id(),
+        };
+        let send_to = match item.send_to {
+            Some(cli_send_to) => super::receiver::SendTo::from(cli_send_to),
+            None => super::receiver::SendTo::send_to(),
+        };
+        Self {
+            nft_contract_account_id,
+            send_to,
+        }
+    }
+}
+
+impl NftContract {
+    pub fn input_nft_contract_account_id() -> String {
+        println!();
+        Input::new()
+            .with_prompt("What is the account ID of the non-fungible token contract?")
+            .interact_text()
+            .unwrap()
+    }
+
+    pub async fn process(
+        self,
+        prepopulated_unsigned_transaction: near_primitives::transaction::Transaction,
+        network_connection_config: crate::common::ConnectionConfig,
+    ) -> crate::CliResult {
+        let unsigned_transaction = near_primitives::transaction::Transaction {
+            receiver_id: self.nft_contract_account_id.clone(),
+            ..prepopulated_unsigned_transaction
+        };
+        self.send_to
+            .process(unsigned_transaction, network_connection_config)
+            .await
+    }
+}
@@ -0,0 +1,58 @@
+use dialoguer::Input;
+
+/// данные об отправителе транзакции
+#[derive(Debug, Default, clap::Clap)]
+pub struct CliSender {
+    pub sender_account_id: Option<String>,
+    #[clap(subcommand)]
+    select_nft_contract: Option<super::nft_contract::CliSelectNftContract>,
+}
+
+#[derive(Debug)]
+pub struct Sender {
+    pub sender_account_id: String,
+    pub select_nft_contract: super::nft_contract::SelectNftContract,
+}
+
+impl From<CliSender> for Sender {
+    fn from(item: CliSender) -> Self {
+        let sender_account_id: String = match item.sender_account_id {
+            Some(cli_sender_account_id) => cli_sender_account_id,
+            None => Sender::input_sender_account_id(),
+        };
+        let select_nft_contract = match item.select_nft_contract {
+            Some(cli_select_nft_contract) => {
+                super::nft_contract::SelectNftContract::from(cli_select_nft_contract)
+            }
+            None => super::nft_contract::SelectNftContract::select_nft_contract(),
+        };
+        Self {
+            sender_account_id,
+            select_nft_contract,
+        }
+    }
+}
+
+impl Sender {
+    pub fn input_sender_account_id() -> String {
+        println!();
+        Input::new()
+            .with_prompt("What is the account ID of the sender?")
+            .interact_text()
+            .unwrap()
+    }
+
+    pub async fn process(
+        self,
+        prepopulated_unsigned_transaction: near_primitives::transaction::Transaction,
+        network_connection_config: crate::common::ConnectionConfig,
+    ) -> crate::CliResult {
+        let unsigned_transaction = near_primitives::transaction::Transaction {
+            signer_id: self.sender_account_id.clone(),
+            ..prepopulated_unsigned_transaction
+        };
+        self.select_nft_contract
+            .process(unsigned_transaction, network_connection_config)
+            .await
+    }
+}
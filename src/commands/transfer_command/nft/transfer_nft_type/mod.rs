@@ -0,0 +1,251 @@
+use dialoguer::Input;
+
+const NFT_TRANSFER_GAS: near_primitives::types::Gas = 30_000_000_000_000;
+const NFT_TRANSFER_CALL_GAS: near_primitives::types::Gas = 100_000_000_000_000;
+
+#[derive(Debug, clap::Clap)]
+pub enum CliTransfer {
+    /// Enter a token ID
+    TokenId(CliTransferNftAction),
+}
+
+#[derive(Debug)]
+pub enum Transfer {
+    TokenId(TransferNftAction),
+}
+
+impl From<CliTransfer> for Transfer {
+    fn from(item: CliTransfer) -> Self {
+        match item {
+            CliTransfer::TokenId(cli_transfer_nft_action) => {
+                Self::TokenId(cli_transfer_nft_action.into())
+            }
+        }
+    }
+}
+
+impl Transfer {
+    pub fn choose_transfer_nft() -> Self {
+        Self::from(CliTransfer::TokenId(Default::default()))
+    }
+
+    pub async fn process(
+        self,
+        prepopulated_unsigned_transaction: near_primitives::transaction::Transaction,
+        receiver_account_id: String,
+        network_connection_config: crate::common::ConnectionConfig,
+    ) -> crate::CliResult {
+        match self {
+            Transfer::TokenId(transfer_nft_action) => {
+                transfer_nft_action
+                    .process(
+                        prepopulated_unsigned_transaction,
+                        receiver_account_id,
+                        network_connection_config,
+                    )
+                    .await
+            }
+        }
+    }
+}
+
+/// создание перевода non-fungible token (NEP-171)
+#[derive(Debug, Default, clap::Clap)]
+pub struct CliTransferNftAction {
+    token_id: Option<String>,
+    /// The approval ID (NEP-178) when the sender is approved to transfer the token, but does not own it
+    #[clap(long)]
+    approval_id: Option<u64>,
+    /// A memo for the transfer
+    #[clap(long)]
+    memo: Option<String>,
+    /// Send the token with nft_transfer_call and pass this message to the receiver contract
+    #[clap(long)]
+    msg: Option<String>,
+    /// Gas for nft_transfer (30 TeraGas) or nft_transfer_call (100 TeraGas) by default
+    #[clap(long = "prepaid-gas")]
+    gas: Option<crate::common::NearGas>,
+    #[clap(subcommand)]
+    sign_option: Option<
+        crate::commands::construct_transaction_command::sign_transaction::CliSignTransaction,
+    >,
+}
+
+#[derive(Debug)]
+pub struct TransferNftAction {
+    pub token_id: String,
+    pub approval_id: Option<u64>,
+    pub memo: Option<String>,
+    pub msg: Option<String>,
+    pub gas: near_primitives::types::Gas,
+    pub sign_option:
+        crate::commands::construct_transaction_command::sign_transaction::SignTransaction,
+}
+
+impl From<CliTransferNftAction> for TransferNftAction {
+    fn from(item: CliTransferNftAction) -> Self {
+        let token_id: String = match item.token_id {
+            Some(cli_token_id) => cli_token_id,
+            None => TransferNftAction::input_token_id(),
+        };
+        let gas: near_primitives::types::Gas = match item.gas {
+            Some(cli_gas) => cli_gas.inner,
+            None => match item.msg {
+                Some(_) => NFT_TRANSFER_CALL_GAS,
+                None => NFT_TRANSFER_GAS,
+            },
+        };
+        let sign_option = match item.sign_option {
+            Some(cli_sign_transaction) => cli_sign_transaction.into(),
+            None => crate::commands::construct_transaction_command::sign_transaction::SignTransaction::choose_sign_option(),
+        };
+        Self {
+            token_id,
+            approval_id: item.approval_id,
+            memo: item.memo,
+            msg: item.msg,
+            gas,
+            sign_option,
+        }
+    }
+}
+
+impl TransferNftAction {
+    pub fn input_token_id() -> String {
+        println!();
+        Input::new()
+            .with_prompt("What is the ID of the token you want to transfer?")
+            .interact_text()
+            .unwrap()
+    }
+
+    /// Checks that the sender owns the token or is approved to transfer it and returns the approval ID to use
+    /// along with the owner of the token (unknown when the token cannot be fetched).
+    async fn check_token_ownership(
+        &self,
+        network_connection_config: &crate::common::ConnectionConfig,
+        nft_contract_account_id: &str,
+        sender_account_id: &str,
+    ) -> color_eyre::eyre::Result<(Option<u64>, Option<String>)> {
+        let token = match crate::common::NonFungibleToken::fetch(
+            network_connection_config,
+            nft_contract_account_id,
+            &self.token_id,
+        )
+        .await
+        {
+            Ok(Some(token)) => token,
+            Ok(None) => {
+                return Err(color_eyre::Report::msg(format!(
+                    "The token {:?} does not exist in <{}>",
+                    self.token_id, nft_contract_account_id
+                )))
+            }
+            Err(err) => {
+                println!(
+                    "\nWARNING: Failed to check the owner of the token {:?}: {}",
+                    self.token_id, err
+                );
+                return Ok((self.approval_id, None));
+            }
+        };
+        if token.owner_id == sender_account_id || self.approval_id.is_some() {
+            return Ok((self.approval_id, Some(token.owner_id)));
+        }
+        match token
+            .approved_account_ids
+            .and_then(|approved_account_ids| approved_account_ids.get(sender_account_id).cloned())
+        {
+            Some(approval_id) => {
+                println!(
+                    "\nThe token {:?} is owned by <{}>; <{}> transfers it with the approval ID {}.",
+                    self.token_id, token.owner_id, sender_account_id, approval_id
+                );
+                Ok((Some(approval_id), Some(token.owner_id)))
+            }
+            None => Err(color_eyre::Report::msg(format!(
+                "<{}> is neither the owner of the token {:?} (it is owned by <{}>) nor approved to transfer it",
+                sender_account_id, self.token_id, token.owner_id
+            ))),
+        }
+    }
+
+    pub async fn process(
+        self,
+        prepopulated_unsigned_transaction: near_primitives::transaction::Transaction,
+        receiver_account_id: String,
+        network_connection_config: crate::common::ConnectionConfig,
+    ) -> crate::CliResult {
+        let (approval_id, owner_id) = self
+            .check_token_ownership(
+                &network_connection_config,
+                &prepopulated_unsigned_transaction.receiver_id,
+                &prepopulated_unsigned_transaction.signer_id,
+            )
+            .await?;
+        let mut args = serde_json::json!({
+            "receiver_id": receiver_account_id,
+            "token_id": self.token_id,
+        });
+        if let Some(approval_id) = approval_id {
+            args["approval_id"] = serde_json::json!(approval_id);
+        }
+        if let Some(memo) = &self.memo {
+            args["memo"] = serde_json::json!(memo);
+        }
+        let method_name = match &self.msg {
+            Some(msg) => {
+                args["msg"] = serde_json::json!(msg);
+                "nft_transfer_call"
+            }
+            None => "nft_transfer",
+        };
+        let transaction_info = match self
+            .sign_option
+            .send_function_call(
+                prepopulated_unsigned_transaction,
+                network_connection_config,
+                method_name,
+                args,
+                self.gas,
+                crate::consts::ONE_YOCTONEAR,
+            )
+            .await?
+        {
+            Some(transaction_info) => transaction_info,
+            None => return Ok(()),
+        };
+        // nft_transfer_call returns `false` when the receiver contract has returned the token
+        let is_returned = match transaction_info.status {
+            near_primitives::views::FinalExecutionStatus::SuccessValue(ref value) => {
+                self.msg.is_some()
+                    && near_primitives::serialize::from_base64(value)
+                        .ok()
+                        .and_then(|value| serde_json::from_slice::<bool>(&value).ok())
+                        == Some(false)
+            }
+            _ => false,
+        };
+        if is_returned {
+            match owner_id {
+                Some(owner_id) => println!(
+                    "The token {:?} has been returned to its owner <{}> by <{}>.",
+                    self.token_id, owner_id, receiver_account_id,
+                ),
+                None => println!(
+                    "The token {:?} has been returned to its owner by <{}>.",
+                    self.token_id, receiver_account_id,
+                ),
+            }
+        } else {
+            println!(
+                "<{}> has transferred the token {:?} of <{}> to <{}> successfully.",
+                transaction_info.transaction.signer_id,
+                self.token_id,
+                transaction_info.transaction.receiver_id,
+                receiver_account_id,
+            );
+        }
+        Ok(())
+    }
+}
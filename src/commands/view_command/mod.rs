@@ -4,8 +4,11 @@ use strum::{EnumDiscriminants, EnumIter, EnumMessage, IntoEnumIterator};
 mod view_account;
 mod view_contract_code;
 mod view_contract_state;
+mod view_ft_balances;
+mod view_nfts;
 mod view_nonce;
 mod view_transaction_status;
+mod view_validators;
 
 /// инструмент выбора to view
 #[derive(Debug, Default, clap::Clap)]
@@ -47,6 +50,12 @@ pub enum CliQueryRequest {
     Transaction(self::view_transaction_status::operation_mode::CliOperationMode),
     /// View a nonce for a public key
     Nonce(self::view_nonce::operation_mode::CliOperationMode),
+    /// View fungible token balances for an account
+    FtBalances(self::view_ft_balances::operation_mode::CliOperationMode),
+    /// View non-fungible tokens owned by an account
+    Nfts(self::view_nfts::operation_mode::CliOperationMode),
+    /// View the validators of the current epoch and estimate the seat price
+    Validators(self::view_validators::operation_mode::CliOperationMode),
 }
 
 #[derive(Debug, EnumDiscriminants)]
@@ -62,6 +71,14 @@ pub enum QueryRequest {
     Transaction(self::view_transaction_status::operation_mode::OperationMode),
     #[strum_discriminants(strum(message = "View a nonce for a public key"))]
     Nonce(self::view_nonce::operation_mode::OperationMode),
+    #[strum_discriminants(strum(message = "View fungible token balances for an account"))]
+    FtBalances(self::view_ft_balances::operation_mode::OperationMode),
+    #[strum_discriminants(strum(message = "View non-fungible tokens owned by an account"))]
+    Nfts(self::view_nfts::operation_mode::OperationMode),
+    #[strum_discriminants(strum(
+        message = "View the validators of the current epoch and estimate the seat price"
+    ))]
+    Validators(self::view_validators::operation_mode::OperationMode),
 }
 
 impl From<CliQueryRequest> for QueryRequest {
@@ -82,6 +99,15 @@ impl From<CliQueryRequest> for QueryRequest {
             CliQueryRequest::Nonce(cli_operation_mode) => {
                 QueryRequest::Nonce(cli_operation_mode.into())
             }
+            CliQueryRequest::FtBalances(cli_operation_mode) => {
+                QueryRequest::FtBalances(cli_operation_mode.into())
+            }
+            CliQueryRequest::Nfts(cli_operation_mode) => {
+                QueryRequest::Nfts(cli_operation_mode.into())
+            }
+            CliQueryRequest::Validators(cli_operation_mode) => {
+                QueryRequest::Validators(cli_operation_mode.into())
+            }
         }
     }
 }
@@ -114,6 +140,13 @@ impl QueryRequest {
                 CliQueryRequest::Transaction(Default::default())
             }
             QueryRequestDiscriminants::Nonce => CliQueryRequest::Nonce(Default::default()),
+            QueryRequestDiscriminants::FtBalances => {
+                CliQueryRequest::FtBalances(Default::default())
+            }
+            QueryRequestDiscriminants::Nfts => CliQueryRequest::Nfts(Default::default()),
+            QueryRequestDiscriminants::Validators => {
+                CliQueryRequest::Validators(Default::default())
+            }
         };
         Self::from(cli_request)
     }
@@ -125,6 +158,9 @@ impl QueryRequest {
             QueryRequest::ContractState(operation_mode) => operation_mode.process().await,
             QueryRequest::Transaction(operation_mode) => operation_mode.process().await,
             QueryRequest::Nonce(operation_mode) => operation_mode.process().await,
+            QueryRequest::FtBalances(operation_mode) => operation_mode.process().await,
+            QueryRequest::Nfts(operation_mode) => operation_mode.process().await,
+            QueryRequest::Validators(operation_mode) => operation_mode.process().await,
         }
     }
 }
@@ -1,5 +1,4 @@
 use dialoguer::Input;
-use std::io::Write;
 
 /// Specify the block_id height for this contract to view
 #[derive(Debug, Default, clap::Clap)]
@@ -38,51 +37,14 @@ impl BlockIdHeight {
         self,
         contract_id: String,
         network_connection_config: crate::common::ConnectionConfig,
-        file_path: Option<std::path::PathBuf>,
+        output: super::ContractCodeOutput,
     ) -> crate::CliResult {
-        let query_view_method_response = self
-            .rpc_client(network_connection_config.archival_rpc_url().as_str())
-            .query(near_jsonrpc_primitives::types::query::RpcQueryRequest {
-                block_reference: near_primitives::types::BlockReference::BlockId(
-                    near_primitives::types::BlockId::Height(self.block_id_height.clone()),
-                ),
-                request: near_primitives::views::QueryRequest::ViewCode {
-                    account_id: contract_id,
-                },
-            })
+        let rpc_client = self.rpc_client(network_connection_config.archival_rpc_url().as_str());
+        let block_reference = near_primitives::types::BlockReference::BlockId(
+            near_primitives::types::BlockId::Height(self.block_id_height.clone()),
+        );
+        output
+            .process(&contract_id, &rpc_client, block_reference)
             .await
-            .map_err(|err| {
-                color_eyre::Report::msg(format!(
-                    "Failed to fetch query for view contract: {:?}",
-                    err
-                ))
-            })?;
-        let call_access_view =
-            if let near_jsonrpc_primitives::types::query::QueryResponseKind::ViewCode(result) =
-                query_view_method_response.kind
-            {
-                result
-            } else {
-                return Err(color_eyre::Report::msg(format!("Error call result")));
-            };
-        match &file_path {
-            Some(file_path) => {
-                let dir_name = &file_path.parent().unwrap();
-                std::fs::create_dir_all(&dir_name)?;
-                std::fs::File::create(file_path)
-                    .map_err(|err| {
-                        color_eyre::Report::msg(format!("Failed to create file: {:?}", err))
-                    })?
-                    .write(&call_access_view.code)
-                    .map_err(|err| {
-                        color_eyre::Report::msg(format!("Failed to write to file: {:?}", err))
-                    })?;
-                println!("\nThe file {:?} was downloaded successfully", file_path);
-            }
-            None => {
-                println!("\nHash of the contract: {}", &call_access_view.hash)
-            }
-        }
-        Ok(())
     }
 }
@@ -5,6 +5,172 @@ use strum::{EnumDiscriminants, EnumIter, EnumMessage, IntoEnumIterator};
 mod block_id_hash;
 mod block_id_height;
 
+/// What to do with the contract code fetched at the chosen block.
+#[derive(Debug)]
+pub enum ContractCodeOutput {
+    Download(std::path::PathBuf),
+    Hash,
+    Inspect,
+    /// Compare the hash of a local contract file with the code hash of the account.
+    Verify(std::path::PathBuf),
+    /// Compare the exported functions of the deployed code and a local contract file.
+    Diff(std::path::PathBuf),
+}
+
+/// Fetches the contract code at the block, along with the hash of the block it was read at.
+async fn fetch_contract_code(
+    rpc_client: &near_jsonrpc_client::JsonRpcClient,
+    block_reference: near_primitives::types::BlockReference,
+    contract_id: &str,
+) -> color_eyre::eyre::Result<(
+    near_primitives::views::ContractCodeView,
+    near_primitives::hash::CryptoHash,
+)> {
+    let query_view_method_response = rpc_client
+        .query(near_jsonrpc_primitives::types::query::RpcQueryRequest {
+            block_reference,
+            request: near_primitives::views::QueryRequest::ViewCode {
+                account_id: contract_id.to_string(),
+            },
+        })
+        .await
+        .map_err(|err| {
+            color_eyre::Report::msg(format!(
+                "Failed to fetch query for view contract: {:?}",
+                err
+            ))
+        })?;
+    let call_access_view =
+        if let near_jsonrpc_primitives::types::query::QueryResponseKind::ViewCode(result) =
+            query_view_method_response.kind
+        {
+            result
+        } else {
+            return Err(color_eyre::Report::msg(format!("Error call result")));
+        };
+    Ok((call_access_view, query_view_method_response.block_hash))
+}
+
+impl ContractCodeOutput {
+    /// Queries only what the output needs: Verify reads the code_hash of the account, Inspect
+    /// reads it at the same block as the code.
+    async fn process(
+        &self,
+        contract_id: &str,
+        rpc_client: &near_jsonrpc_client::JsonRpcClient,
+        block_reference: near_primitives::types::BlockReference,
+    ) -> crate::CliResult {
+        match self {
+            Self::Download(file_path) => {
+                let (contract_code, _) =
+                    fetch_contract_code(rpc_client, block_reference, contract_id).await?;
+                let dir_name = &file_path.parent().unwrap();
+                std::fs::create_dir_all(&dir_name)?;
+                std::fs::File::create(file_path)
+                    .map_err(|err| {
+                        color_eyre::Report::msg(format!("Failed to create file: {:?}", err))
+                    })?
+                    .write_all(&contract_code.code)
+                    .map_err(|err| {
+                        color_eyre::Report::msg(format!("Failed to write to file: {:?}", err))
+                    })?;
+                println!("\nThe file {:?} was downloaded successfully", file_path);
+            }
+            Self::Hash => {
+                let (contract_code, _) =
+                    fetch_contract_code(rpc_client, block_reference, contract_id).await?;
+                println!("\nHash of the contract: {}", &contract_code.hash)
+            }
+            Self::Inspect => {
+                let (contract_code, block_hash) =
+                    fetch_contract_code(rpc_client, block_reference, contract_id).await?;
+                let account_code_hash = crate::common::fetch_account_code_hash_at(
+                    rpc_client,
+                    near_primitives::types::BlockReference::BlockId(
+                        near_primitives::types::BlockId::Hash(block_hash),
+                    ),
+                    contract_id,
+                )
+                .await?;
+                let module = crate::common::WasmModuleInfo::parse(&contract_code.code)
+                    .map_err(|err| color_eyre::Report::msg(err))?;
+                let code_hash = near_primitives::hash::hash(&contract_code.code);
+                println!(
+                    "Contract code of {}: {} bytes",
+                    contract_id,
+                    contract_code.code.len()
+                );
+                println!("sha256: {}", code_hash);
+                if code_hash == account_code_hash {
+                    println!("The sha256 matches the code_hash of the account");
+                } else {
+                    println!(
+                        "The sha256 does not match the code_hash of the account ({})",
+                        account_code_hash
+                    );
+                }
+                let exported_functions = module.exported_functions();
+                println!("\nExported functions ({}):", exported_functions.len());
+                for exported_function in exported_functions {
+                    println!("  {}", exported_function);
+                }
+                println!("\nImports ({}):", module.imports.len());
+                for import in module.imports.iter() {
+                    println!("  {}.{} ({})", import.module, import.name, import.kind);
+                }
+                println!("\nCustom sections ({}):", module.custom_sections.len());
+                for (name, content) in module.custom_sections.iter() {
+                    println!(
+                        "  {}: {}",
+                        name,
+                        crate::common::describe_wasm_custom_section(name, content)
+                    );
+                }
+            }
+            Self::Verify(file_path) => {
+                let account_code_hash = crate::common::fetch_account_code_hash_at(
+                    rpc_client,
+                    block_reference,
+                    contract_id,
+                )
+                .await?;
+                let local_code = std::fs::read(file_path).map_err(|err| {
+                    color_eyre::Report::msg(format!("Failed to read file {:?}: {}", file_path, err))
+                })?;
+                let local_code_hash = near_primitives::hash::hash(&local_code);
+                println!("Local file {:?}: {}", file_path, local_code_hash);
+                println!("Code hash of {}: {}", contract_id, account_code_hash);
+                if !crate::common::contract_code_matches_code_hash(&local_code, &account_code_hash)
+                {
+                    println!("\nMISMATCH");
+                    return Err(color_eyre::Report::msg(format!(
+                        "The contract file {:?} does not match the code deployed to {}",
+                        file_path, contract_id
+                    )));
+                }
+                println!("\nMATCH");
+            }
+            Self::Diff(file_path) => {
+                let (contract_code, _) =
+                    fetch_contract_code(rpc_client, block_reference, contract_id).await?;
+                let local_code = std::fs::read(file_path).map_err(|err| {
+                    color_eyre::Report::msg(format!("Failed to read file {:?}: {}", file_path, err))
+                })?;
+                let deployed_module = crate::common::WasmModuleInfo::parse(&contract_code.code)
+                    .map_err(|err| color_eyre::Report::msg(err))?;
+                let local_module = crate::common::WasmModuleInfo::parse(&local_code)
+                    .map_err(|err| color_eyre::Report::msg(err))?;
+                println!(
+                    "Methods of {:?} compared with the code deployed to {}:",
+                    file_path, contract_id
+                );
+                crate::common::ExportedFunctionsDiff::new(&deployed_module, &local_module).print();
+            }
+        }
+        Ok(())
+    }
+}
+
 #[derive(Debug, clap::Clap)]
 pub enum CliBlockId {
     /// Specify a block ID final to view this contract
@@ -66,22 +232,22 @@ impl BlockId {
         self,
         contract_id: String,
         network_connection_config: crate::common::ConnectionConfig,
-        file_path: Option<std::path::PathBuf>,
+        output: ContractCodeOutput,
     ) -> crate::CliResult {
         println!();
         match self {
             Self::AtBlockHeight(block_id_height) => {
                 block_id_height
-                    .process(contract_id, network_connection_config, file_path)
+                    .process(contract_id, network_connection_config, output)
                     .await
             }
             Self::AtBlockHash(block_id_hash) => {
                 block_id_hash
-                    .process(contract_id, network_connection_config, file_path)
+                    .process(contract_id, network_connection_config, output)
                     .await
             }
             Self::AtFinalBlock => {
-                self.at_final_block(contract_id, network_connection_config, file_path)
+                self.at_final_block(contract_id, network_connection_config, output)
                     .await
             }
         }
@@ -95,49 +261,15 @@ impl BlockId {
         self,
         contract_id: String,
         network_connection_config: crate::common::ConnectionConfig,
-        file_path: Option<std::path::PathBuf>,
+        output: ContractCodeOutput,
     ) -> crate::CliResult {
-        let query_view_method_response = self
-            .rpc_client(network_connection_config.rpc_url().as_str())
-            .query(near_jsonrpc_primitives::types::query::RpcQueryRequest {
-                block_reference: near_primitives::types::Finality::Final.into(),
-                request: near_primitives::views::QueryRequest::ViewCode {
-                    account_id: contract_id,
-                },
-            })
+        let rpc_client = self.rpc_client(network_connection_config.rpc_url().as_str());
+        output
+            .process(
+                &contract_id,
+                &rpc_client,
+                near_primitives::types::Finality::Final.into(),
+            )
             .await
-            .map_err(|err| {
-                color_eyre::Report::msg(format!(
-                    "Failed to fetch query for view contract: {:?}",
-                    err
-                ))
-            })?;
-        let call_access_view =
-            if let near_jsonrpc_primitives::types::query::QueryResponseKind::ViewCode(result) =
-                query_view_method_response.kind
-            {
-                result
-            } else {
-                return Err(color_eyre::Report::msg(format!("Error call result")));
-            };
-        match &file_path {
-            Some(file_path) => {
-                let dir_name = &file_path.parent().unwrap();
-                std::fs::create_dir_all(&dir_name)?;
-                std::fs::File::create(file_path)
-                    .map_err(|err| {
-                        color_eyre::Report::msg(format!("Failed to create file: {:?}", err))
-                    })?
-                    .write(&call_access_view.code)
-                    .map_err(|err| {
-                        color_eyre::Report::msg(format!("Failed to write to file: {:?}", err))
-                    })?;
-                println!("\nThe file {:?} was downloaded successfully", file_path);
-            }
-            None => {
-                println!("\nHash of the contract: {}", &call_access_view.hash)
-            }
-        }
-        Ok(())
     }
 }
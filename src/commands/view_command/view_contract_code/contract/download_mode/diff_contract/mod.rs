@@ -0,0 +1,58 @@
+use dialoguer::Input;
+
+// compare the exported functions of a local contract file with the deployed one
+#[derive(Debug, Default, clap::Clap)]
+pub struct CliContractDiff {
+    file_path: Option<std::path::PathBuf>,
+    #[clap(subcommand)]
+    selected_block_id: Option<super::super::super::block_id::CliBlockId>,
+}
+
+#[derive(Debug)]
+pub struct ContractDiff {
+    pub file_path: std::path::PathBuf,
+    pub selected_block_id: super::super::super::block_id::BlockId,
+}
+
+impl ContractDiff {
+    pub fn from(item: CliContractDiff) -> Self {
+        let file_path = match item.file_path {
+            Some(cli_file_path) => cli_file_path,
+            None => ContractDiff::input_file_path(),
+        };
+        let selected_block_id: super::super::super::block_id::BlockId = match item.selected_block_id
+        {
+            Some(cli_block_id) => cli_block_id.into(),
+            None => super::super::super::block_id::BlockId::choose_block_id(),
+        };
+        ContractDiff {
+            file_path,
+            selected_block_id,
+        }
+    }
+}
+
+impl ContractDiff {
+    fn input_file_path() -> std::path::PathBuf {
+        println!();
+        let input_file_path: String = Input::new()
+            .with_prompt("What is a file location of the new contract?")
+            .interact_text()
+            .unwrap();
+        input_file_path.into()
+    }
+
+    pub async fn process(
+        self,
+        contract_id: String,
+        network_connection_config: crate::common::ConnectionConfig,
+    ) -> crate::CliResult {
+        self.selected_block_id
+            .process(
+                contract_id,
+                network_connection_config,
+                super::super::super::block_id::ContractCodeOutput::Diff(self.file_path),
+            )
+            .await
+    }
+}
@@ -49,7 +49,16 @@ impl ContractFile {
         network_connection_config: crate::common::ConnectionConfig,
     ) -> crate::CliResult {
         self.selected_block_id
-            .process(contract_id, network_connection_config, self.file_path)
+            .process(
+                contract_id,
+                network_connection_config,
+                match self.file_path {
+                    Some(file_path) => {
+                        super::super::super::block_id::ContractCodeOutput::Download(file_path)
+                    }
+                    None => super::super::super::block_id::ContractCodeOutput::Hash,
+                },
+            )
             .await
     }
 }
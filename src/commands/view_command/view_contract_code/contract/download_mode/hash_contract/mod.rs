@@ -28,7 +28,11 @@ impl ContractHash {
         network_connection_config: crate::common::ConnectionConfig,
     ) -> crate::CliResult {
         self.selected_block_id
-            .process(contract_id, network_connection_config, None)
+            .process(
+                contract_id,
+                network_connection_config,
+                super::super::super::block_id::ContractCodeOutput::Hash,
+            )
             .await
     }
 }
@@ -0,0 +1,38 @@
+// inspect a contract code
+#[derive(Debug, Default, clap::Clap)]
+pub struct CliContractInspect {
+    #[clap(subcommand)]
+    selected_block_id: Option<super::super::super::block_id::CliBlockId>,
+}
+
+#[derive(Debug)]
+pub struct ContractInspect {
+    pub selected_block_id: super::super::super::block_id::BlockId,
+}
+
+impl ContractInspect {
+    pub fn from(item: CliContractInspect) -> Self {
+        let selected_block_id: super::super::super::block_id::BlockId = match item.selected_block_id
+        {
+            Some(cli_block_id) => cli_block_id.into(),
+            None => super::super::super::block_id::BlockId::choose_block_id(),
+        };
+        ContractInspect { selected_block_id }
+    }
+}
+
+impl ContractInspect {
+    pub async fn process(
+        self,
+        contract_id: String,
+        network_connection_config: crate::common::ConnectionConfig,
+    ) -> crate::CliResult {
+        self.selected_block_id
+            .process(
+                contract_id,
+                network_connection_config,
+                super::super::super::block_id::ContractCodeOutput::Inspect,
+            )
+            .await
+    }
+}
@@ -1,8 +1,11 @@
 use dialoguer::{theme::ColorfulTheme, Select};
 use strum::{EnumDiscriminants, EnumIter, EnumMessage, IntoEnumIterator};
 
+mod diff_contract;
 mod download_contract;
 mod hash_contract;
+mod inspect_contract;
+mod verify_contract;
 
 #[derive(Debug, clap::Clap)]
 pub enum CliDownloadMode {
@@ -10,6 +13,12 @@ pub enum CliDownloadMode {
     Download(self::download_contract::CliContractFile),
     /// View a contract hash
     Hash(self::hash_contract::CliContractHash),
+    /// Inspect the exports, imports and custom sections of a contract
+    Inspect(self::inspect_contract::CliContractInspect),
+    /// Check that a locally built contract file matches the deployed code
+    Verify(self::verify_contract::CliContractVerify),
+    /// Compare the exported functions of the deployed code and a local contract file
+    Diff(self::diff_contract::CliContractDiff),
 }
 
 #[derive(Debug, EnumDiscriminants)]
@@ -19,6 +28,16 @@ pub enum DownloadMode {
     Download(self::download_contract::ContractFile),
     #[strum_discriminants(strum(message = "View a contract hash"))]
     Hash(self::hash_contract::ContractHash),
+    #[strum_discriminants(strum(message = "Inspect the contract code"))]
+    Inspect(self::inspect_contract::ContractInspect),
+    #[strum_discriminants(strum(
+        message = "Verify a local contract file against the deployed code"
+    ))]
+    Verify(self::verify_contract::ContractVerify),
+    #[strum_discriminants(strum(
+        message = "Compare the methods of the deployed code and a local contract file"
+    ))]
+    Diff(self::diff_contract::ContractDiff),
 }
 
 impl DownloadMode {
@@ -30,6 +49,15 @@ impl DownloadMode {
             CliDownloadMode::Hash(cli_contract_hash) => {
                 DownloadMode::Hash(self::hash_contract::ContractHash::from(cli_contract_hash))
             }
+            CliDownloadMode::Inspect(cli_contract_inspect) => DownloadMode::Inspect(
+                self::inspect_contract::ContractInspect::from(cli_contract_inspect),
+            ),
+            CliDownloadMode::Verify(cli_contract_verify) => DownloadMode::Verify(
+                self::verify_contract::ContractVerify::from(cli_contract_verify),
+            ),
+            CliDownloadMode::Diff(cli_contract_diff) => {
+                DownloadMode::Diff(self::diff_contract::ContractDiff::from(cli_contract_diff))
+            }
         }
     }
 }
@@ -51,6 +79,9 @@ impl DownloadMode {
         let cli_mode = match variants[selected_mode] {
             DownloadModeDiscriminants::Download => CliDownloadMode::Download(Default::default()),
             DownloadModeDiscriminants::Hash => CliDownloadMode::Hash(Default::default()),
+            DownloadModeDiscriminants::Inspect => CliDownloadMode::Inspect(Default::default()),
+            DownloadModeDiscriminants::Verify => CliDownloadMode::Verify(Default::default()),
+            DownloadModeDiscriminants::Diff => CliDownloadMode::Diff(Default::default()),
         };
         Self::from(cli_mode, contract_id)
     }
@@ -71,6 +102,21 @@ impl DownloadMode {
                     .process(contract_id, network_connection_config)
                     .await
             }
+            DownloadMode::Inspect(contract_inspect) => {
+                contract_inspect
+                    .process(contract_id, network_connection_config)
+                    .await
+            }
+            DownloadMode::Verify(contract_verify) => {
+                contract_verify
+                    .process(contract_id, network_connection_config)
+                    .await
+            }
+            DownloadMode::Diff(contract_diff) => {
+                contract_diff
+                    .process(contract_id, network_connection_config)
+                    .await
+            }
         }
     }
 }
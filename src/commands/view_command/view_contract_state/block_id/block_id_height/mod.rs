@@ -36,6 +36,7 @@ impl BlockIdHeight {
     pub async fn process(
         self,
         sender_account_id: String,
+        verify_proof: bool,
         network_connection_config: crate::common::ConnectionConfig,
     ) -> crate::CliResult {
         let query_view_method_response = self
@@ -45,7 +46,7 @@ impl BlockIdHeight {
                     near_primitives::types::BlockId::Height(self.block_id_height.clone()),
                 ),
                 request: near_primitives::views::QueryRequest::ViewState {
-                    account_id: sender_account_id,
+                    account_id: sender_account_id.clone(),
                     prefix: near_primitives::types::StoreKey::from(vec![]),
                 },
             })
@@ -72,6 +73,15 @@ impl BlockIdHeight {
             "\nContract state (proof):\n{:#?}\n",
             &call_access_view.proof
         );
+        if verify_proof {
+            crate::common::verify_and_print_view_state_proof(
+                network_connection_config.archival_rpc_url().as_str(),
+                query_view_method_response.block_hash,
+                &sender_account_id,
+                &call_access_view,
+            )
+            .await?;
+        }
         Ok(())
     }
 }
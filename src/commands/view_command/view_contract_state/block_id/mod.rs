@@ -64,22 +64,23 @@ impl BlockId {
     pub async fn process(
         self,
         sender_account_id: String,
+        verify_proof: bool,
         network_connection_config: crate::common::ConnectionConfig,
     ) -> crate::CliResult {
         println!();
         match self {
             Self::AtBlockHeight(block_id_height) => {
                 block_id_height
-                    .process(sender_account_id, network_connection_config)
+                    .process(sender_account_id, verify_proof, network_connection_config)
                     .await
             }
             Self::AtBlockHash(block_id_hash) => {
                 block_id_hash
-                    .process(sender_account_id, network_connection_config)
+                    .process(sender_account_id, verify_proof, network_connection_config)
                     .await
             }
             Self::AtFinalBlock => {
-                self.at_final_block(sender_account_id, network_connection_config)
+                self.at_final_block(sender_account_id, verify_proof, network_connection_config)
                     .await
             }
         }
@@ -92,6 +93,7 @@ impl BlockId {
     async fn at_final_block(
         self,
         sender_account_id: String,
+        verify_proof: bool,
         network_connection_config: crate::common::ConnectionConfig,
     ) -> crate::CliResult {
         let query_view_method_response = self
@@ -99,7 +101,7 @@ impl BlockId {
             .query(near_jsonrpc_primitives::types::query::RpcQueryRequest {
                 block_reference: near_primitives::types::Finality::Final.into(),
                 request: near_primitives::views::QueryRequest::ViewState {
-                    account_id: sender_account_id,
+                    account_id: sender_account_id.clone(),
                     prefix: near_primitives::types::StoreKey::from(vec![]),
                 },
             })
@@ -126,6 +128,15 @@ impl BlockId {
             "\nContract state (proof):\n{:#?}\n",
             &call_access_view.proof
         );
+        if verify_proof {
+            crate::common::verify_and_print_view_state_proof(
+                network_connection_config.rpc_url().as_str(),
+                query_view_method_response.block_hash,
+                &sender_account_id,
+                &call_access_view,
+            )
+            .await?;
+        }
         Ok(())
     }
 }
@@ -41,6 +41,10 @@ impl SendTo {
 #[derive(Debug, Default, clap::Clap)]
 pub struct CliSender {
     pub sender_account_id: Option<String>,
+    /// Verify the returned key/value pairs against the state root of the shard, as reported
+    /// by the same RPC server
+    #[clap(long)]
+    verify_proof: bool,
     #[clap(subcommand)]
     selected_block_id: Option<super::block_id::CliBlockId>,
 }
@@ -48,6 +52,7 @@ pub struct CliSender {
 #[derive(Debug)]
 pub struct Sender {
     pub sender_account_id: String,
+    verify_proof: bool,
     selected_block_id: super::block_id::BlockId,
 }
 
@@ -63,6 +68,7 @@ impl From<CliSender> for Sender {
         };
         Self {
             sender_account_id,
+            verify_proof: item.verify_proof,
             selected_block_id,
         }
     }
@@ -82,7 +88,11 @@ impl Sender {
         network_connection_config: crate::common::ConnectionConfig,
     ) -> crate::CliResult {
         self.selected_block_id
-            .process(self.sender_account_id, network_connection_config)
+            .process(
+                self.sender_account_id,
+                self.verify_proof,
+                network_connection_config,
+            )
             .await
     }
 }
@@ -0,0 +1,197 @@
+use dialoguer::Input;
+
+#[derive(Debug, clap::Clap)]
+pub enum CliSendTo {
+    /// Specify an account
+    Account(CliAccount),
+}
+
+#[derive(Debug)]
+pub enum SendTo {
+    Account(Account),
+}
+
+impl From<CliSendTo> for SendTo {
+    fn from(item: CliSendTo) -> Self {
+        match item {
+            CliSendTo::Account(cli_account) => {
+                let account = Account::from(cli_account);
+                Self::Account(account)
+            }
+        }
+    }
+}
+
+impl SendTo {
+    pub fn send_to() -> Self {
+        Self::from(CliSendTo::Account(Default::default()))
+    }
+
+    pub async fn process(
+        self,
+        network_connection_config: crate::common::ConnectionConfig,
+    ) -> crate::CliResult {
+        match self {
+            SendTo::Account(account) => account.process(network_connection_config).await,
+        }
+    }
+}
+
+/// Specify account to view the fungible token balances for
+#[derive(Debug, Default, clap::Clap)]
+pub struct CliAccount {
+    account_id: Option<String>,
+    /// Fungible token contracts: a comma-separated list or a path to a file with them
+    #[clap(long)]
+    tokens: Option<crate::common::AccountIdList>,
+    /// Show the balances as a table (plaintext) or as JSON
+    #[clap(long, default_value = "plaintext")]
+    format: crate::common::OutputFormat,
+}
+
+#[derive(Debug)]
+pub struct Account {
+    account_id: String,
+    ft_contract_account_ids: Vec<String>,
+    format: crate::common::OutputFormat,
+}
+
+impl From<CliAccount> for Account {
+    fn from(item: CliAccount) -> Self {
+        let account_id: String = match item.account_id {
+            Some(cli_account_id) => cli_account_id,
+            None => Account::input_account_id(),
+        };
+        let ft_contract_account_ids: Vec<String> = match item.tokens {
+            Some(cli_tokens) => cli_tokens.inner,
+            None => Account::input_ft_contract_account_ids(),
+        };
+        Self {
+            account_id,
+            ft_contract_account_ids,
+            format: item.format,
+        }
+    }
+}
+
+impl Account {
+    fn input_account_id() -> String {
+        println!();
+        Input::new()
+            .with_prompt("Enter your account ID")
+            .interact_text()
+            .unwrap()
+    }
+
+    fn input_ft_contract_account_ids() -> Vec<String> {
+        println!();
+        let tokens: crate::common::AccountIdList = Input::new()
+            .with_prompt("Enter the fungible token contracts (comma-separated) or a path to a file with them")
+            .interact_text()
+            .unwrap();
+        tokens.inner
+    }
+
+    pub async fn process(
+        self,
+        network_connection_config: crate::common::ConnectionConfig,
+    ) -> crate::CliResult {
+        let account_id = &self.account_id;
+        let network_connection_config = &network_connection_config;
+        let ft_balances = futures::future::join_all(self.ft_contract_account_ids.iter().map(
+            |ft_contract_account_id| async move {
+                futures::try_join!(
+                    crate::common::FungibleTokenMetadata::fetch(
+                        network_connection_config,
+                        ft_contract_account_id,
+                    ),
+                    crate::common::fetch_ft_balance(
+                        network_connection_config,
+                        ft_contract_account_id,
+                        account_id,
+                    ),
+                )
+            },
+        ))
+        .await;
+        let mut held_ft_balances = Vec::new();
+        for (ft_contract_account_id, ft_balance) in
+            self.ft_contract_account_ids.iter().zip(ft_balances)
+        {
+            match ft_balance {
+                Ok((_, 0)) => {}
+                Ok((ft_metadata, ft_balance)) => {
+                    held_ft_balances.push((ft_contract_account_id, ft_metadata, ft_balance))
+                }
+                Err(err) => eprintln!(
+                    "WARNING: Failed to fetch the balance in <{}>: {}",
+                    ft_contract_account_id, err
+                ),
+            }
+        }
+        match self.format {
+            crate::common::OutputFormat::Plaintext => {
+                if held_ft_balances.is_empty() {
+                    println!(
+                        "\n<{}> holds none of the listed fungible tokens.",
+                        self.account_id
+                    );
+                    return Ok(());
+                }
+                let rows = held_ft_balances
+                    .iter()
+                    .map(|(ft_contract_account_id, ft_metadata, ft_balance)| {
+                        (
+                            ft_contract_account_id.to_string(),
+                            ft_metadata.format_amount(*ft_balance),
+                        )
+                    })
+                    .collect::<Vec<_>>();
+                let contract_column_width = rows
+                    .iter()
+                    .map(|(ft_contract_account_id, _)| ft_contract_account_id.len())
+                    .chain(std::iter::once("Token contract".len()))
+                    .max()
+                    .unwrap_or_default();
+                println!(
+                    "\nFungible token balances of <{}>:\n\n{:<width$}  Balance",
+                    self.account_id,
+                    "Token contract",
+                    width = contract_column_width
+                );
+                for (ft_contract_account_id, ft_balance) in rows {
+                    println!(
+                        "{:<width$}  {}",
+                        ft_contract_account_id,
+                        ft_balance,
+                        width = contract_column_width
+                    );
+                }
+            }
+            crate::common::OutputFormat::Json => {
+                let balances = held_ft_balances
+                    .iter()
+                    .map(|(ft_contract_account_id, ft_metadata, ft_balance)| {
+                        serde_json::json!({
+                            "contract_id": ft_contract_account_id,
+                            "symbol": ft_metadata.symbol,
+                            "name": ft_metadata.name,
+                            "decimals": ft_metadata.decimals,
+                            "balance": ft_balance.to_string(),
+                            "balance_formatted": ft_metadata.format_amount(*ft_balance),
+                        })
+                    })
+                    .collect::<Vec<_>>();
+                println!(
+                    "{}",
+                    serde_json::to_string_pretty(&serde_json::json!({
+                        "account_id": self.account_id,
+                        "balances": balances,
+                    }))
+                    .unwrap()
+                );
+            }
+        }
+        Ok(())
+    }
+}
@@ -0,0 +1,236 @@
+use dialoguer::Input;
+
+/// The number of tokens requested from the contract at once.
+const NFT_TOKENS_PAGE_SIZE: u64 = 50;
+
+#[derive(Debug, clap::Clap)]
+pub enum CliSendTo {
+    /// Specify an account
+    Account(CliAccount),
+}
+
+#[derive(Debug)]
+pub enum SendTo {
+    Account(Account),
+}
+
+impl From<CliSendTo> for SendTo {
+    fn from(item: CliSendTo) -> Self {
+        match item {
+            CliSendTo::Account(cli_account) => {
+                let account = Account::from(cli_account);
+                Self::Account(account)
+            }
+        }
+    }
+}
+
+impl SendTo {
+    pub fn send_to() -> Self {
+        Self::from(CliSendTo::Account(Default::default()))
+    }
+
+    pub async fn process(
+        self,
+        network_connection_config: crate::common::ConnectionConfig,
+    ) -> crate::CliResult {
+        match self {
+            SendTo::Account(account) => account.process(network_connection_config).await,
+        }
+    }
+}
+
+/// Specify account to view the non-fungible tokens for
+#[derive(Debug, Default, clap::Clap)]
+pub struct CliAccount {
+    account_id: Option<String>,
+    /// The non-fungible token (NEP-171) contract
+    #[clap(long)]
+    contract: Option<String>,
+    /// Skip this number of tokens
+    #[clap(long, default_value = "0")]
+    from_index: u128,
+    /// Show at most this number of tokens (all of them by default)
+    #[clap(long)]
+    limit: Option<u64>,
+    /// Show the tokens as a list (plaintext) or as JSON
+    #[clap(long, default_value = "plaintext")]
+    format: crate::common::OutputFormat,
+}
+
+#[derive(Debug)]
+pub struct Account {
+    account_id: String,
+    nft_contract_account_id: String,
+    from_index: u128,
+    limit: Option<u64>,
+    format: crate::common::OutputFormat,
+}
+
+impl From<CliAccount> for Account {
+    fn from(item: CliAccount) -> Self {
+        let account_id: String = match item.account_id {
+            Some(cli_account_id) => cli_account_id,
+            None => Account::input_account_id(),
+        };
+        let nft_contract_account_id: String = match item.contract {
+            Some(cli_contract) => cli_contract,
+            None => Account::input_nft_contract_account_id(),
+        };
+        Self {
+            account_id,
+            nft_contract_account_id,
+            from_index: item.from_index,
+            limit: item.limit,
+            format: item.format,
+        }
+    }
+}
+
+impl Account {
+    fn input_account_id() -> String {
+        println!();
+        Input::new()
+            .with_prompt("Enter your account ID")
+            .interact_text()
+            .unwrap()
+    }
+
+    fn input_nft_contract_account_id() -> String {
+        println!();
+        Input::new()
+            .with_prompt("What is the account ID of the non-fungible token contract?")
+            .interact_text()
+            .unwrap()
+    }
+
+    async fn fetch_tokens(
+        &self,
+        network_connection_config: &crate::common::ConnectionConfig,
+    ) -> color_eyre::eyre::Result<Vec<crate::common::NonFungibleToken>> {
+        let mut tokens = Vec::new();
+        loop {
+            let page_size = match self.limit {
+                Some(limit) => std::cmp::min(limit - tokens.len() as u64, NFT_TOKENS_PAGE_SIZE),
+                None => NFT_TOKENS_PAGE_SIZE,
+            };
+            if page_size == 0 {
+                break;
+            }
+            let page = crate::common::NonFungibleToken::fetch_for_owner(
+                network_connection_config,
+                &self.nft_contract_account_id,
+                &self.account_id,
+                self.from_index + tokens.len() as u128,
+                page_size,
+            )
+            .await?;
+            let is_last_page = (page.len() as u64) < page_size;
+            tokens.extend(page);
+            if is_last_page {
+                break;
+            }
+        }
+        Ok(tokens)
+    }
+
+    pub async fn process(
+        self,
+        network_connection_config: crate::common::ConnectionConfig,
+    ) -> crate::CliResult {
+        let (nft_contract_metadata, tokens) = futures::join!(
+            crate::common::NonFungibleTokenContractMetadata::fetch(
+                &network_connection_config,
+                &self.nft_contract_account_id,
+            ),
+            self.fetch_tokens(&network_connection_config),
+        );
+        let tokens = tokens?;
+        let nft_contract_metadata = match nft_contract_metadata {
+            Ok(nft_contract_metadata) => Some(nft_contract_metadata),
+            Err(err) => {
+                eprintln!(
+                    "WARNING: Failed to fetch the metadata of <{}>: {}",
+                    self.nft_contract_account_id, err
+                );
+                None
+            }
+        };
+        let media_url = |media: &str| match &nft_contract_metadata {
+            Some(nft_contract_metadata) => nft_contract_metadata.media_url(media),
+            None => media.to_string(),
+        };
+        match self.format {
+            crate::common::OutputFormat::Plaintext => {
+                let collection = match &nft_contract_metadata {
+                    Some(nft_contract_metadata) => format!(
+                        "{} ({}) <{}>",
+                        nft_contract_metadata.name,
+                        nft_contract_metadata.symbol,
+                        self.nft_contract_account_id
+                    ),
+                    None => format!("<{}>", self.nft_contract_account_id),
+                };
+                if tokens.is_empty() {
+                    println!("\n<{}> owns no tokens of {}.", self.account_id, collection);
+                    return Ok(());
+                }
+                println!(
+                    "\n<{}> owns {} token(s) of {}:",
+                    self.account_id,
+                    tokens.len(),
+                    collection
+                );
+                for token in tokens {
+                    let metadata = token.metadata.unwrap_or_default();
+                    match metadata.title {
+                        Some(title) => println!("\n  {}: {}", token.token_id, title),
+                        None => println!("\n  {}", token.token_id),
+                    }
+                    if let Some(description) = metadata.description {
+                        println!("    {}", description);
+                    }
+                    if let Some(media) = metadata.media {
+                        println!("    media: {}", media_url(&media));
+                    }
+                    for (account_id, approval_id) in token.approved_account_ids.unwrap_or_default()
+                    {
+                        println!(
+                            "    approved: <{}> (approval ID {})",
+                            account_id, approval_id
+                        );
+                    }
+                }
+            }
+            crate::common::OutputFormat::Json => {
+                let tokens = tokens
+                    .into_iter()
+                    .map(|token| {
+                        let metadata = token.metadata.unwrap_or_default();
+                        serde_json::json!({
+                            "token_id": token.token_id,
+                            "owner_id": token.owner_id,
+                            "title": metadata.title,
+                            "description": metadata.description,
+                            "media": metadata.media.as_deref().map(media_url),
+                            "copies": metadata.copies,
+                            "approved_account_ids": token.approved_account_ids,
+                        })
+                    })
+                    .collect::<Vec<_>>();
+                println!(
+                    "{}",
+                    serde_json::to_string_pretty(&serde_json::json!({
+                        "account_id": self.account_id,
+                        "contract_id": self.nft_contract_account_id,
+                        "name": nft_contract_metadata.as_ref().map(|metadata| &metadata.name),
+                        "symbol": nft_contract_metadata.as_ref().map(|metadata| &metadata.symbol),
+                        "tokens": tokens,
+                    }))
+                    .unwrap()
+                );
+            }
+        }
+        Ok(())
+    }
+}
@@ -46,11 +46,36 @@ impl SendFrom {
 #[derive(Debug, Default, clap::Clap)]
 pub struct CliSender {
     pub account_id: Option<String>,
+    /// Query EXPERIMENTAL_tx_status instead of tx to show the actions of every receipt in the
+    /// receipt tree and the transfers and refunds of the transaction
+    #[clap(long)]
+    receipts: bool,
+    /// Print only the NEP-297 events of the transaction with the receipt and the contract which
+    /// emitted them, one JSON object per line
+    #[clap(long)]
+    events_only: bool,
+    /// Export the receipt graph (dot, mermaid)
+    #[clap(long)]
+    graph: Option<crate::common::GraphFormat>,
+    /// Write the receipt graph to this file instead of the terminal
+    #[clap(long)]
+    graph_file: Option<std::path::PathBuf>,
+    /// Prove the transaction outcome with the light client proof instead of trusting the RPC server
+    #[clap(long)]
+    verify: bool,
+    /// The hash of a final block you trust to anchor the light client proof
+    #[clap(long)]
+    light_client_head: Option<near_primitives::hash::CryptoHash>,
 }
 
 #[derive(Debug, Clone)]
 pub struct Sender {
     pub account_id: String,
+    pub receipts: bool,
+    pub events_only: bool,
+    pub graph: Option<crate::common::GraphFormat>,
+    pub graph_file: Option<std::path::PathBuf>,
+    pub light_client_head: Option<near_primitives::hash::CryptoHash>,
 }
 
 impl From<CliSender> for Sender {
@@ -59,7 +84,19 @@ impl From<CliSender> for Sender {
             Some(cli_account_id) => cli_account_id,
             None => Sender::input_sender_account_id(),
         };
-        Self { account_id }
+        let light_client_head = match (item.verify, item.light_client_head) {
+            (_, Some(light_client_head)) => Some(light_client_head),
+            (true, None) => Some(crate::common::input_light_client_head()),
+            (false, None) => None,
+        };
+        Self {
+            account_id,
+            receipts: item.receipts,
+            events_only: item.events_only,
+            graph: item.graph,
+            graph_file: item.graph_file,
+            light_client_head,
+        }
     }
 }
 
@@ -72,27 +109,84 @@ impl Sender {
             .unwrap()
     }
 
-    fn rpc_client(&self, selected_server_url: &str) -> near_jsonrpc_client::JsonRpcClient {
-        near_jsonrpc_client::new_client(&selected_server_url)
-    }
-
     pub async fn process(
         self,
         network_connection_config: crate::common::ConnectionConfig,
         transaction_hash: String,
     ) -> crate::CliResult {
         let account_id = self.account_id.clone();
-        let query_view_transaction_status = self
-            .rpc_client(network_connection_config.archival_rpc_url().as_str())
-            .tx(transaction_hash, account_id)
-            .await
-            .map_err(|err| {
-                color_eyre::Report::msg(format!(
-                    "Failed to fetch query for view transaction: {:?}",
-                    err
-                ))
-            })?;
-        println!("Transactiion status: {:#?}", query_view_transaction_status);
+        let archival_rpc_url = network_connection_config.archival_rpc_url();
+        let params = serde_json::json!([transaction_hash, account_id]);
+        let (final_outcome, receipts) = if self.receipts {
+            let query_view_transaction_status: near_primitives::views::FinalExecutionOutcomeWithReceiptView =
+                crate::common::call_json_rpc_method(
+                    archival_rpc_url.as_str(),
+                    "EXPERIMENTAL_tx_status",
+                    params,
+                )
+                .await
+                .map_err(|err| {
+                    color_eyre::Report::msg(format!(
+                        "Failed to fetch query for view transaction: {:?}",
+                        err
+                    ))
+                })?;
+            (
+                query_view_transaction_status.final_outcome,
+                query_view_transaction_status.receipts,
+            )
+        } else {
+            let final_outcome: near_primitives::views::FinalExecutionOutcomeView =
+                crate::common::call_json_rpc_method(archival_rpc_url.as_str(), "tx", params)
+                    .await
+                    .map_err(|err| {
+                        color_eyre::Report::msg(format!(
+                            "Failed to fetch query for view transaction: {:?}",
+                            err
+                        ))
+                    })?;
+            (final_outcome, vec![])
+        };
+        if self.events_only {
+            for event in crate::common::transaction_events(&final_outcome) {
+                println!("{}", serde_json::to_string(&event)?);
+            }
+        } else {
+            crate::common::print_transaction_status(&final_outcome, &receipts, self.receipts);
+        }
+        if let Some(graph_format) = &self.graph {
+            let graph =
+                crate::common::transaction_receipt_graph(&final_outcome, &receipts, graph_format);
+            match &self.graph_file {
+                Some(graph_file) => {
+                    std::fs::write(graph_file, graph).map_err(|err| {
+                        color_eyre::Report::msg(format!(
+                            "Failed to write the receipt graph to {:?}: {}",
+                            graph_file, err
+                        ))
+                    })?;
+                    println!("\nThe receipt graph is saved in {:?}", graph_file);
+                }
+                None => println!("\n{}", graph),
+            }
+        }
+        if let Some(light_client_head) = self.light_client_head {
+            let transaction_hash: near_primitives::hash::CryptoHash =
+                transaction_hash.parse().map_err(|err| {
+                    color_eyre::Report::msg(format!("Transaction hash is invalid: {:?}", err))
+                })?;
+            let outcome_block_hash = crate::common::verify_transaction_outcome_with_light_client(
+                network_connection_config.archival_rpc_url().as_str(),
+                &transaction_hash,
+                &self.account_id,
+                &light_client_head,
+            )
+            .await?;
+            println!(
+                "\nLight client verification: the outcome of {} is proven in block {} against the trusted head {}",
+                transaction_hash, outcome_block_hash, light_client_head
+            );
+        }
         Ok(())
     }
 }
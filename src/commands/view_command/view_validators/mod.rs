@@ -0,0 +1,106 @@
+pub mod operation_mode;
+
+async fn display_validators(
+    network_connection_config: crate::common::ConnectionConfig,
+) -> crate::CliResult {
+    let (epoch_validators, seat_price_config) = futures::try_join!(
+        crate::common::EpochValidators::fetch(&network_connection_config, None),
+        crate::common::SeatPriceConfig::fetch(&network_connection_config),
+    )
+    .map_err(|err| color_eyre::Report::msg(format!("Failed to fetch the validators: {:?}", err)))?;
+
+    match epoch_validators.epoch_height {
+        Some(epoch_height) => println!(
+            "\nEpoch {} started at block #{}",
+            epoch_height, epoch_validators.epoch_start_height
+        ),
+        None => println!(
+            "\nThe current epoch started at block #{}",
+            epoch_validators.epoch_start_height
+        ),
+    }
+
+    let current_stakes = epoch_validators
+        .current_validators
+        .iter()
+        .map(|validator| validator.stake)
+        .collect::<Vec<_>>();
+    println!(
+        "\nCurrent validators ({}), seat price {}:",
+        epoch_validators.current_validators.len(),
+        seat_price_summary(&seat_price_config, &current_stakes)
+    );
+    let mut current_validators = epoch_validators.current_validators.clone();
+    current_validators.sort_by(|a, b| b.stake.cmp(&a.stake));
+    for validator in current_validators.iter() {
+        println!(
+            "  {:<40} {:>28}  blocks {}/{}  chunks {}/{}{}",
+            validator.account_id,
+            crate::common::NearBalance::from_yoctonear(validator.stake).to_string(),
+            validator.num_produced_blocks,
+            validator.num_expected_blocks,
+            validator.num_produced_chunks,
+            validator.num_expected_chunks,
+            if validator.is_slashed {
+                "  SLASHED"
+            } else {
+                ""
+            }
+        );
+    }
+
+    println!(
+        "\nNext validators ({}):",
+        epoch_validators.next_validators.len()
+    );
+    print_validator_stakes(&epoch_validators.next_validators);
+
+    println!(
+        "\nCurrent proposals ({}):",
+        epoch_validators.current_proposals.len()
+    );
+    print_validator_stakes(&epoch_validators.current_proposals);
+
+    println!(
+        "\nKicked out in the previous epoch ({}):",
+        epoch_validators.prev_epoch_kickout.len()
+    );
+    for kickout in epoch_validators.prev_epoch_kickout.iter() {
+        println!(
+            "  {:<40} {}",
+            kickout.account_id,
+            crate::common::validator_kickout_reason_summary(&kickout.reason)
+        );
+    }
+
+    println!(
+        "\nEstimated seat price for a stake proposal made now: {}",
+        seat_price_summary(&seat_price_config, &epoch_validators.proposed_stakes(None))
+    );
+    println!(
+        "(the actual seat price depends on the proposals submitted until the end of the epoch)"
+    );
+    Ok(())
+}
+
+fn print_validator_stakes(validators: &[crate::common::ValidatorStake]) {
+    let mut validators = validators.to_vec();
+    validators.sort_by(|a, b| b.stake.cmp(&a.stake));
+    for validator in validators.iter() {
+        println!(
+            "  {:<40} {:>28}",
+            validator.account_id,
+            crate::common::NearBalance::from_yoctonear(validator.stake).to_string()
+        );
+    }
+}
+
+fn seat_price_summary(
+    seat_price_config: &crate::common::SeatPriceConfig,
+    stakes: &[u128],
+) -> String {
+    match seat_price_config.estimate_seat_price(stakes) {
+        Some(seat_price) => crate::common::NearBalance::from_yoctonear(seat_price).to_string(),
+        None => "unknown (not enough stake to fill the seats)".to_string(),
+    }
+}
@@ -0,0 +1,44 @@
+use dialoguer::Input;
+
+/// предустановленный RPC-сервер
+#[derive(Debug, Default, clap::Clap)]
+pub struct CliServer {}
+
+/// данные для custom server
+#[derive(Debug, Default, clap::Clap)]
+pub struct CliCustomServer {
+    #[clap(long)]
+    pub url: Option<crate::common::AvailableRpcServerUrl>,
+}
+
+#[derive(Debug)]
+pub struct Server {
+    pub connection_config: crate::common::ConnectionConfig,
+}
+
+impl CliServer {
+    pub fn into_server(self, connection_config: crate::common::ConnectionConfig) -> Server {
+        Server { connection_config }
+    }
+}
+
+impl CliCustomServer {
+    pub fn into_server(self) -> Server {
+        let url: crate::common::AvailableRpcServerUrl = match self.url {
+            Some(url) => url,
+            None => Input::new()
+                .with_prompt("What is the RPC endpoint?")
+                .interact_text()
+                .unwrap(),
+        };
+        Server {
+            connection_config: crate::common::ConnectionConfig::Custom { url: url.inner },
+        }
+    }
+}
+
+impl Server {
+    pub async fn process(self) -> crate::CliResult {
+        super::super::super::super::display_validators(self.connection_config).await
+    }
+}
@@ -1,6 +1,6 @@
 use std::convert::TryInto;
 
-use near_primitives::borsh::BorshDeserialize;
+use near_primitives::borsh::{BorshDeserialize, BorshSerialize};
 
 #[derive(
     Debug,
@@ -17,6 +17,21 @@ pub enum OutputFormat {
     Json,
 }
 
+#[derive(
+    Debug,
+    Clone,
+    strum_macros::IntoStaticStr,
+    strum_macros::EnumString,
+    strum_macros::EnumVariantNames,
+    smart_default::SmartDefault,
+)]
+#[strum(serialize_all = "snake_case")]
+pub enum GraphFormat {
+    #[default]
+    Dot,
+    Mermaid,
+}
+
 #[derive(Debug, Clone)]
 pub struct TransactionAsBase64 {
     pub inner: near_primitives::transaction::Transaction,
@@ -30,65 +45,2144 @@ impl std::str::FromStr for TransactionAsBase64 {
                 &near_primitives::serialize::from_base64(s)
                     .map_err(|err| format!("base64 transaction sequence is invalid: {}", err))?,
             )
-            .map_err(|err| format!("transaction could not be parsed: {}", err))?,
-        })
+            .map_err(|err| format!("transaction could not be parsed: {}", err))?,
+        })
+    }
+}
+
+impl std::fmt::Display for TransactionAsBase64 {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        write!(f, "Transaction {}", self.inner.get_hash_and_size().0)
+    }
+}
+
+#[derive(Debug, Clone)]
+pub struct BlockHashAsBase58 {
+    pub inner: near_primitives::hash::CryptoHash,
+}
+
+impl std::str::FromStr for BlockHashAsBase58 {
+    type Err = String;
+    fn from_str(s: &str) -> Result<Self, Self::Err> {
+        Ok(Self {
+            inner: near_primitives::serialize::from_base(s)
+                .map_err(|err| format!("base block hash sequence is invalid: {}", err))?
+                .as_slice()
+                .try_into()
+                .map_err(|err| format!("block hash could not be collected: {}", err))?,
+        })
+    }
+}
+
+impl std::fmt::Display for BlockHashAsBase58 {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        write!(f, "BlockHash {}", self.inner)
+    }
+}
+
+#[derive(Debug, Clone, PartialEq)]
+pub struct AvailableRpcServerUrl {
+    pub inner: url::Url,
+}
+
+impl std::str::FromStr for AvailableRpcServerUrl {
+    type Err = String;
+    fn from_str(s: &str) -> Result<Self, Self::Err> {
+        let url: url::Url =
+            url::Url::parse(s).map_err(|err| format!("URL is not parsed: {}", err))?;
+        actix::System::new()
+            .block_on(async {
+                near_jsonrpc_client::new_client(&url.as_str())
+                    .status()
+                    .await
+            })
+            .map_err(|err| format!("AvailableRpcServerUrl: {:?}", err))?;
+        Ok(Self { inner: url })
+    }
+}
+
+impl std::fmt::Display for AvailableRpcServerUrl {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        write!(f, "Url {}", self.inner)
+    }
+}
+
+/// Function call arguments given as JSON; the JSON is validated while parsing.
+#[derive(Debug, Clone, PartialEq)]
+pub struct FunctionArgsJson {
+    pub inner: Vec<u8>,
+}
+
+impl std::str::FromStr for FunctionArgsJson {
+    type Err = String;
+    fn from_str(s: &str) -> Result<Self, Self::Err> {
+        serde_json::from_str::<serde_json::Value>(s)
+            .map_err(|err| format!("args are not a valid JSON: {}", err))?;
+        Ok(Self {
+            inner: s.as_bytes().to_vec(),
+        })
+    }
+}
+
+impl std::fmt::Display for FunctionArgsJson {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        write!(f, "{}", String::from_utf8_lossy(&self.inner))
+    }
+}
+
+#[derive(Debug, Clone, PartialEq)]
+pub struct FunctionArgsBase64 {
+    pub inner: Vec<u8>,
+}
+
+impl std::str::FromStr for FunctionArgsBase64 {
+    type Err = String;
+    fn from_str(s: &str) -> Result<Self, Self::Err> {
+        Ok(Self {
+            inner: near_primitives::serialize::from_base64(s)
+                .map_err(|err| format!("base64 args sequence is invalid: {}", err))?,
+        })
+    }
+}
+
+/// A type description which drives the borsh encoding of JSON values, e.g.
+/// `{receiver_id: string, amount: u128, memo: option<string>}`.
+/// Supported types: `bool`, `u8`..`u128`, `i8`..`i128`, `string`, `vec<T>`, `option<T>`,
+/// `[T; N]`, tuples `(T, U)` and structs `{field: T}`.
+#[derive(Debug, Clone, PartialEq)]
+pub enum BorshType {
+    Bool,
+    Integer { signed: bool, size: usize },
+    String,
+    Vec(Box<BorshType>),
+    Option(Box<BorshType>),
+    Array(Box<BorshType>, usize),
+    Tuple(Vec<BorshType>),
+    Struct(Vec<(String, BorshType)>),
+}
+
+struct BorshTypeParser<'a> {
+    input: &'a str,
+}
+
+impl<'a> BorshTypeParser<'a> {
+    fn skip_whitespace(&mut self) {
+        self.input = self.input.trim_start();
+    }
+
+    fn eat(&mut self, token: char) -> bool {
+        self.skip_whitespace();
+        if self.input.starts_with(token) {
+            self.input = &self.input[token.len_utf8()..];
+            true
+        } else {
+            false
+        }
+    }
+
+    fn expect(&mut self, token: char) -> Result<(), String> {
+        if self.eat(token) {
+            Ok(())
+        } else {
+            Err(format!(
+                "Borsh type: expected '{}' at '{}'",
+                token, self.input
+            ))
+        }
+    }
+
+    fn identifier(&mut self) -> Result<&'a str, String> {
+        self.skip_whitespace();
+        let len = self
+            .input
+            .find(|c: char| !(c.is_ascii_alphanumeric() || c == '_'))
+            .unwrap_or_else(|| self.input.len());
+        if len == 0 {
+            return Err(format!("Borsh type: expected a name at '{}'", self.input));
+        }
+        let (identifier, rest) = self.input.split_at(len);
+        self.input = rest;
+        Ok(identifier)
+    }
+
+    fn parse_type(&mut self) -> Result<BorshType, String> {
+        if self.eat('(') {
+            let mut items = vec![];
+            if !self.eat(')') {
+                loop {
+                    items.push(self.parse_type()?);
+                    if self.eat(')') {
+                        break;
+                    }
+                    self.expect(',')?;
+                }
+            }
+            return Ok(BorshType::Tuple(items));
+        }
+        if self.eat('{') {
+            let mut fields = vec![];
+            if !self.eat('}') {
+                loop {
+                    let name = self.identifier()?.to_string();
+                    self.expect(':')?;
+                    fields.push((name, self.parse_type()?));
+                    if self.eat('}') {
+                        break;
+                    }
+                    self.expect(',')?;
+                }
+            }
+            return Ok(BorshType::Struct(fields));
+        }
+        if self.eat('[') {
+            let item = self.parse_type()?;
+            self.expect(';')?;
+            let len = self
+                .identifier()?
+                .parse::<usize>()
+                .map_err(|err| format!("Borsh type: array length is invalid: {}", err))?;
+            self.expect(']')?;
+            return Ok(BorshType::Array(Box::new(item), len));
+        }
+        let name = self.identifier()?.to_lowercase();
+        let borsh_type = match name.as_str() {
+            "bool" => BorshType::Bool,
+            "string" => BorshType::String,
+            "vec" | "option" => {
+                self.expect('<')?;
+                let item = Box::new(self.parse_type()?);
+                self.expect('>')?;
+                if name == "vec" {
+                    BorshType::Vec(item)
+                } else {
+                    BorshType::Option(item)
+                }
+            }
+            _ => {
+                let signed = match name.chars().next() {
+                    Some('u') => false,
+                    Some('i') => true,
+                    _ => return Err(format!("Borsh type: unknown type '{}'", name)),
+                };
+                let size = match &name[1..] {
+                    "8" => 1,
+                    "16" => 2,
+                    "32" => 4,
+                    "64" => 8,
+                    "128" => 16,
+                    _ => return Err(format!("Borsh type: unknown type '{}'", name)),
+                };
+                BorshType::Integer { signed, size }
+            }
+        };
+        Ok(borsh_type)
+    }
+}
+
+impl std::str::FromStr for BorshType {
+    type Err = String;
+    fn from_str(s: &str) -> Result<Self, Self::Err> {
+        let mut parser = BorshTypeParser { input: s };
+        let borsh_type = parser.parse_type()?;
+        parser.skip_whitespace();
+        if !parser.input.is_empty() {
+            return Err(format!("Borsh type: unexpected '{}'", parser.input));
+        }
+        Ok(borsh_type)
+    }
+}
+
+impl BorshType {
+    /// Encodes a JSON value with borsh; big integers may be given as strings.
+    pub fn encode(&self, value: &serde_json::Value) -> Result<Vec<u8>, String> {
+        let mut buf = vec![];
+        self.encode_into(value, &mut buf)?;
+        Ok(buf)
+    }
+
+    /// Decodes borsh bytes into a JSON value; 64- and 128-bit integers become strings.
+    pub fn decode(&self, bytes: &[u8]) -> Result<serde_json::Value, String> {
+        let mut reader = ByteReader { bytes };
+        let value = self.decode_from(&mut reader)?;
+        if !reader.bytes.is_empty() {
+            return Err(format!(
+                "Borsh: {} bytes are left after decoding",
+                reader.bytes.len()
+            ));
+        }
+        Ok(value)
+    }
+
+    fn decode_from(&self, reader: &mut ByteReader) -> Result<serde_json::Value, String> {
+        Ok(match self {
+            Self::Bool => match reader.read_u8()? {
+                0 => serde_json::Value::Bool(false),
+                1 => serde_json::Value::Bool(true),
+                byte => return Err(format!("Borsh: {} is not a boolean", byte)),
+            },
+            Self::Integer { signed, size } => {
+                let bytes = reader.take(*size)?;
+                let mut buf = [0u8; 16];
+                buf[..*size].copy_from_slice(bytes);
+                if *signed && bytes[*size - 1] & 0x80 != 0 {
+                    for byte in buf[*size..].iter_mut() {
+                        *byte = 0xff;
+                    }
+                }
+                let number = if *signed {
+                    i128::from_le_bytes(buf).to_string()
+                } else {
+                    u128::from_le_bytes(buf).to_string()
+                };
+                if *size >= 8 {
+                    serde_json::Value::String(number)
+                } else {
+                    serde_json::from_str(&number).unwrap()
+                }
+            }
+            Self::String => {
+                let len = reader.read_u32()? as usize;
+                serde_json::Value::String(
+                    String::from_utf8(reader.take(len)?.to_vec())
+                        .map_err(|err| format!("Borsh: string is not a valid UTF-8: {}", err))?,
+                )
+            }
+            Self::Vec(item) => {
+                let len = reader.read_u32()?;
+                let mut items = vec![];
+                for _ in 0..len {
+                    items.push(item.decode_from(reader)?);
+                }
+                serde_json::Value::Array(items)
+            }
+            Self::Option(item) => match reader.read_u8()? {
+                0 => serde_json::Value::Null,
+                1 => item.decode_from(reader)?,
+                byte => return Err(format!("Borsh: {} is not an option tag", byte)),
+            },
+            Self::Array(item, len) => serde_json::Value::Array(
+                (0..*len)
+                    .map(|_| item.decode_from(reader))
+                    .collect::<Result<_, _>>()?,
+            ),
+            Self::Tuple(items) => serde_json::Value::Array(
+                items
+                    .iter()
+                    .map(|item| item.decode_from(reader))
+                    .collect::<Result<_, _>>()?,
+            ),
+            Self::Struct(fields) => {
+                let mut object = serde_json::Map::new();
+                for (name, field) in fields {
+                    object.insert(name.clone(), field.decode_from(reader)?);
+                }
+                serde_json::Value::Object(object)
+            }
+        })
+    }
+
+    fn encode_into(&self, value: &serde_json::Value, buf: &mut Vec<u8>) -> Result<(), String> {
+        match self {
+            Self::Bool => buf.push(
+                value
+                    .as_bool()
+                    .ok_or_else(|| format!("Borsh: expected a boolean, got {}", value))?
+                    as u8,
+            ),
+            Self::Integer { signed, size } => {
+                let number = match value {
+                    serde_json::Value::Number(number) => number.to_string(),
+                    serde_json::Value::String(number) => number.clone(),
+                    _ => return Err(format!("Borsh: expected an integer, got {}", value)),
+                };
+                let bits = (*size as u32) * 8;
+                let bytes = if *signed {
+                    let number = number
+                        .parse::<i128>()
+                        .map_err(|err| format!("Borsh: {} is not an integer: {}", number, err))?;
+                    if bits < 128
+                        && (number < -(1i128 << (bits - 1)) || number >= 1i128 << (bits - 1))
+                    {
+                        return Err(format!("Borsh: {} does not fit into i{}", number, bits));
+                    }
+                    number.to_le_bytes()
+                } else {
+                    let number = number
+                        .parse::<u128>()
+                        .map_err(|err| format!("Borsh: {} is not an integer: {}", number, err))?;
+                    if bits < 128 && number >= 1u128 << bits {
+                        return Err(format!("Borsh: {} does not fit into u{}", number, bits));
+                    }
+                    number.to_le_bytes()
+                };
+                buf.extend(&bytes[..*size]);
+            }
+            Self::String => {
+                let string = value
+                    .as_str()
+                    .ok_or_else(|| format!("Borsh: expected a string, got {}", value))?;
+                buf.extend(&(string.len() as u32).to_le_bytes());
+                buf.extend(string.as_bytes());
+            }
+            Self::Vec(item) => {
+                let items = value
+                    .as_array()
+                    .ok_or_else(|| format!("Borsh: expected an array, got {}", value))?;
+                buf.extend(&(items.len() as u32).to_le_bytes());
+                for item_value in items {
+                    item.encode_into(item_value, buf)?;
+                }
+            }
+            Self::Option(item) => {
+                if value.is_null() {
+                    buf.push(0);
+                } else {
+                    buf.push(1);
+                    item.encode_into(value, buf)?;
+                }
+            }
+            Self::Array(item, len) => {
+                let items = value
+                    .as_array()
+                    .filter(|items| items.len() == *len)
+                    .ok_or_else(|| {
+                        format!("Borsh: expected an array of {} items, got {}", len, value)
+                    })?;
+                for item_value in items {
+                    item.encode_into(item_value, buf)?;
+                }
+            }
+            Self::Tuple(items) => {
+                let values = value
+                    .as_array()
+                    .filter(|values| values.len() == items.len())
+                    .ok_or_else(|| {
+                        format!(
+                            "Borsh: expected an array of {} items, got {}",
+                            items.len(),
+                            value
+                        )
+                    })?;
+                for (item, item_value) in items.iter().zip(values) {
+                    item.encode_into(item_value, buf)?;
+                }
+            }
+            Self::Struct(fields) => {
+                let object = value
+                    .as_object()
+                    .ok_or_else(|| format!("Borsh: expected an object, got {}", value))?;
+                for (name, field) in fields {
+                    match (object.get(name), field) {
+                        (Some(field_value), _) => field.encode_into(field_value, buf)?,
+                        (None, Self::Option(_)) => buf.push(0),
+                        (None, _) => return Err(format!("Borsh: field '{}' is missing", name)),
+                    }
+                }
+            }
+        }
+        Ok(())
+    }
+}
+
+/// How to render the bytes returned by a view method.
+#[derive(Debug, Clone, PartialEq, smart_default::SmartDefault)]
+pub enum ResultFormat {
+    #[default]
+    Json,
+    Utf8,
+    Hex,
+    Base64,
+    Borsh(BorshType),
+    /// The JSON result is checked against the return type declared in the contract ABI.
+    Abi {
+        type_schema: serde_json::Value,
+        root_schema: serde_json::Value,
+    },
+}
+
+impl std::str::FromStr for ResultFormat {
+    type Err = String;
+    fn from_str(s: &str) -> Result<Self, Self::Err> {
+        match s.trim() {
+            "json" => Ok(Self::Json),
+            "utf8" => Ok(Self::Utf8),
+            "hex" => Ok(Self::Hex),
+            "base64" => Ok(Self::Base64),
+            format => match format.strip_prefix("borsh:") {
+                Some(borsh_type) => Ok(Self::Borsh(borsh_type.parse()?)),
+                None => Err(format!(
+                    "Result format: expected json, utf8, hex, base64 or borsh:<type>, got '{}'",
+                    format
+                )),
+            },
+        }
+    }
+}
+
+/// Renders the result in the requested format, falling back to UTF-8 text or base64
+/// when the bytes cannot be rendered that way.
+pub fn format_call_function_result(result: &[u8], result_format: &ResultFormat) -> String {
+    let formatted = match result_format {
+        ResultFormat::Json => serde_json::from_slice::<serde_json::Value>(result)
+            .map(|value| serde_json::to_string_pretty(&value).unwrap())
+            .map_err(|err| format!("the result is not a valid JSON: {}", err)),
+        ResultFormat::Utf8 => String::from_utf8(result.to_vec())
+            .map_err(|err| format!("the result is not a valid UTF-8: {}", err)),
+        ResultFormat::Hex => Ok(hex::encode(result)),
+        ResultFormat::Base64 => Ok(near_primitives::serialize::to_base64(result)),
+        ResultFormat::Borsh(borsh_type) => borsh_type
+            .decode(result)
+            .map(|value| serde_json::to_string_pretty(&value).unwrap()),
+        ResultFormat::Abi {
+            type_schema,
+            root_schema,
+        } => serde_json::from_slice::<serde_json::Value>(result)
+            .map_err(|err| format!("the result is not a valid JSON: {}", err))
+            .map(|value| {
+                let formatted = serde_json::to_string_pretty(&value).unwrap();
+                match validate_json_schema(&value, type_schema, root_schema, "result") {
+                    Ok(()) => format!(
+                        "({})\n{}",
+                        json_schema_type_name(type_schema, root_schema),
+                        formatted
+                    ),
+                    Err(err) => format!(
+                        "(the result does not match the declared return type: {})\n{}",
+                        err, formatted
+                    ),
+                }
+            }),
+    };
+    match formatted {
+        Ok(formatted) => formatted,
+        Err(err) => match String::from_utf8(result.to_vec()) {
+            Ok(text) => format!("({}, shown as UTF-8 text)\n{}", err, text),
+            Err(_) => format!(
+                "({}, shown as base64)\n{}",
+                err,
+                near_primitives::serialize::to_base64(result)
+            ),
+        },
+    }
+}
+
+pub fn print_call_function_result(
+    call_result: &near_primitives::views::CallResult,
+    result_format: &ResultFormat,
+) {
+    println!("--------------");
+    if !call_result.logs.is_empty() {
+        println!("Logs:");
+        print_outcome_logs(&call_result.logs, "  ");
+        println!("--------------");
+    }
+    println!();
+    if call_result.result.is_empty() {
+        println!("The method returned no value");
+    } else {
+        println!(
+            "{}",
+            format_call_function_result(&call_result.result, result_format)
+        );
+    }
+}
+
+/// Alternative ways to pass function call arguments on the command line.
+#[derive(Debug, Default, clap::Clap)]
+pub struct CliFunctionArgs {
+    /// Function arguments as a JSON string (the JSON is validated)
+    #[clap(long)]
+    args_json: Option<FunctionArgsJson>,
+    /// Read function arguments from a file as is
+    #[clap(long)]
+    args_file: Option<std::path::PathBuf>,
+    /// Function arguments as base64-encoded bytes
+    #[clap(long)]
+    args_base64: Option<FunctionArgsBase64>,
+    /// Read function arguments from stdin as is
+    #[clap(long)]
+    args_stdin: bool,
+    /// Function arguments as a JSON value to be encoded with borsh (requires --args-borsh-type)
+    #[clap(long)]
+    args_borsh: Option<String>,
+    /// The borsh type description of --args-borsh, e.g. "{receiver_id: string, amount: u128}"
+    #[clap(long)]
+    args_borsh_type: Option<BorshType>,
+}
+
+/// Function call arguments given with `CliFunctionArgs`. An invalid source is kept as an error
+/// and stdin is read only by `into_bytes`, so both happen after all the interactive prompts
+/// instead of falling back to another prompt.
+#[derive(Debug, Clone)]
+pub enum FunctionArgs {
+    Bytes(Vec<u8>),
+    Stdin,
+    Invalid(String),
+}
+
+impl FunctionArgs {
+    /// The arguments if they are already known before the call is processed.
+    pub fn bytes(&self) -> Option<&[u8]> {
+        match self {
+            FunctionArgs::Bytes(args) => Some(args),
+            FunctionArgs::Stdin | FunctionArgs::Invalid(_) => None,
+        }
+    }
+
+    pub fn into_bytes(self) -> color_eyre::eyre::Result<Vec<u8>> {
+        match self {
+            FunctionArgs::Bytes(args) => Ok(args),
+            FunctionArgs::Stdin => {
+                let mut args = vec![];
+                std::io::Read::read_to_end(&mut std::io::stdin(), &mut args).map_err(|err| {
+                    color_eyre::Report::msg(format!("Failed to read args from stdin: {}", err))
+                })?;
+                Ok(args)
+            }
+            FunctionArgs::Invalid(err) => Err(color_eyre::Report::msg(err)),
+        }
+    }
+}
+
+impl CliFunctionArgs {
+    /// Returns `None` when none of the argument sources is given.
+    pub fn into_args(self) -> Option<FunctionArgs> {
+        let sources_count = vec![
+            self.args_json.is_some(),
+            self.args_file.is_some(),
+            self.args_base64.is_some(),
+            self.args_stdin,
+            self.args_borsh.is_some(),
+        ]
+        .into_iter()
+        .filter(|is_given| *is_given)
+        .count();
+        if sources_count > 1 {
+            return Some(FunctionArgs::Invalid(
+                "Only one of --args-json, --args-file, --args-base64, --args-stdin and --args-borsh can be used"
+                    .to_string(),
+            ));
+        }
+        if let Some(args_json) = self.args_json {
+            return Some(FunctionArgs::Bytes(args_json.inner));
+        }
+        if let Some(args_file) = self.args_file {
+            return Some(match std::fs::read(&args_file) {
+                Ok(args) => FunctionArgs::Bytes(args),
+                Err(err) => FunctionArgs::Invalid(format!(
+                    "Failed to read args from {:?}: {}",
+                    args_file, err
+                )),
+            });
+        }
+        if let Some(args_base64) = self.args_base64 {
+            return Some(FunctionArgs::Bytes(args_base64.inner));
+        }
+        if self.args_stdin {
+            return Some(FunctionArgs::Stdin);
+        }
+        match (self.args_borsh, self.args_borsh_type) {
+            (Some(args_borsh), Some(args_borsh_type)) => Some(
+                match serde_json::from_str::<serde_json::Value>(&args_borsh)
+                    .map_err(|err| format!("--args-borsh is not a valid JSON: {}", err))
+                    .and_then(|value| args_borsh_type.encode(&value))
+                {
+                    Ok(args) => FunctionArgs::Bytes(args),
+                    Err(err) => FunctionArgs::Invalid(err),
+                },
+            ),
+            (Some(_), None) => Some(FunctionArgs::Invalid(
+                "--args-borsh requires --args-borsh-type".to_string(),
+            )),
+            (None, Some(_)) => Some(FunctionArgs::Invalid(
+                "--args-borsh-type requires --args-borsh".to_string(),
+            )),
+            (None, None) => None,
+        }
+    }
+}
+
+const WASM_MAGIC: &[u8] = b"\0asm";
+const WASM_SECTION_CUSTOM: u8 = 0;
+const WASM_SECTION_IMPORT: u8 = 2;
+const WASM_SECTION_EXPORT: u8 = 7;
+
+#[derive(Debug, Clone, Copy, PartialEq)]
+pub enum WasmExternalKind {
+    Function,
+    Table,
+    Memory,
+    Global,
+}
+
+impl std::fmt::Display for WasmExternalKind {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        match self {
+            Self::Function => write!(f, "function"),
+            Self::Table => write!(f, "table"),
+            Self::Memory => write!(f, "memory"),
+            Self::Global => write!(f, "global"),
+        }
+    }
+}
+
+#[derive(Debug, Clone)]
+pub struct WasmImport {
+    pub module: String,
+    pub name: String,
+    pub kind: WasmExternalKind,
+}
+
+#[derive(Debug, Clone)]
+pub struct WasmExport {
+    pub name: String,
+    pub kind: WasmExternalKind,
+}
+
+/// The parts of a wasm module near-cli looks at; code and data sections are skipped.
+#[derive(Debug, Default)]
+pub struct WasmModuleInfo {
+    pub version: u32,
+    pub imports: Vec<WasmImport>,
+    pub exports: Vec<WasmExport>,
+    pub custom_sections: Vec<(String, Vec<u8>)>,
+}
+
+impl WasmModuleInfo {
+    pub fn parse(code: &[u8]) -> Result<Self, String> {
+        Self::decode(code).map_err(|err| format!("Wasm: {}", err))
+    }
+
+    fn decode(code: &[u8]) -> Result<Self, String> {
+        let mut reader = ByteReader { bytes: code };
+        if reader.take(4).ok() != Some(WASM_MAGIC) {
+            return Err("the code does not start with the wasm magic number".to_string());
+        }
+        let mut module = Self {
+            version: reader.read_u32()?,
+            ..Default::default()
+        };
+        while !reader.bytes.is_empty() {
+            let section_id = reader.read_u8()?;
+            let section_len = reader.read_leb128_u32()? as usize;
+            let mut section = ByteReader {
+                bytes: reader.take(section_len)?,
+            };
+            match section_id {
+                WASM_SECTION_CUSTOM => {
+                    let name = section.read_wasm_name()?;
+                    module.custom_sections.push((name, section.bytes.to_vec()));
+                }
+                WASM_SECTION_IMPORT => {
+                    for _ in 0..section.read_leb128_u32()? {
+                        let import_module = section.read_wasm_name()?;
+                        let name = section.read_wasm_name()?;
+                        let kind = section.read_wasm_external_kind()?;
+                        match kind {
+                            WasmExternalKind::Function => {
+                                section.read_leb128_u32()?;
+                            }
+                            WasmExternalKind::Table => {
+                                section.read_u8()?;
+                                section.read_wasm_limits()?;
+                            }
+                            WasmExternalKind::Memory => section.read_wasm_limits()?,
+                            WasmExternalKind::Global => {
+                                section.take(2)?;
+                            }
+                        }
+                        module.imports.push(WasmImport {
+                            module: import_module,
+                            name,
+                            kind,
+                        });
+                    }
+                }
+                WASM_SECTION_EXPORT => {
+                    for _ in 0..section.read_leb128_u32()? {
+                        let name = section.read_wasm_name()?;
+                        let kind = section.read_wasm_external_kind()?;
+                        section.read_leb128_u32()?;
+                        module.exports.push(WasmExport { name, kind });
+                    }
+                }
+                _ => {}
+            }
+        }
+        Ok(module)
+    }
+
+    pub fn exported_functions(&self) -> Vec<&str> {
+        self.exports
+            .iter()
+            .filter(|export| export.kind == WasmExternalKind::Function)
+            .map(|export| export.name.as_str())
+            .collect()
+    }
+
+    pub fn custom_section(&self, name: &str) -> Option<&[u8]> {
+        self.custom_sections
+            .iter()
+            .find(|(section_name, _)| section_name == name)
+            .map(|(_, content)| content.as_slice())
+    }
+}
+
+const WASM_CUSTOM_SECTION_PREVIEW_LEN: usize = 200;
+
+/// Decodes the "producers" section: `field: name version, name version; field: ...`.
+fn decode_wasm_producers_section(content: &[u8]) -> Result<String, String> {
+    let mut reader = ByteReader { bytes: content };
+    let mut fields = vec![];
+    for _ in 0..reader.read_leb128_u32()? {
+        let field_name = reader.read_wasm_name()?;
+        let mut values = vec![];
+        for _ in 0..reader.read_leb128_u32()? {
+            let name = reader.read_wasm_name()?;
+            let version = reader.read_wasm_name()?;
+            values.push(format!("{} {}", name, version).trim_end().to_string());
+        }
+        fields.push(format!("{}: {}", field_name, values.join(", ")));
+    }
+    Ok(fields.join("; "))
+}
+
+/// A one-line description of a custom section: decoded producers, text or just the size.
+pub fn describe_wasm_custom_section(name: &str, content: &[u8]) -> String {
+    if name == "producers" {
+        if let Ok(producers) = decode_wasm_producers_section(content) {
+            return producers;
+        }
+    }
+    match std::str::from_utf8(content) {
+        Ok(text)
+            if name != "name" && !text.chars().any(|c| c.is_control() && !c.is_whitespace()) =>
+        {
+            if text.chars().count() > WASM_CUSTOM_SECTION_PREVIEW_LEN {
+                format!(
+                    "{}... ({} bytes)",
+                    text.chars()
+                        .take(WASM_CUSTOM_SECTION_PREVIEW_LEN)
+                        .collect::<String>(),
+                    content.len()
+                )
+            } else {
+                text.to_string()
+            }
+        }
+        _ => format!("{} bytes", content.len()),
+    }
+}
+
+/// The `max_contract_size` limit of the NEAR runtime, used when it cannot be queried.
+const DEFAULT_MAX_CONTRACT_SIZE: u64 = 4 * 1024 * 1024;
+const NEAR_HOST_MODULE: &str = "env";
+
+/// Functions the NEAR runtime provides to contracts in the `env` module.
+const NEAR_HOST_FUNCTIONS: &[&str] = &[
+    "read_register",
+    "register_len",
+    "write_register",
+    "current_account_id",
+    "signer_account_id",
+    "signer_account_pk",
+    "predecessor_account_id",
+    "input",
+    "block_index",
+    "block_timestamp",
+    "epoch_height",
+    "storage_usage",
+    "account_balance",
+    "account_locked_balance",
+    "attached_deposit",
+    "prepaid_gas",
+    "used_gas",
+    "random_seed",
+    "sha256",
+    "keccak256",
+    "keccak512",
+    "ripemd160",
+    "ecrecover",
+    "ed25519_verify",
+    "value_return",
+    "panic",
+    "panic_utf8",
+    "log_utf8",
+    "log_utf16",
+    "abort",
+    "promise_create",
+    "promise_then",
+    "promise_and",
+    "promise_batch_create",
+    "promise_batch_then",
+    "promise_batch_action_create_account",
+    "promise_batch_action_deploy_contract",
+    "promise_batch_action_function_call",
+    "promise_batch_action_function_call_weight",
+    "promise_batch_action_transfer",
+    "promise_batch_action_stake",
+    "promise_batch_action_add_key_with_full_access",
+    "promise_batch_action_add_key_with_function_call",
+    "promise_batch_action_delete_key",
+    "promise_batch_action_delete_account",
+    "promise_results_count",
+    "promise_result",
+    "promise_return",
+    "storage_write",
+    "storage_read",
+    "storage_remove",
+    "storage_has_key",
+    "storage_iter_prefix",
+    "storage_iter_range",
+    "storage_iter_next",
+    "gas",
+    "validator_stake",
+    "validator_total_stake",
+    "alt_bn128_g1_multiexp",
+    "alt_bn128_g1_sum",
+    "alt_bn128_pairing_check",
+];
+
+/// The result of checking contract code before it is deployed.
+#[derive(Debug)]
+pub struct ContractCodeCheck {
+    pub module: WasmModuleInfo,
+    /// Imports the NEAR runtime does not provide; calls into such a contract fail.
+    pub unknown_imports: Vec<String>,
+}
+
+/// Checks that the code is a wasm module within the size limit which exports the init method.
+pub fn check_contract_code(
+    code: &[u8],
+    max_contract_size: u64,
+    init_method_name: Option<&str>,
+) -> Result<ContractCodeCheck, String> {
+    if code.len() as u64 > max_contract_size {
+        return Err(format!(
+            "the contract code is {} bytes, which exceeds the limit of {} bytes",
+            code.len(),
+            max_contract_size
+        ));
+    }
+    let module = WasmModuleInfo::parse(code)?;
+    if module.version != 1 {
+        return Err(format!(
+            "Wasm: unsupported version {}, expected 1",
+            module.version
+        ));
+    }
+    if let Some(init_method_name) = init_method_name {
+        if !module.exported_functions().contains(&init_method_name) {
+            return Err(format!(
+                "the init method '{}' is not exported by the contract",
+                init_method_name
+            ));
+        }
+    }
+    let unknown_imports = module
+        .imports
+        .iter()
+        .filter(|import| match import.kind {
+            WasmExternalKind::Function => {
+                import.module != NEAR_HOST_MODULE
+                    || !NEAR_HOST_FUNCTIONS.contains(&import.name.as_str())
+            }
+            _ => import.module != NEAR_HOST_MODULE,
+        })
+        .map(|import| format!("{}.{} ({})", import.module, import.name, import.kind))
+        .collect();
+    Ok(ContractCodeCheck {
+        module,
+        unknown_imports,
+    })
+}
+
+/// Queries the contract size limit of the network, falling back to the default one.
+pub async fn fetch_max_contract_size(network_connection_config: Option<&ConnectionConfig>) -> u64 {
+    let network_connection_config = match network_connection_config {
+        Some(network_connection_config) => network_connection_config,
+        None => return DEFAULT_MAX_CONTRACT_SIZE,
+    };
+    call_json_rpc_method::<serde_json::Value>(
+        network_connection_config.rpc_url().as_str(),
+        "EXPERIMENTAL_protocol_config",
+        serde_json::json!({ "finality": "final" }),
+    )
+    .await
+    .ok()
+    .and_then(|protocol_config| {
+        protocol_config
+            .pointer("/runtime_config/wasm_config/limit_config/max_contract_size")
+            .and_then(|max_contract_size| max_contract_size.as_u64())
+    })
+    .unwrap_or(DEFAULT_MAX_CONTRACT_SIZE)
+}
+
+/// How the exported functions changed between two versions of a contract.
+#[derive(Debug, Default, PartialEq)]
+pub struct ExportedFunctionsDiff {
+    pub added: Vec<String>,
+    pub removed: Vec<String>,
+    pub unchanged: Vec<String>,
+}
+
+impl ExportedFunctionsDiff {
+    pub fn new(old_module: &WasmModuleInfo, new_module: &WasmModuleInfo) -> Self {
+        let old_functions: std::collections::BTreeSet<&str> =
+            old_module.exported_functions().into_iter().collect();
+        let new_functions: std::collections::BTreeSet<&str> =
+            new_module.exported_functions().into_iter().collect();
+        Self {
+            added: new_functions
+                .difference(&old_functions)
+                .map(|name| name.to_string())
+                .collect(),
+            removed: old_functions
+                .difference(&new_functions)
+                .map(|name| name.to_string())
+                .collect(),
+            unchanged: old_functions
+                .intersection(&new_functions)
+                .map(|name| name.to_string())
+                .collect(),
+        }
+    }
+
+    pub fn print(&self) {
+        println!("\nRemoved methods ({}):", self.removed.len());
+        for name in self.removed.iter() {
+            println!("  - {}", name);
+        }
+        println!("Added methods ({}):", self.added.len());
+        for name in self.added.iter() {
+            println!("  + {}", name);
+        }
+        println!("Unchanged methods ({}):", self.unchanged.len());
+        for name in self.unchanged.iter() {
+            println!("    {}", name);
+        }
+    }
+}
+
+/// The contract code deployed to an account.
+#[derive(Debug)]
+pub struct DeployedContractCode {
+    pub code_hash: near_primitives::hash::CryptoHash,
+    pub code: Vec<u8>,
+    pub block_height: near_primitives::types::BlockHeight,
+}
+
+/// Whether the code is what an account with this code_hash has deployed (never true for an
+/// account without code).
+pub fn contract_code_matches_code_hash(
+    code: &[u8],
+    code_hash: &near_primitives::hash::CryptoHash,
+) -> bool {
+    *code_hash != near_primitives::hash::CryptoHash::default()
+        && near_primitives::hash::hash(code) == *code_hash
+}
+
+async fn query_account_view(
+    rpc_client: &near_jsonrpc_client::JsonRpcClient,
+    block_reference: near_primitives::types::BlockReference,
+    account_id: &str,
+) -> color_eyre::eyre::Result<(
+    near_primitives::views::AccountView,
+    near_primitives::hash::CryptoHash,
+    near_primitives::types::BlockHeight,
+)> {
+    let query_view_account_response = rpc_client
+        .query(near_jsonrpc_primitives::types::query::RpcQueryRequest {
+            block_reference,
+            request: near_primitives::views::QueryRequest::ViewAccount {
+                account_id: account_id.to_string(),
+            },
+        })
+        .await
+        .map_err(|err| {
+            color_eyre::Report::msg(format!("Failed to fetch query for view account: {:?}", err))
+        })?;
+    match query_view_account_response.kind {
+        near_jsonrpc_primitives::types::query::QueryResponseKind::ViewAccount(account_view) => {
+            Ok((
+                account_view,
+                query_view_account_response.block_hash,
+                query_view_account_response.block_height,
+            ))
+        }
+        _ => Err(color_eyre::Report::msg(format!("Error call result"))),
+    }
+}
+
+/// Fetches the account at the final block.
+pub async fn fetch_account_view(
+    network_connection_config: &ConnectionConfig,
+    account_id: &str,
+) -> color_eyre::eyre::Result<near_primitives::views::AccountView> {
+    let rpc_client = near_jsonrpc_client::new_client(network_connection_config.rpc_url().as_str());
+    let (account_view, _, _) = query_account_view(
+        &rpc_client,
+        near_primitives::types::Finality::Final.into(),
+        account_id,
+    )
+    .await?;
+    Ok(account_view)
+}
+
+/// Fetches the code hash of the account at the final block.
+pub async fn fetch_account_code_hash(
+    network_connection_config: &ConnectionConfig,
+    account_id: &str,
+) -> color_eyre::eyre::Result<near_primitives::hash::CryptoHash> {
+    let rpc_client = near_jsonrpc_client::new_client(network_connection_config.rpc_url().as_str());
+    fetch_account_code_hash_at(
+        &rpc_client,
+        near_primitives::types::Finality::Final.into(),
+        account_id,
+    )
+    .await
+}
+
+/// Fetches the code hash of the account at the given block.
+pub async fn fetch_account_code_hash_at(
+    rpc_client: &near_jsonrpc_client::JsonRpcClient,
+    block_reference: near_primitives::types::BlockReference,
+    account_id: &str,
+) -> color_eyre::eyre::Result<near_primitives::hash::CryptoHash> {
+    let (account_view, _, _) = query_account_view(rpc_client, block_reference, account_id).await?;
+    Ok(account_view.code_hash)
+}
+
+/// Fetches the code deployed to the account at the final block, `None` when it has no code.
+pub async fn fetch_deployed_contract_code(
+    network_connection_config: &ConnectionConfig,
+    account_id: &str,
+) -> color_eyre::eyre::Result<Option<DeployedContractCode>> {
+    let rpc_client = near_jsonrpc_client::new_client(network_connection_config.rpc_url().as_str());
+    let (account_view, block_hash, block_height) = query_account_view(
+        &rpc_client,
+        near_primitives::types::Finality::Final.into(),
+        account_id,
+    )
+    .await?;
+    if account_view.code_hash == near_primitives::hash::CryptoHash::default() {
+        return Ok(None);
+    }
+    let query_view_code_response = rpc_client
+        .query(near_jsonrpc_primitives::types::query::RpcQueryRequest {
+            block_reference: near_primitives::types::BlockReference::BlockId(
+                near_primitives::types::BlockId::Hash(block_hash),
+            ),
+            request: near_primitives::views::QueryRequest::ViewCode {
+                account_id: account_id.to_string(),
+            },
+        })
+        .await
+        .map_err(|err| {
+            color_eyre::Report::msg(format!(
+                "Failed to fetch query for view contract: {:?}",
+                err
+            ))
+        })?;
+    let contract_code =
+        if let near_jsonrpc_primitives::types::query::QueryResponseKind::ViewCode(result) =
+            query_view_code_response.kind
+        {
+            result
+        } else {
+            return Err(color_eyre::Report::msg(format!("Error call result")));
+        };
+    Ok(Some(DeployedContractCode {
+        code_hash: contract_code.hash,
+        code: contract_code.code,
+        block_height,
+    }))
+}
+
+/// The custom wasm section a contract can embed its ABI into (as JSON, which cargo-near
+/// compresses with zstd).
+const CONTRACT_ABI_WASM_SECTION: &str = "near-abi";
+const ZSTD_MAGIC: &[u8] = &[0x28, 0xb5, 0x2f, 0xfd];
+
+static JSON_SCHEMA_ANY: serde_json::Value = serde_json::Value::Bool(true);
+/// Bounds the recursion of `anyOf`, `allOf` and nested values through recursive definitions.
+const JSON_SCHEMA_MAX_DEPTH: usize = 64;
+
+/// A contract ABI in the format produced by `cargo near abi`.
+#[derive(Debug, Clone, serde::Deserialize)]
+pub struct ContractAbi {
+    #[serde(default)]
+    pub schema_version: String,
+    pub body: ContractAbiBody,
+}
+
+#[derive(Debug, Clone, serde::Deserialize)]
+pub struct ContractAbiBody {
+    pub functions: Vec<AbiFunction>,
+    #[serde(default)]
+    pub root_schema: serde_json::Value,
+}
+
+#[derive(Debug, Clone, PartialEq, serde::Deserialize)]
+#[serde(rename_all = "snake_case")]
+pub enum AbiFunctionKind {
+    View,
+    Call,
+}
+
+#[derive(Debug, Clone, serde::Deserialize)]
+pub struct AbiFunction {
+    pub name: String,
+    #[serde(default)]
+    pub doc: Option<String>,
+    pub kind: AbiFunctionKind,
+    #[serde(default)]
+    pub modifiers: Vec<String>,
+    #[serde(default)]
+    pub params: AbiParameters,
+    #[serde(default)]
+    pub result: Option<AbiType>,
+}
+
+#[derive(Debug, Clone, Default, serde::Deserialize)]
+pub struct AbiParameters {
+    #[serde(default)]
+    pub serialization_type: String,
+    #[serde(default)]
+    pub args: Vec<AbiParameter>,
+}
+
+#[derive(Debug, Clone, serde::Deserialize)]
+pub struct AbiParameter {
+    pub name: String,
+    #[serde(default)]
+    pub type_schema: serde_json::Value,
+}
+
+#[derive(Debug, Clone, PartialEq, serde::Deserialize)]
+pub struct AbiType {
+    pub serialization_type: String,
+    #[serde(default)]
+    pub type_schema: serde_json::Value,
+}
+
+impl ContractAbi {
+    pub fn from_json(bytes: &[u8]) -> Result<Self, String> {
+        serde_json::from_slice(bytes).map_err(|err| format!("ABI is not valid: {}", err))
+    }
+
+    pub fn from_file(file_path: &std::path::Path) -> Result<Self, String> {
+        let bytes = std::fs::read(file_path)
+            .map_err(|err| format!("failed to read ABI from {:?}: {}", file_path, err))?;
+        Self::from_json(&bytes)
+    }
+
+    pub fn from_wasm(code: &[u8]) -> Result<Self, String> {
+        let module = WasmModuleInfo::parse(code)?;
+        let section = module
+            .custom_section(CONTRACT_ABI_WASM_SECTION)
+            .ok_or_else(|| {
+                format!(
+                    "the contract code has no \"{}\" section with an ABI",
+                    CONTRACT_ABI_WASM_SECTION
+                )
+            })?;
+        if section.starts_with(ZSTD_MAGIC) {
+            let section = zstd::decode_all(section)
+                .map_err(|err| format!("failed to decompress the embedded ABI: {}", err))?;
+            return Self::from_json(&section);
+        }
+        Self::from_json(section)
+    }
+
+    /// Fetches the contract code with `ViewCode` and reads the ABI embedded into it.
+    pub fn from_contract(
+        network_connection_config: &ConnectionConfig,
+        contract_id: &str,
+    ) -> Result<Self, String> {
+        let query_view_code_response = actix::System::new()
+            .block_on(async {
+                near_jsonrpc_client::new_client(network_connection_config.rpc_url().as_str())
+                    .query(near_jsonrpc_primitives::types::query::RpcQueryRequest {
+                        block_reference: near_primitives::types::Finality::Final.into(),
+                        request: near_primitives::views::QueryRequest::ViewCode {
+                            account_id: contract_id.to_string(),
+                        },
+                    })
+                    .await
+            })
+            .map_err(|err| format!("failed to fetch the contract code: {:?}", err))?;
+        match query_view_code_response.kind {
+            near_jsonrpc_primitives::types::query::QueryResponseKind::ViewCode(contract_code) => {
+                Self::from_wasm(&contract_code.code)
+            }
+            _ => Err("failed to fetch the contract code: unexpected response".to_string()),
+        }
+    }
+
+    /// Loads the ABI from `--abi-file` or `--abi-from-contract`, or asks where to load it from
+    /// when the method name is not given yet. Failures are reported and the ABI is skipped.
+    pub fn load(
+        abi_file: Option<std::path::PathBuf>,
+        abi_from_contract: bool,
+        is_method_name_given: bool,
+        network_connection_config: Option<&ConnectionConfig>,
+        contract_id: &str,
+    ) -> Option<Self> {
+        let contract_abi = match (abi_file, network_connection_config) {
+            (Some(abi_file), _) => Self::from_file(&abi_file),
+            (None, Some(network_connection_config)) if abi_from_contract => {
+                Self::from_contract(network_connection_config, contract_id)
+            }
+            (None, None) if abi_from_contract => {
+                Err("--abi-from-contract is not available in offline mode".to_string())
+            }
+            (None, _) if is_method_name_given => return None,
+            (None, network_connection_config) => {
+                Self::choose_abi_source(network_connection_config, contract_id)?
+            }
+        };
+        match contract_abi {
+            Ok(contract_abi) => Some(contract_abi),
+            Err(err) => {
+                println!("\nError: {}\n", err);
+                None
+            }
+        }
+    }
+
+    fn choose_abi_source(
+        network_connection_config: Option<&ConnectionConfig>,
+        contract_id: &str,
+    ) -> Option<Result<Self, String>> {
+        println!();
+        let mut sources = vec!["No, I will enter the method name and args myself"];
+        if network_connection_config.is_some() {
+            sources.push("Yes, read the ABI embedded into the contract code");
+        }
+        sources.push("Yes, read the ABI from a JSON file");
+        let selection = dialoguer::Select::with_theme(&dialoguer::theme::ColorfulTheme::default())
+            .with_prompt(
+                "Do you want to use the contract ABI to choose a method and enter its args?",
+            )
+            .items(&sources)
+            .default(0)
+            .interact()
+            .unwrap();
+        match (selection, network_connection_config) {
+            (0, _) => None,
+            (1, Some(network_connection_config)) => {
+                Some(Self::from_contract(network_connection_config, contract_id))
+            }
+            _ => {
+                let abi_file: String = dialoguer::Input::new()
+                    .with_prompt("What is the path to the ABI file?")
+                    .interact_text()
+                    .unwrap();
+                Some(Self::from_file(std::path::Path::new(&abi_file)))
+            }
+        }
+    }
+
+    pub fn function(&self, method_name: &str) -> Option<&AbiFunction> {
+        self.body
+            .functions
+            .iter()
+            .find(|function| function.name == method_name)
+    }
+
+    /// Offers the functions of the given kind; returns `None` when the ABI has none.
+    pub fn choose_function(&self, kind: AbiFunctionKind) -> Option<&AbiFunction> {
+        let functions = self
+            .body
+            .functions
+            .iter()
+            .filter(|function| function.kind == kind)
+            .collect::<Vec<_>>();
+        if functions.is_empty() {
+            println!("\nThe ABI has no {:?} functions", kind);
+            return None;
+        }
+        let items = functions
+            .iter()
+            .map(|function| self.function_signature(function))
+            .collect::<Vec<_>>();
+        println!();
+        let selection = dialoguer::Select::with_theme(&dialoguer::theme::ColorfulTheme::default())
+            .with_prompt("Choose a method")
+            .items(&items)
+            .default(0)
+            .interact()
+            .unwrap();
+        Some(functions[selection])
+    }
+
+    fn function_signature(&self, function: &AbiFunction) -> String {
+        let args = function
+            .params
+            .args
+            .iter()
+            .map(|arg| {
+                format!(
+                    "{}: {}",
+                    arg.name,
+                    json_schema_type_name(&arg.type_schema, &self.body.root_schema)
+                )
+            })
+            .collect::<Vec<_>>()
+            .join(", ");
+        let signature = match &function.result {
+            Some(result) => format!(
+                "{}({}) -> {}",
+                function.name,
+                args,
+                json_schema_type_name(&result.type_schema, &self.body.root_schema)
+            ),
+            None => format!("{}({})", function.name, args),
+        };
+        match function.doc.as_ref().and_then(|doc| doc.lines().next()) {
+            Some(doc) => format!("{}  // {}", signature, doc.trim()),
+            None => signature,
+        }
+    }
+
+    /// Checks JSON args against the declared parameter types (borsh args are not checked).
+    pub fn validate_args(&self, function: &AbiFunction, args: &[u8]) -> Result<(), String> {
+        if function.params.serialization_type == "borsh" {
+            return Ok(());
+        }
+        let args: serde_json::Value = match serde_json::from_slice(args) {
+            Ok(args) => args,
+            Err(_) if function.params.args.is_empty() && args.is_empty() => return Ok(()),
+            Err(err) => return Err(format!("args are not a valid JSON: {}", err)),
+        };
+        let args = args
+            .as_object()
+            .ok_or_else(|| "args: expected a JSON object".to_string())?;
+        for parameter in function.params.args.iter() {
+            match args.get(&parameter.name) {
+                Some(value) => validate_json_schema(
+                    value,
+                    &parameter.type_schema,
+                    &self.body.root_schema,
+                    &parameter.name,
+                )?,
+                None => {
+                    if !json_schema_accepts_null(&parameter.type_schema, &self.body.root_schema) {
+                        return Err(format!("args: missing argument '{}'", parameter.name));
+                    }
+                }
+            }
+        }
+        if let Some(unknown) = args.keys().find(|name| {
+            !function
+                .params
+                .args
+                .iter()
+                .any(|parameter| &parameter.name == *name)
+        }) {
+            return Err(format!(
+                "args: '{}' is not an argument of {}",
+                unknown, function.name
+            ));
+        }
+        Ok(())
+    }
+
+    /// Prompts for every declared argument; returns `None` when the args cannot be entered
+    /// this way (borsh-serialized parameters).
+    pub fn input_args(&self, function: &AbiFunction) -> Option<Vec<u8>> {
+        if function.params.serialization_type == "borsh" {
+            println!(
+                "\nThe ABI declares borsh-serialized args for this method, enter them as bytes"
+            );
+            return None;
+        }
+        let mut args = serde_json::Map::new();
+        for parameter in function.params.args.iter() {
+            if let Some(value) = self.input_parameter(parameter) {
+                args.insert(parameter.name.clone(), value);
+            }
+        }
+        Some(serde_json::to_vec(&serde_json::Value::Object(args)).unwrap())
+    }
+
+    fn input_parameter(&self, parameter: &AbiParameter) -> Option<serde_json::Value> {
+        let root_schema = &self.body.root_schema;
+        let is_optional = json_schema_accepts_null(&parameter.type_schema, root_schema);
+        let prompt = format!(
+            "Enter {} ({}{})",
+            parameter.name,
+            json_schema_type_name(&parameter.type_schema, root_schema),
+            if is_optional {
+                ", leave empty to skip"
+            } else {
+                ""
+            }
+        );
+        println!();
+        loop {
+            let input: String = dialoguer::Input::new()
+                .with_prompt(&prompt)
+                .allow_empty(is_optional)
+                .interact_text()
+                .unwrap();
+            if input.is_empty() && is_optional {
+                return None;
+            }
+            // Values which do not fit the schema as JSON are taken as plain strings, so
+            // account ids and U128 amounts can be typed without quotes.
+            let value = match serde_json::from_str::<serde_json::Value>(&input) {
+                Ok(value)
+                    if validate_json_schema(&value, &parameter.type_schema, root_schema, "")
+                        .is_ok() =>
+                {
+                    value
+                }
+                _ => serde_json::Value::String(input),
+            };
+            match validate_json_schema(&value, &parameter.type_schema, root_schema, &parameter.name)
+            {
+                Ok(()) => return Some(value),
+                Err(err) => println!("{}", err),
+            }
+        }
+    }
+
+    /// Validates the given args, or prompts for them when they are missing.
+    pub fn function_args(
+        &self,
+        function: &AbiFunction,
+        args: Option<Vec<u8>>,
+    ) -> Result<Option<Vec<u8>>, String> {
+        match args {
+            Some(args) => {
+                self.validate_args(function, &args)?;
+                Ok(Some(args))
+            }
+            None => Ok(self.input_args(function)),
+        }
+    }
+
+    /// How to render the result of the function according to its declared return type.
+    pub fn result_format(&self, function: &AbiFunction) -> Option<ResultFormat> {
+        match &function.result {
+            Some(result) if result.serialization_type == "json" => Some(ResultFormat::Abi {
+                type_schema: result.type_schema.clone(),
+                root_schema: self.body.root_schema.clone(),
+            }),
+            Some(_) => Some(ResultFormat::Base64),
+            None => None,
+        }
+    }
+}
+
+/// Follows `$ref` until a schema without it; a missing or cyclic reference accepts any value.
+fn resolve_json_schema<'a>(
+    schema: &'a serde_json::Value,
+    root_schema: &'a serde_json::Value,
+) -> &'a serde_json::Value {
+    let mut schema = schema;
+    let mut visited_references = std::collections::HashSet::new();
+    while let Some(reference) = schema.get("$ref").and_then(|reference| reference.as_str()) {
+        if !visited_references.insert(reference) {
+            return &JSON_SCHEMA_ANY;
+        }
+        schema = match root_schema.pointer(reference.trim_start_matches('#')) {
+            Some(schema) => schema,
+            None => return &JSON_SCHEMA_ANY,
+        };
+    }
+    schema
+}
+
+fn json_schema_accepts_null(schema: &serde_json::Value, root_schema: &serde_json::Value) -> bool {
+    validate_json_schema(&serde_json::Value::Null, schema, root_schema, "").is_ok()
+}
+
+fn json_schema_type_name(schema: &serde_json::Value, root_schema: &serde_json::Value) -> String {
+    if let Some(reference) = schema.get("$ref").and_then(|reference| reference.as_str()) {
+        return reference
+            .rsplit('/')
+            .next()
+            .unwrap_or(reference)
+            .to_string();
+    }
+    if let Some(variants) = schema
+        .get("anyOf")
+        .or_else(|| schema.get("oneOf"))
+        .and_then(|variants| variants.as_array())
+    {
+        return variants
+            .iter()
+            .map(|variant| json_schema_type_name(variant, root_schema))
+            .collect::<Vec<_>>()
+            .join(" | ");
+    }
+    let type_name = match schema.get("type") {
+        Some(serde_json::Value::String(type_name)) => type_name.clone(),
+        Some(serde_json::Value::Array(type_names)) => type_names
+            .iter()
+            .filter_map(|type_name| type_name.as_str())
+            .collect::<Vec<_>>()
+            .join(" | "),
+        _ => return "any".to_string(),
+    };
+    match (schema.get("format"), schema.get("items")) {
+        (Some(serde_json::Value::String(format)), _) => format!("{} ({})", type_name, format),
+        (_, Some(items)) if items.is_object() => {
+            format!(
+                "{}<{}>",
+                type_name,
+                json_schema_type_name(items, root_schema)
+            )
+        }
+        _ => type_name,
     }
 }
 
-impl std::fmt::Display for TransactionAsBase64 {
-    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
-        write!(f, "Transaction {}", self.inner.get_hash_and_size().0)
+fn json_value_has_type(value: &serde_json::Value, type_name: &str) -> bool {
+    match type_name {
+        "null" => value.is_null(),
+        "boolean" => value.is_boolean(),
+        "integer" => value.is_i64() || value.is_u64(),
+        "number" => value.is_number(),
+        "string" => value.is_string(),
+        "array" => value.is_array(),
+        "object" => value.is_object(),
+        _ => true,
     }
 }
 
-#[derive(Debug, Clone)]
-pub struct BlockHashAsBase58 {
-    pub inner: near_primitives::hash::CryptoHash,
+/// Checks the value against the subset of JSON Schema used by contract ABIs.
+fn validate_json_schema(
+    value: &serde_json::Value,
+    schema: &serde_json::Value,
+    root_schema: &serde_json::Value,
+    path: &str,
+) -> Result<(), String> {
+    validate_json_schema_nested(value, schema, root_schema, path, 0)
 }
 
-impl std::str::FromStr for BlockHashAsBase58 {
-    type Err = String;
-    fn from_str(s: &str) -> Result<Self, Self::Err> {
-        Ok(Self {
-            inner: near_primitives::serialize::from_base(s)
-                .map_err(|err| format!("base block hash sequence is invalid: {}", err))?
-                .as_slice()
-                .try_into()
-                .map_err(|err| format!("block hash could not be collected: {}", err))?,
-        })
+fn validate_json_schema_nested(
+    value: &serde_json::Value,
+    schema: &serde_json::Value,
+    root_schema: &serde_json::Value,
+    path: &str,
+    depth: usize,
+) -> Result<(), String> {
+    if depth > JSON_SCHEMA_MAX_DEPTH {
+        return Err(format!(
+            "{}: the schema is nested deeper than {} levels",
+            path, JSON_SCHEMA_MAX_DEPTH
+        ));
+    }
+    let expected = || json_schema_type_name(schema, root_schema);
+    let schema = match resolve_json_schema(schema, root_schema) {
+        serde_json::Value::Object(schema) => schema,
+        serde_json::Value::Bool(false) => return Err(format!("{}: no value is allowed", path)),
+        _ => return Ok(()),
+    };
+    if let Some(variants) = schema
+        .get("anyOf")
+        .or_else(|| schema.get("oneOf"))
+        .and_then(|variants| variants.as_array())
+    {
+        if !variants.iter().any(|variant| {
+            validate_json_schema_nested(value, variant, root_schema, path, depth + 1).is_ok()
+        }) {
+            return Err(format!("{}: expected {}, got {}", path, expected(), value));
+        }
+    }
+    if let Some(variants) = schema.get("allOf").and_then(|variants| variants.as_array()) {
+        for variant in variants {
+            validate_json_schema_nested(value, variant, root_schema, path, depth + 1)?;
+        }
+    }
+    if let Some(allowed) = schema.get("enum").and_then(|allowed| allowed.as_array()) {
+        if !allowed.contains(value) {
+            let allowed = allowed
+                .iter()
+                .map(|allowed| allowed.to_string())
+                .collect::<Vec<_>>()
+                .join(", ");
+            return Err(format!(
+                "{}: expected one of {}, got {}",
+                path, allowed, value
+            ));
+        }
     }
+    let type_names = match schema.get("type") {
+        Some(serde_json::Value::String(type_name)) => vec![type_name.as_str()],
+        Some(serde_json::Value::Array(type_names)) => type_names
+            .iter()
+            .filter_map(|type_name| type_name.as_str())
+            .collect(),
+        _ => vec![],
+    };
+    if !type_names.is_empty()
+        && !type_names
+            .iter()
+            .any(|type_name| json_value_has_type(value, type_name))
+    {
+        return Err(format!("{}: expected {}, got {}", path, expected(), value));
+    }
+    match value {
+        serde_json::Value::Number(number) => {
+            let number = number.as_f64().unwrap_or_default();
+            if let Some(minimum) = schema.get("minimum").and_then(|minimum| minimum.as_f64()) {
+                if number < minimum {
+                    return Err(format!("{}: {} is less than {}", path, number, minimum));
+                }
+            }
+            if let Some(maximum) = schema.get("maximum").and_then(|maximum| maximum.as_f64()) {
+                if number > maximum {
+                    return Err(format!("{}: {} is greater than {}", path, number, maximum));
+                }
+            }
+        }
+        serde_json::Value::Object(fields) => {
+            if let Some(required) = schema
+                .get("required")
+                .and_then(|required| required.as_array())
+            {
+                if let Some(missing) = required
+                    .iter()
+                    .filter_map(|name| name.as_str())
+                    .find(|name| !fields.contains_key(*name))
+                {
+                    return Err(format!("{}: missing field '{}'", path, missing));
+                }
+            }
+            if let Some(properties) = schema
+                .get("properties")
+                .and_then(|properties| properties.as_object())
+            {
+                for (name, field) in fields {
+                    if let Some(field_schema) = properties.get(name) {
+                        validate_json_schema_nested(
+                            field,
+                            field_schema,
+                            root_schema,
+                            &format!("{}.{}", path, name),
+                            depth + 1,
+                        )?;
+                    }
+                }
+            }
+        }
+        serde_json::Value::Array(items) => {
+            match schema.get("items") {
+                Some(serde_json::Value::Array(item_schemas)) => {
+                    if items.len() != item_schemas.len() {
+                        return Err(format!(
+                            "{}: expected {} items, got {}",
+                            path,
+                            item_schemas.len(),
+                            items.len()
+                        ));
+                    }
+                    for (index, (item, item_schema)) in
+                        items.iter().zip(item_schemas.iter()).enumerate()
+                    {
+                        validate_json_schema_nested(
+                            item,
+                            item_schema,
+                            root_schema,
+                            &format!("{}[{}]", path, index),
+                            depth + 1,
+                        )?;
+                    }
+                }
+                Some(item_schema) => {
+                    for (index, item) in items.iter().enumerate() {
+                        validate_json_schema_nested(
+                            item,
+                            item_schema,
+                            root_schema,
+                            &format!("{}[{}]", path, index),
+                            depth + 1,
+                        )?;
+                    }
+                }
+                None => {}
+            }
+            let min_items = schema
+                .get("minItems")
+                .and_then(|min_items| min_items.as_u64());
+            let max_items = schema
+                .get("maxItems")
+                .and_then(|max_items| max_items.as_u64());
+            if min_items.map_or(false, |min_items| (items.len() as u64) < min_items)
+                || max_items.map_or(false, |max_items| (items.len() as u64) > max_items)
+            {
+                return Err(format!(
+                    "{}: unexpected number of items {}",
+                    path,
+                    items.len()
+                ));
+            }
+        }
+        _ => {}
+    }
+    Ok(())
 }
 
-impl std::fmt::Display for BlockHashAsBase58 {
-    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
-        write!(f, "BlockHash {}", self.inner)
-    }
+/// The gas the local simulation is run with when no prepaid gas is given (200 TeraGas).
+#[cfg(feature = "simulate")]
+pub const SIMULATION_DEFAULT_PREPAID_GAS: near_primitives::types::Gas = 200_000_000_000_000;
+#[cfg(feature = "simulate")]
+const STATE_BYTES_PREVIEW_LEN: usize = 64;
+
+/// A function call to run locally against the contract code and state at some block.
+#[cfg(feature = "simulate")]
+#[derive(Debug, Clone)]
+pub struct FunctionCallSimulation {
+    pub contract_account_id: String,
+    pub signer_account_id: String,
+    pub method_name: String,
+    pub args: Vec<u8>,
+    pub deposit: near_primitives::types::Balance,
+    pub gas: near_primitives::types::Gas,
 }
 
-#[derive(Debug, Clone, PartialEq)]
-pub struct AvailableRpcServerUrl {
-    pub inner: url::Url,
+#[cfg(feature = "simulate")]
+#[derive(Debug)]
+pub struct FunctionCallSimulationOutcome {
+    pub block_height: near_primitives::types::BlockHeight,
+    pub block_hash: near_primitives::hash::CryptoHash,
+    pub outcome: Option<near_vm_logic::VMOutcome>,
+    pub error: Option<String>,
+    /// Whether the runtime config of the network could not be fetched and the one near-cli
+    /// is built with was used instead.
+    pub default_runtime_config: bool,
+    /// Changed contract storage keys with their values before and after the call.
+    pub state_changes: Vec<(Vec<u8>, Option<Vec<u8>>, Option<Vec<u8>>)>,
 }
 
-impl std::str::FromStr for AvailableRpcServerUrl {
-    type Err = String;
-    fn from_str(s: &str) -> Result<Self, Self::Err> {
-        let url: url::Url =
-            url::Url::parse(s).map_err(|err| format!("URL is not parsed: {}", err))?;
-        actix::System::new()
-            .block_on(async {
-                near_jsonrpc_client::new_client(&url.as_str())
-                    .status()
-                    .await
+#[cfg(feature = "simulate")]
+impl FunctionCallSimulation {
+    /// Downloads the contract account, code and state at the block and executes the call
+    /// in the NEAR wasm runtime. Promises created by the call are not executed.
+    pub async fn run(
+        &self,
+        rpc_url: &str,
+        block_reference: near_primitives::types::BlockReference,
+    ) -> color_eyre::eyre::Result<FunctionCallSimulationOutcome> {
+        let rpc_client = near_jsonrpc_client::new_client(rpc_url);
+        let query_view_account_response = rpc_client
+            .query(near_jsonrpc_primitives::types::query::RpcQueryRequest {
+                block_reference,
+                request: near_primitives::views::QueryRequest::ViewAccount {
+                    account_id: self.contract_account_id.clone(),
+                },
             })
-            .map_err(|err| format!("AvailableRpcServerUrl: {:?}", err))?;
-        Ok(Self { inner: url })
+            .await
+            .map_err(|err| {
+                color_eyre::Report::msg(format!(
+                    "Failed to fetch query for view account: {:?}",
+                    err
+                ))
+            })?;
+        let account_view =
+            if let near_jsonrpc_primitives::types::query::QueryResponseKind::ViewAccount(result) =
+                query_view_account_response.kind
+            {
+                result
+            } else {
+                return Err(color_eyre::Report::msg(format!("Error call result")));
+            };
+        let block_hash = query_view_account_response.block_hash;
+        let block_height = query_view_account_response.block_height;
+        let at_block = || {
+            near_primitives::types::BlockReference::BlockId(near_primitives::types::BlockId::Hash(
+                block_hash,
+            ))
+        };
+        let query_view_code_response = rpc_client
+            .query(near_jsonrpc_primitives::types::query::RpcQueryRequest {
+                block_reference: at_block(),
+                request: near_primitives::views::QueryRequest::ViewCode {
+                    account_id: self.contract_account_id.clone(),
+                },
+            })
+            .await
+            .map_err(|err| {
+                color_eyre::Report::msg(format!(
+                    "Failed to fetch query for view contract: {:?}",
+                    err
+                ))
+            })?;
+        let contract_code =
+            if let near_jsonrpc_primitives::types::query::QueryResponseKind::ViewCode(result) =
+                query_view_code_response.kind
+            {
+                result
+            } else {
+                return Err(color_eyre::Report::msg(format!("Error call result")));
+            };
+        let query_view_state_response = rpc_client
+            .query(near_jsonrpc_primitives::types::query::RpcQueryRequest {
+                block_reference: at_block(),
+                request: near_primitives::views::QueryRequest::ViewState {
+                    account_id: self.contract_account_id.clone(),
+                    prefix: near_primitives::types::StoreKey::from(vec![]),
+                },
+            })
+            .await
+            .map_err(|err| {
+                color_eyre::Report::msg(format!(
+                    "Failed to fetch query for view contract state: {:?}",
+                    err
+                ))
+            })?;
+        let view_state =
+            if let near_jsonrpc_primitives::types::query::QueryResponseKind::ViewState(result) =
+                query_view_state_response.kind
+            {
+                result
+            } else {
+                return Err(color_eyre::Report::msg(format!("Error call result")));
+            };
+        let block = rpc_client.block(at_block()).await.map_err(|err| {
+            color_eyre::Report::msg(format!("Failed to fetch the block: {:?}", err))
+        })?;
+        let epoch_height = call_json_rpc_method::<serde_json::Value>(
+            rpc_url,
+            "validators",
+            serde_json::json!([block_hash]),
+        )
+        .await
+        .ok()
+        .and_then(|validators| {
+            validators
+                .get("epoch_height")
+                .and_then(|epoch_height| epoch_height.as_u64())
+        })
+        .unwrap_or_default();
+        let protocol_config = call_json_rpc_method::<serde_json::Value>(
+            rpc_url,
+            "EXPERIMENTAL_protocol_config",
+            serde_json::json!({ "block_id": block_hash }),
+        )
+        .await
+        .ok();
+        let runtime_config = protocol_config.as_ref().and_then(|protocol_config| {
+            let wasm_config = serde_json::from_value::<near_vm_logic::VMConfig>(
+                protocol_config["runtime_config"]["wasm_config"].clone(),
+            )
+            .ok()?;
+            let fees_config =
+                serde_json::from_value::<near_primitives::runtime::fees::RuntimeFeesConfig>(
+                    protocol_config["runtime_config"]["transaction_costs"].clone(),
+                )
+                .ok()?;
+            Some((wasm_config, fees_config))
+        });
+        let default_runtime_config = runtime_config.is_none();
+        let (wasm_config, fees_config) = runtime_config.unwrap_or_else(|| {
+            (
+                near_vm_logic::VMConfig::default(),
+                near_primitives::runtime::fees::RuntimeFeesConfig::default(),
+            )
+        });
+        let protocol_version = protocol_config
+            .as_ref()
+            .and_then(|protocol_config| protocol_config["protocol_version"].as_u64())
+            .map(|protocol_version| protocol_version as near_primitives::types::ProtocolVersion)
+            .unwrap_or(near_primitives::version::PROTOCOL_VERSION);
+
+        let mut initial_state = std::collections::HashMap::new();
+        for item in view_state.values.iter() {
+            let key = near_primitives::serialize::from_base64(&item.key).map_err(|err| {
+                color_eyre::Report::msg(format!("State key {} is not base64: {}", item.key, err))
+            })?;
+            let value = near_primitives::serialize::from_base64(&item.value).map_err(|err| {
+                color_eyre::Report::msg(format!(
+                    "State value of {} is not base64: {}",
+                    item.key, err
+                ))
+            })?;
+            initial_state.insert(key, value);
+        }
+        let mut external = near_vm_logic::mocks::mock_external::MockedExternal::new();
+        external.fake_trie = initial_state.clone();
+        let context = near_vm_logic::VMContext {
+            current_account_id: self.contract_account_id.clone(),
+            signer_account_id: self.signer_account_id.clone(),
+            signer_account_pk: near_crypto::PublicKey::empty(near_crypto::KeyType::ED25519)
+                .try_to_vec()
+                .unwrap(),
+            predecessor_account_id: self.signer_account_id.clone(),
+            input: self.args.clone(),
+            block_index: block_height,
+            block_timestamp: block.header.timestamp,
+            epoch_height,
+            account_balance: account_view.amount + self.deposit,
+            account_locked_balance: account_view.locked,
+            storage_usage: account_view.storage_usage,
+            attached_deposit: self.deposit,
+            prepaid_gas: self.gas,
+            random_seed: block.header.random_value.as_ref().to_vec(),
+            is_view: false,
+            output_data_receivers: vec![],
+        };
+        let (outcome, error) = near_vm_runner::run(
+            &near_primitives::contract::ContractCode::new(contract_code.code, None),
+            &self.method_name,
+            &mut external,
+            context,
+            &wasm_config,
+            &fees_config,
+            &[],
+            protocol_version,
+            None,
+        );
+        let mut state_changes = external
+            .fake_trie
+            .iter()
+            .filter(|(key, value)| initial_state.get(*key) != Some(*value))
+            .map(|(key, value)| {
+                (
+                    key.clone(),
+                    initial_state.get(key).cloned(),
+                    Some(value.clone()),
+                )
+            })
+            .chain(
+                initial_state
+                    .iter()
+                    .filter(|(key, _)| !external.fake_trie.contains_key(*key))
+                    .map(|(key, value)| (key.clone(), Some(value.clone()), None)),
+            )
+            .collect::<Vec<_>>();
+        state_changes.sort();
+        Ok(FunctionCallSimulationOutcome {
+            block_height,
+            block_hash,
+            outcome,
+            error: error.map(|error| format!("{:?}", error)),
+            default_runtime_config,
+            state_changes,
+        })
+    }
+
+    /// Simulates the call at the final block and suggests the gas for it with a 20% margin.
+    /// The used gas is taken, so the gas the call attaches to the promises it creates is
+    /// included. Returns `None` when the call cannot be simulated or fails.
+    pub fn estimate_gas(
+        &self,
+        network_connection_config: &ConnectionConfig,
+    ) -> Option<near_primitives::types::Gas> {
+        let simulation_outcome = actix::System::new()
+            .block_on(self.run(
+                network_connection_config.rpc_url().as_str(),
+                near_primitives::types::Finality::Final.into(),
+            ))
+            .ok()?;
+        if simulation_outcome.error.is_some() {
+            return None;
+        }
+        let used_gas = simulation_outcome.outcome?.used_gas;
+        Some(std::cmp::min(used_gas + used_gas / 5, self.gas))
     }
 }
 
-impl std::fmt::Display for AvailableRpcServerUrl {
-    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
-        write!(f, "Url {}", self.inner)
+/// Shows contract storage bytes as text when they are printable, otherwise as base64.
+#[cfg(feature = "simulate")]
+fn display_state_bytes(bytes: &[u8]) -> String {
+    let displayed = match std::str::from_utf8(bytes) {
+        Ok(text) if !text.chars().any(|c| c.is_control()) => format!("{:?}", text),
+        _ => format!("base64:{}", near_primitives::serialize::to_base64(bytes)),
+    };
+    if displayed.chars().count() > STATE_BYTES_PREVIEW_LEN {
+        format!(
+            "{}... ({} bytes)",
+            displayed
+                .chars()
+                .take(STATE_BYTES_PREVIEW_LEN)
+                .collect::<String>(),
+            bytes.len()
+        )
+    } else {
+        displayed
+    }
+}
+
+#[cfg(feature = "simulate")]
+impl FunctionCallSimulationOutcome {
+    pub fn print(&self, result_format: &ResultFormat) {
+        println!(
+            "The call was simulated locally at block #{} ({}), no transaction was sent",
+            self.block_height, self.block_hash
+        );
+        if self.default_runtime_config {
+            println!("Warning: the runtime config of the network cannot be fetched, the gas and fees are computed with the default one");
+        }
+        println!("--------------");
+        if let Some(outcome) = &self.outcome {
+            if !outcome.logs.is_empty() {
+                println!("Logs:");
+                print_outcome_logs(&outcome.logs, "  ");
+                println!("--------------");
+            }
+        }
+        if let Some(error) = &self.error {
+            println!("\nThe call failed: {}", error);
+        }
+        if let Some(outcome) = &self.outcome {
+            match &outcome.return_data {
+                near_vm_logic::ReturnData::Value(value) if self.error.is_none() => {
+                    if value.is_empty() {
+                        println!("\nThe method returned no value");
+                    } else {
+                        println!(
+                            "\nReturn value:\n{}",
+                            format_call_function_result(value, result_format)
+                        );
+                    }
+                }
+                near_vm_logic::ReturnData::ReceiptIndex(index) => println!(
+                    "\nThe method returns the result of the promise #{} (promises are not simulated)",
+                    index
+                ),
+                _ => {}
+            }
+            println!(
+                "\nGas burnt: {} ({} gas); used, including gas attached to promises: {} ({} gas)",
+                NearGas {
+                    inner: outcome.burnt_gas
+                },
+                outcome.burnt_gas,
+                NearGas {
+                    inner: outcome.used_gas
+                },
+                outcome.used_gas
+            );
+            println!(
+                "Account balance after the call: {}, storage usage: {} bytes",
+                NearBalance::from_yoctonear(outcome.balance),
+                outcome.storage_usage
+            );
+        }
+        println!("\nState changes ({}):", self.state_changes.len());
+        for (key, before, after) in self.state_changes.iter() {
+            match (before, after) {
+                (None, Some(after)) => println!(
+                    "  + {} = {}",
+                    display_state_bytes(key),
+                    display_state_bytes(after)
+                ),
+                (Some(before), Some(after)) => println!(
+                    "  ~ {}: {} -> {}",
+                    display_state_bytes(key),
+                    display_state_bytes(before),
+                    display_state_bytes(after)
+                ),
+                (Some(_), None) => println!("  - {}", display_state_bytes(key)),
+                (None, None) => {}
+            }
+        }
     }
 }
 
@@ -227,16 +2321,609 @@ impl NearGas {
                     .checked_add(num_fract_gas)
                     .ok_or_else(|| "Near Gas: underflow or overflow happens")?)
             }
-            1 => Ok(res_split[0]
-                .parse::<u64>()
-                .map_err(|err| format!("Near Gas: {}", err))?
-                .checked_mul(10u64.pow(12))
-                .ok_or_else(|| "Near Gas: underflow or overflow happens")?),
-            _ => return Err("Near Gas: incorrect number entered".to_string()),
+            1 => Ok(res_split[0]
+                .parse::<u64>()
+                .map_err(|err| format!("Near Gas: {}", err))?
+                .checked_mul(10u64.pow(12))
+                .ok_or_else(|| "Near Gas: underflow or overflow happens")?),
+            _ => return Err("Near Gas: incorrect number entered".to_string()),
+        }
+    }
+}
+
+/// Calls a view method of a contract at the final block and decodes its JSON result.
+pub async fn call_view_function<R: serde::de::DeserializeOwned>(
+    network_connection_config: &ConnectionConfig,
+    contract_account_id: &str,
+    method_name: &str,
+    args: serde_json::Value,
+) -> color_eyre::eyre::Result<R> {
+    let query_view_method_response =
+        near_jsonrpc_client::new_client(network_connection_config.rpc_url().as_str())
+            .query(near_jsonrpc_primitives::types::query::RpcQueryRequest {
+                block_reference: near_primitives::types::Finality::Final.into(),
+                request: near_primitives::views::QueryRequest::CallFunction {
+                    account_id: contract_account_id.to_string(),
+                    method_name: method_name.to_string(),
+                    args: near_primitives::types::FunctionArgs::from(args.to_string().into_bytes()),
+                },
+            })
+            .await
+            .map_err(|err| {
+                color_eyre::Report::msg(format!(
+                    "Failed to call <{}> \"{}\": {:?}",
+                    contract_account_id, method_name, err
+                ))
+            })?;
+    let call_result =
+        if let near_jsonrpc_primitives::types::query::QueryResponseKind::CallResult(result) =
+            query_view_method_response.kind
+        {
+            result
+        } else {
+            return Err(color_eyre::Report::msg(format!("Error call result")));
+        };
+    serde_json::from_slice(&call_result.result).map_err(|err| {
+        color_eyre::Report::msg(format!(
+            "The result of <{}> \"{}\" cannot be parsed: {}",
+            contract_account_id, method_name, err
+        ))
+    })
+}
+
+/// Fungible token (NEP-141) metadata as returned by `ft_metadata`.
+#[derive(Debug, Clone, serde::Deserialize)]
+pub struct FungibleTokenMetadata {
+    pub spec: String,
+    pub name: String,
+    pub symbol: String,
+    pub decimals: u8,
+}
+
+impl FungibleTokenMetadata {
+    pub async fn fetch(
+        network_connection_config: &ConnectionConfig,
+        ft_contract_account_id: &str,
+    ) -> color_eyre::eyre::Result<Self> {
+        call_view_function(
+            network_connection_config,
+            ft_contract_account_id,
+            "ft_metadata",
+            serde_json::json!({}),
+        )
+        .await
+    }
+
+    /// Parses a human amount such as "10.5" or "10.5 USDC" into the smallest token units.
+    pub fn parse_amount(&self, s: &str) -> Result<u128, String> {
+        let num = s.trim().trim_end_matches(char::is_alphabetic).trim();
+        let symbol = s.trim().trim_start_matches(&num).trim();
+        if !symbol.is_empty() && !symbol.eq_ignore_ascii_case(&self.symbol) {
+            return Err(format!(
+                "Fungible token amount: expected an amount in {}, got {}",
+                self.symbol, symbol
+            ));
+        }
+        let decimals = u32::from(self.decimals);
+        let res_split: Vec<&str> = num.split('.').collect();
+        let (int_part, fract_part) = match res_split.len() {
+            1 => (res_split[0], ""),
+            2 => (res_split[0], res_split[1]),
+            _ => return Err("Fungible token amount: incorrect number entered".to_string()),
+        };
+        if fract_part.len() as u32 > decimals {
+            return Err(format!(
+                "Fungible token amount: {} has only {} decimals",
+                self.symbol, decimals
+            ));
+        }
+        let overflow = || "Fungible token amount: underflow or overflow happens".to_string();
+        let num_int = int_part
+            .parse::<u128>()
+            .map_err(|err| format!("Fungible token amount: {}", err))?
+            .checked_mul(10u128.checked_pow(decimals).ok_or_else(overflow)?)
+            .ok_or_else(overflow)?;
+        let num_fract = if fract_part.is_empty() {
+            0
+        } else {
+            fract_part
+                .parse::<u128>()
+                .map_err(|err| format!("Fungible token amount: {}", err))?
+                .checked_mul(
+                    10u128
+                        .checked_pow(decimals - fract_part.len() as u32)
+                        .ok_or_else(overflow)?,
+                )
+                .ok_or_else(overflow)?
+        };
+        num_int.checked_add(num_fract).ok_or_else(overflow)
+    }
+
+    /// Formats an amount in the smallest token units with the token decimals and symbol; the
+    /// amount is left in the smallest units if the decimals do not fit into `u128`.
+    pub fn format_amount(&self, amount: u128) -> String {
+        let one_token = match 10u128.checked_pow(u32::from(self.decimals)) {
+            Some(one_token) => one_token,
+            None => return format!("{} (in the smallest {} units)", amount, self.symbol),
+        };
+        let fract = format!(
+            "{:0>width$}",
+            amount % one_token,
+            width = usize::from(self.decimals)
+        );
+        let fract = fract.trim_end_matches('0');
+        if fract.is_empty() {
+            format!("{} {}", amount / one_token, self.symbol)
+        } else {
+            format!("{}.{} {}", amount / one_token, fract, self.symbol)
+        }
+    }
+}
+
+/// Returns the balance of an account in a fungible token contract, in the smallest token units.
+pub async fn fetch_ft_balance(
+    network_connection_config: &ConnectionConfig,
+    ft_contract_account_id: &str,
+    account_id: &str,
+) -> color_eyre::eyre::Result<u128> {
+    let ft_balance: String = call_view_function(
+        network_connection_config,
+        ft_contract_account_id,
+        "ft_balance_of",
+        serde_json::json!({ "account_id": account_id }),
+    )
+    .await?;
+    ft_balance.parse::<u128>().map_err(|err| {
+        color_eyre::Report::msg(format!(
+            "The balance {:?} returned by <{}> is not a number: {}",
+            ft_balance, ft_contract_account_id, err
+        ))
+    })
+}
+
+/// Account IDs given as a comma-separated list or as a path to a file with them
+/// (separated by commas or whitespace, `#` starts a comment).
+#[derive(Debug, Clone, PartialEq)]
+pub struct AccountIdList {
+    pub inner: Vec<String>,
+}
+
+impl std::str::FromStr for AccountIdList {
+    type Err = String;
+    fn from_str(s: &str) -> Result<Self, Self::Err> {
+        let path = std::path::Path::new(s.trim());
+        let list = if path.is_file() {
+            std::fs::read_to_string(path)
+                .map_err(|err| format!("Failed to read {:?}: {}", path, err))?
+        } else {
+            s.to_string()
+        };
+        let inner = list
+            .lines()
+            .map(|line| line.splitn(2, '#').next().unwrap_or_default())
+            .flat_map(|line| line.split(|c: char| c == ',' || c.is_whitespace()))
+            .filter(|account_id| !account_id.is_empty())
+            .map(str::to_string)
+            .collect::<Vec<_>>();
+        if inner.is_empty() {
+            return Err("Account ID list: no account IDs given".to_string());
+        }
+        Ok(Self { inner })
+    }
+}
+
+/// The storage balance of an account registered in a contract (NEP-145).
+#[derive(Debug, Clone, serde::Deserialize)]
+pub struct StorageBalance {
+    #[serde(with = "near_primitives::serialize::u128_dec_format")]
+    pub total: u128,
+    #[serde(with = "near_primitives::serialize::u128_dec_format")]
+    pub available: u128,
+}
+
+impl StorageBalance {
+    pub async fn fetch(
+        network_connection_config: &ConnectionConfig,
+        contract_account_id: &str,
+        account_id: &str,
+    ) -> color_eyre::eyre::Result<Option<Self>> {
+        call_view_function(
+            network_connection_config,
+            contract_account_id,
+            "storage_balance_of",
+            serde_json::json!({ "account_id": account_id }),
+        )
+        .await
+    }
+}
+
+/// The storage deposit bounds of a contract (NEP-145).
+#[derive(Debug, Clone, serde::Deserialize)]
+pub struct StorageBalanceBounds {
+    #[serde(with = "near_primitives::serialize::u128_dec_format")]
+    pub min: u128,
+    #[serde(with = "near_primitives::serialize::option_u128_dec_format")]
+    pub max: Option<u128>,
+}
+
+impl StorageBalanceBounds {
+    pub async fn fetch(
+        network_connection_config: &ConnectionConfig,
+        contract_account_id: &str,
+    ) -> color_eyre::eyre::Result<Self> {
+        call_view_function(
+            network_connection_config,
+            contract_account_id,
+            "storage_balance_bounds",
+            serde_json::json!({}),
+        )
+        .await
+    }
+}
+
+/// Non-fungible token contract metadata (NEP-177) as returned by `nft_metadata`.
+#[derive(Debug, Clone, serde::Deserialize)]
+pub struct NonFungibleTokenContractMetadata {
+    pub spec: String,
+    pub name: String,
+    pub symbol: String,
+    #[serde(default)]
+    pub base_uri: Option<String>,
+}
+
+impl NonFungibleTokenContractMetadata {
+    pub async fn fetch(
+        network_connection_config: &ConnectionConfig,
+        nft_contract_account_id: &str,
+    ) -> color_eyre::eyre::Result<Self> {
+        call_view_function(
+            network_connection_config,
+            nft_contract_account_id,
+            "nft_metadata",
+            serde_json::json!({}),
+        )
+        .await
+    }
+
+    /// Resolves a relative token media link against the `base_uri` of the contract.
+    pub fn media_url(&self, media: &str) -> String {
+        match &self.base_uri {
+            Some(base_uri) if !media.contains("://") => {
+                format!(
+                    "{}/{}",
+                    base_uri.trim_end_matches('/'),
+                    media.trim_start_matches('/')
+                )
+            }
+            _ => media.to_string(),
+        }
+    }
+}
+
+/// Non-fungible token metadata (NEP-177); only the fields near-cli shows.
+#[derive(Debug, Clone, Default, serde::Deserialize)]
+pub struct NonFungibleTokenMetadata {
+    #[serde(default)]
+    pub title: Option<String>,
+    #[serde(default)]
+    pub description: Option<String>,
+    #[serde(default)]
+    pub media: Option<String>,
+    #[serde(default)]
+    pub copies: Option<u64>,
+}
+
+/// A non-fungible token (NEP-171) as returned by `nft_token` and `nft_tokens_for_owner`.
+#[derive(Debug, Clone, serde::Deserialize)]
+pub struct NonFungibleToken {
+    pub token_id: String,
+    pub owner_id: String,
+    #[serde(default)]
+    pub metadata: Option<NonFungibleTokenMetadata>,
+    /// Approved accounts with their approval IDs (NEP-178)
+    #[serde(default)]
+    pub approved_account_ids: Option<std::collections::BTreeMap<String, u64>>,
+}
+
+impl NonFungibleToken {
+    pub async fn fetch(
+        network_connection_config: &ConnectionConfig,
+        nft_contract_account_id: &str,
+        token_id: &str,
+    ) -> color_eyre::eyre::Result<Option<Self>> {
+        call_view_function(
+            network_connection_config,
+            nft_contract_account_id,
+            "nft_token",
+            serde_json::json!({ "token_id": token_id }),
+        )
+        .await
+    }
+
+    /// Fetches a page of the tokens owned by an account.
+    pub async fn fetch_for_owner(
+        network_connection_config: &ConnectionConfig,
+        nft_contract_account_id: &str,
+        account_id: &str,
+        from_index: u128,
+        limit: u64,
+    ) -> color_eyre::eyre::Result<Vec<Self>> {
+        call_view_function(
+            network_connection_config,
+            nft_contract_account_id,
+            "nft_tokens_for_owner",
+            serde_json::json!({
+                "account_id": account_id,
+                "from_index": from_index.to_string(),
+                "limit": limit,
+            }),
+        )
+        .await
+    }
+}
+
+/// A validator of the current epoch as returned by the `validators` RPC.
+#[derive(Debug, Clone, serde::Deserialize)]
+pub struct CurrentEpochValidator {
+    pub account_id: String,
+    #[serde(with = "near_primitives::serialize::u128_dec_format")]
+    pub stake: u128,
+    #[serde(default)]
+    pub is_slashed: bool,
+    #[serde(default)]
+    pub num_produced_blocks: u64,
+    #[serde(default)]
+    pub num_expected_blocks: u64,
+    #[serde(default)]
+    pub num_produced_chunks: u64,
+    #[serde(default)]
+    pub num_expected_chunks: u64,
+}
+
+/// A validator of the next epoch or a stake proposal as returned by the `validators` RPC.
+#[derive(Debug, Clone, serde::Deserialize)]
+pub struct ValidatorStake {
+    pub account_id: String,
+    #[serde(with = "near_primitives::serialize::u128_dec_format")]
+    pub stake: u128,
+}
+
+/// A validator kicked out in the previous epoch; the reason is kept as JSON since its shape
+/// depends on the protocol version.
+#[derive(Debug, Clone, serde::Deserialize)]
+pub struct ValidatorKickout {
+    pub account_id: String,
+    pub reason: serde_json::Value,
+}
+
+/// The validators of an epoch as returned by the `validators` RPC.
+#[derive(Debug, Clone, serde::Deserialize)]
+pub struct EpochValidators {
+    pub current_validators: Vec<CurrentEpochValidator>,
+    pub next_validators: Vec<ValidatorStake>,
+    pub current_proposals: Vec<ValidatorStake>,
+    pub prev_epoch_kickout: Vec<ValidatorKickout>,
+    pub epoch_start_height: near_primitives::types::BlockHeight,
+    #[serde(default)]
+    pub epoch_height: Option<near_primitives::types::EpochHeight>,
+}
+
+impl EpochValidators {
+    /// Fetches the validators of the epoch of the given block (the latest one when `None`).
+    pub async fn fetch(
+        network_connection_config: &ConnectionConfig,
+        block_id: Option<near_primitives::types::BlockId>,
+    ) -> color_eyre::eyre::Result<Self> {
+        call_json_rpc_method(
+            network_connection_config.rpc_url().as_str(),
+            "validators",
+            serde_json::json!([block_id]),
+        )
+        .await
+    }
+
+    /// The stakes of the validators of the epoch after the next one if the current proposals
+    /// (and the given new one) are accepted: the next validators with their stakes updated by
+    /// the proposals, without the kicked out ones.
+    pub fn proposed_stakes(&self, new_proposal: Option<(&str, u128)>) -> Vec<u128> {
+        let mut stakes = self
+            .next_validators
+            .iter()
+            .map(|validator| (validator.account_id.as_str(), validator.stake))
+            .collect::<std::collections::BTreeMap<_, _>>();
+        for proposal in self.current_proposals.iter() {
+            stakes.insert(proposal.account_id.as_str(), proposal.stake);
+        }
+        for kickout in self.prev_epoch_kickout.iter() {
+            stakes.remove(kickout.account_id.as_str());
+        }
+        if let Some((account_id, stake)) = new_proposal {
+            stakes.insert(account_id, stake);
+        }
+        stakes.retain(|_, stake| *stake > 0);
+        stakes.values().cloned().collect()
+    }
+}
+
+/// Describes why a validator was kicked out, e.g. `{"NotEnoughBlocks": {"produced": 1, "expected": 10}}`.
+pub fn validator_kickout_reason_summary(reason: &serde_json::Value) -> String {
+    match reason {
+        serde_json::Value::String(reason) => reason.to_string(),
+        serde_json::Value::Object(reason) => reason
+            .iter()
+            .map(|(kind, details)| match kind.as_str() {
+                "NotEnoughBlocks" | "NotEnoughChunks" => format!(
+                    "{} (produced {} of {} expected)",
+                    kind, details["produced"], details["expected"]
+                ),
+                "NotEnoughStake" => format!(
+                    "{} (stake {}, threshold {})",
+                    kind,
+                    details
+                        .get("stake_u128")
+                        .or_else(|| details.get("stake"))
+                        .and_then(|stake| stake.as_str())
+                        .and_then(|stake| stake.parse::<u128>().ok())
+                        .map(|stake| NearBalance::from_yoctonear(stake).to_string())
+                        .unwrap_or_else(|| "unknown".to_string()),
+                    details
+                        .get("threshold_u128")
+                        .or_else(|| details.get("threshold"))
+                        .and_then(|threshold| threshold.as_str())
+                        .and_then(|threshold| threshold.parse::<u128>().ok())
+                        .map(|threshold| NearBalance::from_yoctonear(threshold).to_string())
+                        .unwrap_or_else(|| "unknown".to_string()),
+                ),
+                _ => format!("{} {}", kind, details),
+            })
+            .collect::<Vec<_>>()
+            .join(", "),
+        reason => reason.to_string(),
+    }
+}
+
+/// The protocol version from which the seat price is derived from the minimum stake ratio.
+const SEAT_PRICE_MINIMUM_STAKE_RATIO_PROTOCOL_VERSION: u64 = 49;
+
+/// The share of the seat price a stake proposal needs at least, unless the protocol config says
+/// otherwise.
+const DEFAULT_MINIMUM_STAKE_DIVISOR: u64 = 10;
+
+/// The network parameters the seat price depends on.
+#[derive(Debug, Clone, PartialEq)]
+pub struct SeatPriceConfig {
+    pub num_seats: u64,
+    pub minimum_stake_ratio: Option<(u128, u128)>,
+    pub minimum_stake_divisor: u64,
+}
+
+impl SeatPriceConfig {
+    pub async fn fetch(
+        network_connection_config: &ConnectionConfig,
+    ) -> color_eyre::eyre::Result<Self> {
+        let protocol_config = call_json_rpc_method::<serde_json::Value>(
+            network_connection_config.rpc_url().as_str(),
+            "EXPERIMENTAL_protocol_config",
+            serde_json::json!({ "finality": "final" }),
+        )
+        .await?;
+        let num_seats = protocol_config["num_block_producer_seats"]
+            .as_u64()
+            .ok_or_else(|| {
+                color_eyre::Report::msg("The protocol config has no num_block_producer_seats")
+            })?;
+        let protocol_version = protocol_config["protocol_version"]
+            .as_u64()
+            .unwrap_or_default();
+        let minimum_stake_ratio = match protocol_config["minimum_stake_ratio"].as_array() {
+            Some(ratio)
+                if ratio.len() == 2
+                    && protocol_version >= SEAT_PRICE_MINIMUM_STAKE_RATIO_PROTOCOL_VERSION =>
+            {
+                match (ratio[0].as_u64(), ratio[1].as_u64()) {
+                    (Some(numerator), Some(denominator)) if denominator > 0 => {
+                        Some((u128::from(numerator), u128::from(denominator)))
+                    }
+                    _ => None,
+                }
+            }
+            _ => None,
+        };
+        let minimum_stake_divisor = protocol_config["minimum_stake_divisor"]
+            .as_u64()
+            .filter(|divisor| *divisor > 0)
+            .unwrap_or(DEFAULT_MINIMUM_STAKE_DIVISOR);
+        Ok(Self {
+            num_seats,
+            minimum_stake_ratio,
+            minimum_stake_divisor,
+        })
+    }
+
+    /// The smallest stake the runtime accepts in a proposal: stakes below it fail with
+    /// `InsufficientStake`.
+    pub fn minimum_stake(&self, seat_price: u128) -> u128 {
+        seat_price / u128::from(self.minimum_stake_divisor)
+    }
+
+    /// Estimates the minimum stake to get a seat among validators with the given stakes.
+    ///
+    /// With the minimum stake ratio, any stake above that share of the total stake gets a seat
+    /// while there are free seats, otherwise the smallest stake has to be outbid. Before that,
+    /// the seat price is the largest threshold for which `sum(stake / threshold)` still fills all
+    /// the seats. Returns `None` when the stakes cannot fill the seats.
+    pub fn estimate_seat_price(&self, stakes: &[u128]) -> Option<u128> {
+        let total_stake: u128 = stakes.iter().sum();
+        if let Some((numerator, denominator)) = self.minimum_stake_ratio {
+            return if (stakes.len() as u64) < self.num_seats {
+                Some(total_stake / denominator * numerator)
+            } else {
+                stakes.iter().min().map(|min_stake| min_stake + 1)
+            };
+        }
+        if total_stake < u128::from(self.num_seats) {
+            return None;
         }
+        let fills_seats = |threshold: u128| {
+            let mut num_seats = 0;
+            for stake in stakes {
+                num_seats += stake / threshold;
+                if num_seats >= u128::from(self.num_seats) {
+                    return true;
+                }
+            }
+            false
+        };
+        let (mut left, mut right) = (1, total_stake + 1);
+        while left != right - 1 {
+            let middle = left + (right - left) / 2;
+            if fills_seats(middle) {
+                left = middle;
+            } else {
+                right = middle;
+            }
+        }
+        Some(left)
+    }
+}
+
+/// Checks that the key can be used for staking; the runtime rejects any other key type with
+/// `UnsuitableStakingKey`.
+pub fn check_staking_key(public_key: &near_crypto::PublicKey) -> Result<(), String> {
+    match public_key.key_type() {
+        near_crypto::KeyType::ED25519 => Ok(()),
+        key_type => Err(format!(
+            "The staking key must be an ED25519 key, but <{}> is {}",
+            public_key, key_type
+        )),
     }
 }
 
+/// Checks a stake proposal of an account with the given liquid and locked balances the way the
+/// runtime does, so that it does not fail with `TriesToStake` or `TriesToUnstake` after being sent.
+pub fn check_stake_proposal(
+    stake: u128,
+    account_amount: u128,
+    account_locked: u128,
+) -> Result<(), String> {
+    if stake == 0 {
+        return if account_locked == 0 {
+            Err("The account has nothing staked, so there is nothing to unstake".to_string())
+        } else {
+            Ok(())
+        };
+    }
+    if stake > account_amount.saturating_add(account_locked) {
+        return Err(format!(
+            "The stake {} exceeds the balance {} plus the locked amount {}",
+            NearBalance::from_yoctonear(stake),
+            NearBalance::from_yoctonear(account_amount),
+            NearBalance::from_yoctonear(account_locked)
+        ));
+    }
+    Ok(())
+}
+
 #[derive(Debug, Clone)]
 pub enum ConnectionConfig {
     Testnet,
@@ -296,6 +2983,24 @@ impl ConnectionConfig {
             Self::Custom { url: _ } => crate::consts::DIR_NAME_CUSTOM,
         }
     }
+
+    /// The account the lockup contracts of the network are created under, if it is known.
+    pub fn lockup_master_account_id(&self) -> Option<&str> {
+        match self {
+            Self::Testnet => Some(crate::consts::TESTNET_LOCKUP_MASTER_ACCOUNT_ID),
+            Self::Mainnet => Some(crate::consts::MAINNET_LOCKUP_MASTER_ACCOUNT_ID),
+            Self::Betanet | Self::Custom { url: _ } => None,
+        }
+    }
+
+    /// The account of the staking pool whitelist used by the lockup contracts, if it is known.
+    pub fn lockup_whitelist_account_id(&self) -> Option<&str> {
+        match self {
+            Self::Testnet => Some(crate::consts::TESTNET_LOCKUP_WHITELIST_ACCOUNT_ID),
+            Self::Mainnet => Some(crate::consts::MAINNET_LOCKUP_WHITELIST_ACCOUNT_ID),
+            Self::Betanet | Self::Custom { url: _ } => None,
+        }
+    }
 }
 
 #[derive(Debug)]
@@ -534,10 +3239,1125 @@ pub async fn print_transaction_error(
                             println!("Error: The attached amount of gas in a FunctionCall action has to be a positive number.")
                         }
                     }
-                },
+                },
+            }
+        },
+    }
+}
+
+/// Trie column under which contract storage lives (see `TrieKey::ContractData` in nearcore).
+const TRIE_COLUMN_CONTRACT_DATA: u8 = 9;
+const TRIE_ACCOUNT_DATA_SEPARATOR: u8 = b',';
+
+const TRIE_LEAF_NODE: u8 = 0;
+const TRIE_BRANCH_NODE_NO_VALUE: u8 = 1;
+const TRIE_BRANCH_NODE_WITH_VALUE: u8 = 2;
+const TRIE_EXTENSION_NODE: u8 = 3;
+
+/// How many blocks after the queried one we are ready to walk to find a new chunk for the shard.
+const STATE_ROOT_LOOKUP_MAX_BLOCKS: u64 = 16;
+
+pub fn account_id_to_shard_id(account_id: &str, num_shards: u64) -> u64 {
+    let hash = near_primitives::hash::hash(account_id.as_bytes());
+    let mut shard_seed = [0u8; 8];
+    shard_seed.copy_from_slice(&hash.as_ref()[..8]);
+    u64::from_le_bytes(shard_seed) % num_shards
+}
+
+#[derive(Debug, PartialEq)]
+enum RawTrieNode {
+    Leaf(Vec<u8>, near_primitives::hash::CryptoHash),
+    Branch(
+        Vec<Option<near_primitives::hash::CryptoHash>>,
+        Option<near_primitives::hash::CryptoHash>,
+    ),
+    Extension(Vec<u8>, near_primitives::hash::CryptoHash),
+}
+
+struct ByteReader<'a> {
+    bytes: &'a [u8],
+}
+
+impl<'a> ByteReader<'a> {
+    fn take(&mut self, len: usize) -> Result<&'a [u8], String> {
+        if self.bytes.len() < len {
+            return Err("unexpected end of data".to_string());
+        }
+        let (head, tail) = self.bytes.split_at(len);
+        self.bytes = tail;
+        Ok(head)
+    }
+
+    fn read_u8(&mut self) -> Result<u8, String> {
+        Ok(self.take(1)?[0])
+    }
+
+    fn read_u16(&mut self) -> Result<u16, String> {
+        let mut buf = [0u8; 2];
+        buf.copy_from_slice(self.take(2)?);
+        Ok(u16::from_le_bytes(buf))
+    }
+
+    fn read_u32(&mut self) -> Result<u32, String> {
+        let mut buf = [0u8; 4];
+        buf.copy_from_slice(self.take(4)?);
+        Ok(u32::from_le_bytes(buf))
+    }
+
+    fn read_hash(&mut self) -> Result<near_primitives::hash::CryptoHash, String> {
+        self.take(32)?
+            .try_into()
+            .map_err(|err| format!("Trie node: hash could not be collected: {:?}", err))
+    }
+
+    fn read_key(&mut self) -> Result<Vec<u8>, String> {
+        let key_len = self.read_u32()? as usize;
+        Ok(self.take(key_len)?.to_vec())
+    }
+
+    fn read_leb128_u32(&mut self) -> Result<u32, String> {
+        let mut result: u64 = 0;
+        for shift in (0..35).step_by(7) {
+            let byte = self.read_u8()?;
+            result |= u64::from(byte & 0x7f) << shift;
+            if byte & 0x80 == 0 {
+                return result
+                    .try_into()
+                    .map_err(|_| "LEB128 value does not fit into u32".to_string());
+            }
+        }
+        Err("LEB128 value is too long".to_string())
+    }
+
+    fn read_wasm_name(&mut self) -> Result<String, String> {
+        let name_len = self.read_leb128_u32()? as usize;
+        String::from_utf8(self.take(name_len)?.to_vec())
+            .map_err(|err| format!("name is not a valid UTF-8: {}", err))
+    }
+
+    fn read_wasm_external_kind(&mut self) -> Result<WasmExternalKind, String> {
+        match self.read_u8()? {
+            0 => Ok(WasmExternalKind::Function),
+            1 => Ok(WasmExternalKind::Table),
+            2 => Ok(WasmExternalKind::Memory),
+            3 => Ok(WasmExternalKind::Global),
+            kind => Err(format!("unknown external kind {}", kind)),
+        }
+    }
+
+    fn read_wasm_limits(&mut self) -> Result<(), String> {
+        let flags = self.read_u8()?;
+        self.read_leb128_u32()?;
+        if flags & 1 != 0 {
+            self.read_leb128_u32()?;
+        }
+        Ok(())
+    }
+}
+
+impl RawTrieNode {
+    /// Decodes `RawTrieNodeWithSize` as nearcore stores it (the trailing memory usage is ignored).
+    fn decode(bytes: &[u8]) -> Result<Self, String> {
+        let mut reader = ByteReader { bytes };
+        match reader.read_u8()? {
+            TRIE_LEAF_NODE => {
+                let key = reader.read_key()?;
+                let _value_length = reader.read_u32()?;
+                let value_hash = reader.read_hash()?;
+                Ok(Self::Leaf(key, value_hash))
+            }
+            node_type @ TRIE_BRANCH_NODE_NO_VALUE | node_type @ TRIE_BRANCH_NODE_WITH_VALUE => {
+                let value = if node_type == TRIE_BRANCH_NODE_WITH_VALUE {
+                    let _value_length = reader.read_u32()?;
+                    Some(reader.read_hash()?)
+                } else {
+                    None
+                };
+                let bitmap = reader.read_u16()?;
+                let mut children = Vec::with_capacity(16);
+                for index in 0..16 {
+                    if bitmap & (1 << index) != 0 {
+                        children.push(Some(reader.read_hash()?));
+                    } else {
+                        children.push(None);
+                    }
+                }
+                Ok(Self::Branch(children, value))
+            }
+            TRIE_EXTENSION_NODE => {
+                let key = reader.read_key()?;
+                let child = reader.read_hash()?;
+                Ok(Self::Extension(key, child))
+            }
+            node_type => Err(format!("Trie node: unknown node type {}", node_type)),
+        }
+    }
+}
+
+fn bytes_to_nibbles(bytes: &[u8]) -> Vec<u8> {
+    bytes
+        .iter()
+        .flat_map(|byte| vec![byte >> 4, byte & 0x0f])
+        .collect()
+}
+
+/// Decodes a hex-prefix encoded trie key into nibbles.
+fn decode_trie_key_nibbles(encoded: &[u8]) -> Result<Vec<u8>, String> {
+    let (first, rest) = encoded
+        .split_first()
+        .ok_or_else(|| "Trie node: empty key".to_string())?;
+    let mut nibbles = Vec::with_capacity(rest.len() * 2 + 1);
+    if first & 0x10 != 0 {
+        nibbles.push(first & 0x0f);
+    }
+    nibbles.extend(bytes_to_nibbles(rest));
+    Ok(nibbles)
+}
+
+/// Walks the trie from `state_root` using only the nodes from the proof and returns
+/// the hash of the value stored under `key` (`None` if the proof shows the key is absent).
+fn lookup_value_hash_in_proof(
+    proof_nodes: &std::collections::HashMap<near_primitives::hash::CryptoHash, Vec<u8>>,
+    state_root: &near_primitives::hash::CryptoHash,
+    key: &[u8],
+) -> Result<Option<near_primitives::hash::CryptoHash>, String> {
+    let key_nibbles = bytes_to_nibbles(key);
+    let mut remaining: &[u8] = &key_nibbles;
+    let mut node_hash = state_root.clone();
+    loop {
+        if node_hash == near_primitives::hash::CryptoHash::default() {
+            return Ok(None);
+        }
+        let node_bytes = proof_nodes
+            .get(&node_hash)
+            .ok_or_else(|| format!("trie node {} is missing from the proof", node_hash))?;
+        match RawTrieNode::decode(node_bytes)? {
+            RawTrieNode::Leaf(encoded_key, value_hash) => {
+                let leaf_nibbles = decode_trie_key_nibbles(&encoded_key)?;
+                return Ok(if remaining == leaf_nibbles.as_slice() {
+                    Some(value_hash)
+                } else {
+                    None
+                });
+            }
+            RawTrieNode::Extension(encoded_key, child) => {
+                let extension_nibbles = decode_trie_key_nibbles(&encoded_key)?;
+                if !remaining.starts_with(&extension_nibbles) {
+                    return Ok(None);
+                }
+                remaining = &remaining[extension_nibbles.len()..];
+                node_hash = child;
+            }
+            RawTrieNode::Branch(children, value) => match remaining.split_first() {
+                None => return Ok(value),
+                Some((index, rest)) => match children[*index as usize] {
+                    Some(child) => {
+                        remaining = rest;
+                        node_hash = child;
+                    }
+                    None => return Ok(None),
+                },
+            },
+        }
+    }
+}
+
+/// Checks that every key/value pair returned by `ViewState` is proven against `state_root`.
+pub fn verify_view_state_proof(
+    account_id: &str,
+    state_root: &near_primitives::hash::CryptoHash,
+    view_state: &near_primitives::views::ViewStateResult,
+) -> crate::CliResult {
+    let mut proof_nodes = std::collections::HashMap::new();
+    for encoded_node in view_state
+        .proof
+        .iter()
+        .chain(view_state.values.iter().flat_map(|item| item.proof.iter()))
+    {
+        let node = near_primitives::serialize::from_base64(encoded_node).map_err(|err| {
+            color_eyre::Report::msg(format!("Proof node is not valid base64: {}", err))
+        })?;
+        proof_nodes.insert(near_primitives::hash::hash(&node), node);
+    }
+    if !view_state.values.is_empty() && proof_nodes.is_empty() {
+        return Err(color_eyre::Report::msg(format!(
+            "The RPC server returned {} key/value pairs without any proof",
+            view_state.values.len()
+        )));
+    }
+    for item in view_state.values.iter() {
+        let key = near_primitives::serialize::from_base64(&item.key).map_err(|err| {
+            color_eyre::Report::msg(format!("State key is not valid base64: {}", err))
+        })?;
+        let value = near_primitives::serialize::from_base64(&item.value).map_err(|err| {
+            color_eyre::Report::msg(format!("State value is not valid base64: {}", err))
+        })?;
+        let mut trie_key = vec![TRIE_COLUMN_CONTRACT_DATA];
+        trie_key.extend(account_id.as_bytes());
+        trie_key.push(TRIE_ACCOUNT_DATA_SEPARATOR);
+        trie_key.extend(&key);
+        let proven_value_hash = lookup_value_hash_in_proof(&proof_nodes, state_root, &trie_key)
+            .map_err(|err| {
+                color_eyre::Report::msg(format!("State key {} is not proven: {}", item.key, err))
+            })?;
+        match proven_value_hash {
+            Some(value_hash) if value_hash == near_primitives::hash::hash(&value) => {}
+            Some(_) => {
+                return Err(color_eyre::Report::msg(format!(
+                    "State key {} is not proven: the returned value does not match the proof",
+                    item.key
+                )))
+            }
+            None => {
+                return Err(color_eyre::Report::msg(format!(
+                    "State key {} is not proven: the proof shows the key is absent",
+                    item.key
+                )))
+            }
+        }
+    }
+    Ok(())
+}
+
+/// The error the RPC server returns for a height without a block.
+fn is_unknown_block_error(err: &near_jsonrpc_primitives::errors::RpcError) -> bool {
+    match &err.data {
+        Some(serde_json::Value::String(data)) => data.contains("DB Not Found"),
+        _ => false,
+    }
+}
+
+/// Finds the root of the state the RPC server used to answer a query at `block_hash`.
+/// The state after applying a block is committed to by `prev_state_root` of the next new
+/// chunk of the same shard, so we walk forward along the chain until we meet one.
+async fn fetch_post_state_root(
+    rpc_url: &str,
+    block_hash: near_primitives::hash::CryptoHash,
+    account_id: &str,
+) -> color_eyre::eyre::Result<(
+    near_primitives::types::ShardId,
+    near_primitives::hash::CryptoHash,
+)> {
+    let rpc_client = near_jsonrpc_client::new_client(rpc_url);
+    let queried_block = rpc_client
+        .block(near_primitives::types::BlockReference::BlockId(
+            near_primitives::types::BlockId::Hash(block_hash),
+        ))
+        .await
+        .map_err(|err| {
+            color_eyre::Report::msg(format!("Failed to fetch the queried block: {:?}", err))
+        })?;
+    let shard_id = account_id_to_shard_id(account_id, queried_block.chunks.len() as u64);
+    let mut prev_hash = queried_block.header.hash;
+    let mut height = queried_block.header.height;
+    while height < queried_block.header.height + STATE_ROOT_LOOKUP_MAX_BLOCKS {
+        height += 1;
+        let block = match rpc_client
+            .block(near_primitives::types::BlockReference::BlockId(
+                near_primitives::types::BlockId::Height(height),
+            ))
+            .await
+        {
+            Ok(block) => block,
+            // Heights can be skipped when a block producer misses its slot
+            Err(err) if is_unknown_block_error(&err) => continue,
+            Err(err) => {
+                return Err(color_eyre::Report::msg(format!(
+                    "Failed to fetch block #{}: {:?}",
+                    height, err
+                )))
+            }
+        };
+        if block.header.prev_hash != prev_hash {
+            return Err(color_eyre::Report::msg(format!(
+                "Block #{} does not extend block {}, the queried block is not on the canonical chain",
+                height, prev_hash
+            )));
+        }
+        let chunk_header = block
+            .chunks
+            .iter()
+            .find(|chunk_header| chunk_header.shard_id == shard_id)
+            .ok_or_else(|| {
+                color_eyre::Report::msg(format!(
+                    "Block #{} has no chunk header for shard {}",
+                    height, shard_id
+                ))
+            })?;
+        if chunk_header.height_included == block.header.height {
+            return Ok((shard_id, chunk_header.prev_state_root.clone()));
+        }
+        prev_hash = block.header.hash;
+    }
+    Err(color_eyre::Report::msg(format!(
+        "Could not find a new chunk for shard {} within {} blocks after the queried block",
+        shard_id, STATE_ROOT_LOOKUP_MAX_BLOCKS
+    )))
+}
+
+/// Verifies a `ViewState` answer of the RPC server at `rpc_url` and reports the result.
+/// The state root is taken from a block served by the same RPC server, so the check proves the
+/// answer is consistent with that server's view of the chain, not that the chain is canonical.
+pub async fn verify_and_print_view_state_proof(
+    rpc_url: &str,
+    block_hash: near_primitives::hash::CryptoHash,
+    account_id: &str,
+    view_state: &near_primitives::views::ViewStateResult,
+) -> crate::CliResult {
+    let (shard_id, state_root) = fetch_post_state_root(rpc_url, block_hash, account_id).await?;
+    verify_view_state_proof(account_id, &state_root, view_state)?;
+    println!(
+        "Proof verified: all {} key/value pairs are proven against the state root {} of shard {}",
+        view_state.values.len(),
+        state_root,
+        shard_id
+    );
+    println!(
+        "Note: the state root was reported by the same RPC server that returned the proof; \
+         use a trusted RPC server to rely on this check."
+    );
+    Ok(())
+}
+
+/// Upper bound for JSON RPC responses we read without `near_jsonrpc_client`.
+const JSON_RPC_RESPONSE_SIZE_LIMIT: usize = 64 * 1024 * 1024;
+
+/// Calls a JSON RPC method which is not wrapped by `near_jsonrpc_client` yet.
+pub async fn call_json_rpc_method<R: serde::de::DeserializeOwned>(
+    rpc_url: &str,
+    method: &str,
+    params: serde_json::Value,
+) -> color_eyre::eyre::Result<R> {
+    let mut response = actix_web::client::Client::default()
+        .post(rpc_url)
+        .send_json(&serde_json::json!({
+            "jsonrpc": "2.0",
+            "id": "dontcare",
+            "method": method,
+            "params": params,
+        }))
+        .await
+        .map_err(|err| color_eyre::Report::msg(format!("Failed to call {}: {}", method, err)))?;
+    let response_json: serde_json::Value = response
+        .json()
+        .limit(JSON_RPC_RESPONSE_SIZE_LIMIT)
+        .await
+        .map_err(|err| {
+            color_eyre::Report::msg(format!("Failed to read {} response: {}", method, err))
+        })?;
+    if let Some(error) = response_json.get("error") {
+        return Err(color_eyre::Report::msg(format!(
+            "{} returned an error: {}",
+            method, error
+        )));
+    }
+    serde_json::from_value(response_json["result"].clone()).map_err(|err| {
+        color_eyre::Report::msg(format!("Failed to parse {} response: {}", method, err))
+    })
+}
+
+#[derive(Debug, serde::Deserialize)]
+struct LightClientExecutionProof {
+    outcome_proof: near_primitives::views::ExecutionOutcomeWithIdView,
+    outcome_root_proof: near_primitives::merkle::MerklePath,
+    block_header_lite: near_primitives::views::LightClientBlockLiteView,
+    block_proof: near_primitives::merkle::MerklePath,
+}
+
+pub fn input_light_client_head() -> near_primitives::hash::CryptoHash {
+    dialoguer::Input::new()
+        .with_prompt(
+            "Enter the hash of a final block you trust as the light client head (it must be newer than the transaction)",
+        )
+        .interact_text()
+        .unwrap()
+}
+
+/// How many times we poll for a final block newer than the transaction before giving up.
+const FINALITY_WAIT_MAX_ATTEMPTS: u32 = 60;
+const FINALITY_WAIT_INTERVAL: std::time::Duration = std::time::Duration::from_secs(1);
+
+fn light_client_block_hash(
+    block_header_lite: &near_primitives::views::LightClientBlockLiteView,
+) -> near_primitives::hash::CryptoHash {
+    let inner_lite_hash = near_primitives::hash::hash(
+        &near_primitives::block_header::BlockHeaderInnerLite::from(
+            block_header_lite.inner_lite.clone(),
+        )
+        .try_to_vec()
+        .expect("Block header is not expected to fail on serialization"),
+    );
+    near_primitives::merkle::combine_hash(
+        near_primitives::merkle::combine_hash(
+            inner_lite_hash,
+            block_header_lite.inner_rest_hash.clone(),
+        ),
+        block_header_lite.prev_block_hash.clone(),
+    )
+}
+
+/// Checks the merkle paths of a light client proof against the block merkle root of the trusted
+/// head. Returns the hash of the block whose outcome root commits to the outcome.
+fn verify_light_client_execution_proof(
+    proof: &LightClientExecutionProof,
+    transaction_hash: &near_primitives::hash::CryptoHash,
+    head_block_merkle_root: &near_primitives::hash::CryptoHash,
+) -> color_eyre::eyre::Result<near_primitives::hash::CryptoHash> {
+    if &proof.outcome_proof.id != transaction_hash {
+        return Err(color_eyre::Report::msg(format!(
+            "The RPC server returned a proof for {} instead of {}",
+            proof.outcome_proof.id, transaction_hash
+        )));
+    }
+
+    let outcome_hash = near_primitives::hash::hash(
+        &proof
+            .outcome_proof
+            .to_hashes()
+            .try_to_vec()
+            .expect("Hashes are not expected to fail on serialization"),
+    );
+    let shard_outcome_root =
+        near_primitives::merkle::compute_root_from_path(&proof.outcome_proof.proof, outcome_hash);
+    let block_outcome_root = near_primitives::merkle::compute_root_from_path(
+        &proof.outcome_root_proof,
+        near_primitives::hash::hash(shard_outcome_root.as_ref()),
+    );
+    if block_outcome_root != proof.block_header_lite.inner_lite.outcome_root {
+        return Err(color_eyre::Report::msg(
+            "Outcome merkle proof failed: the outcome is not included in the block outcome root",
+        ));
+    }
+
+    let outcome_block_hash = light_client_block_hash(&proof.block_header_lite);
+    if &near_primitives::merkle::compute_root_from_path(
+        &proof.block_proof,
+        outcome_block_hash.clone(),
+    ) != head_block_merkle_root
+    {
+        return Err(color_eyre::Report::msg(format!(
+            "Block merkle proof failed: block {} is not an ancestor of the light client head",
+            outcome_block_hash
+        )));
+    }
+    Ok(outcome_block_hash)
+}
+
+/// Proves that the transaction outcome is included in the chain which ends with
+/// `light_client_head` using the light client proof RPC, so a single RPC node does not
+/// have to be trusted. Returns the hash of the block whose outcome root commits to the outcome.
+pub async fn verify_transaction_outcome_with_light_client(
+    rpc_url: &str,
+    transaction_hash: &near_primitives::hash::CryptoHash,
+    sender_id: &str,
+    light_client_head: &near_primitives::hash::CryptoHash,
+) -> color_eyre::eyre::Result<near_primitives::hash::CryptoHash> {
+    let head_block = near_jsonrpc_client::new_client(rpc_url)
+        .block(near_primitives::types::BlockReference::BlockId(
+            near_primitives::types::BlockId::Hash(light_client_head.clone()),
+        ))
+        .await
+        .map_err(|err| {
+            color_eyre::Report::msg(format!(
+                "Failed to fetch the light client head block: {:?}",
+                err
+            ))
+        })?;
+    let head_block_merkle_root = head_block.header.block_merkle_root.clone();
+    let head_header = near_primitives::block_header::BlockHeader::from(head_block.header);
+    if head_header.hash() != light_client_head {
+        return Err(color_eyre::Report::msg(format!(
+            "The RPC server returned a header which does not hash to the trusted light client head {}",
+            light_client_head
+        )));
+    }
+
+    let proof: LightClientExecutionProof = call_json_rpc_method(
+        rpc_url,
+        "EXPERIMENTAL_light_client_proof",
+        serde_json::json!({
+            "type": "transaction",
+            "transaction_hash": transaction_hash,
+            "sender_id": sender_id,
+            "light_client_head": light_client_head,
+        }),
+    )
+    .await?;
+    verify_light_client_execution_proof(&proof, transaction_hash, &head_block_merkle_root)
+}
+
+/// Waits until the RPC server reports a final block above the block with `block_hash`, so the
+/// block can be proven against a final light client head. Returns the latest final block.
+async fn wait_for_final_block_after(
+    rpc_url: &str,
+    block_hash: &near_primitives::hash::CryptoHash,
+) -> color_eyre::eyre::Result<near_primitives::views::BlockView> {
+    let rpc_client = near_jsonrpc_client::new_client(rpc_url);
+    let block = rpc_client
+        .block(near_primitives::types::BlockReference::BlockId(
+            near_primitives::types::BlockId::Hash(block_hash.clone()),
+        ))
+        .await
+        .map_err(|err| {
+            color_eyre::Report::msg(format!("Failed to fetch block {}: {:?}", block_hash, err))
+        })?;
+    for _ in 0..FINALITY_WAIT_MAX_ATTEMPTS {
+        let final_block = rpc_client
+            .block(near_primitives::types::Finality::Final.into())
+            .await
+            .map_err(|err| {
+                color_eyre::Report::msg(format!("Failed to fetch the final block: {:?}", err))
+            })?;
+        if final_block.header.height > block.header.height {
+            return Ok(final_block);
+        }
+        actix::clock::sleep(FINALITY_WAIT_INTERVAL).await;
+    }
+    Err(color_eyre::Report::msg(format!(
+        "Block #{} did not become final in {} seconds",
+        block.header.height,
+        FINALITY_WAIT_MAX_ATTEMPTS as u64 * FINALITY_WAIT_INTERVAL.as_secs()
+    )))
+}
+
+/// Runs the light client verification for a transaction we have just sent. Without
+/// `light_client_head` the user is asked for a trusted head once the transaction is final.
+pub async fn verify_sent_transaction_outcome(
+    network_connection_config: &ConnectionConfig,
+    transaction_info: &near_primitives::views::FinalExecutionOutcomeView,
+    light_client_head: Option<near_primitives::hash::CryptoHash>,
+) -> crate::CliResult {
+    println!(
+        "
+Waiting for the transaction to become final to verify its outcome..."
+    );
+    let final_block = wait_for_final_block_after(
+        network_connection_config.rpc_url().as_str(),
+        &transaction_info.transaction_outcome.block_hash,
+    )
+    .await?;
+    let light_client_head = match light_client_head {
+        Some(light_client_head) => light_client_head,
+        None => {
+            println!(
+                "The latest final block reported by the RPC server is {} (#{}).",
+                final_block.header.hash, final_block.header.height
+            );
+            input_light_client_head()
+        }
+    };
+    let outcome_block_hash = verify_transaction_outcome_with_light_client(
+        network_connection_config.rpc_url().as_str(),
+        &transaction_info.transaction_outcome.id,
+        &transaction_info.transaction.signer_id,
+        &light_client_head,
+    )
+    .await
+    .map_err(|err| {
+        color_eyre::Report::msg(format!(
+            "{}\nThe light client head must be newer than the transaction; you can verify it later with `view transaction-status --verify`",
+            err
+        ))
+    })?;
+    println!(
+        "\nLight client verification: the outcome of {} is proven in block {} against the trusted head {}",
+        transaction_info.transaction_outcome.id, outcome_block_hash, light_client_head
+    );
+    Ok(())
+}
+
+/// Refund receipts are issued by the runtime on behalf of this predecessor.
+const SYSTEM_ACCOUNT_ID: &str = "system";
+
+pub fn action_summary(action: &near_primitives::views::ActionView) -> String {
+    match action {
+        near_primitives::views::ActionView::CreateAccount => "CreateAccount".to_string(),
+        near_primitives::views::ActionView::DeployContract { .. } => "DeployContract".to_string(),
+        near_primitives::views::ActionView::FunctionCall {
+            method_name,
+            gas,
+            deposit,
+            ..
+        } => format!(
+            "FunctionCall {}() with {} attached and {} prepaid",
+            method_name,
+            NearBalance::from_yoctonear(*deposit),
+            NearGas { inner: *gas }
+        ),
+        near_primitives::views::ActionView::Transfer { deposit } => {
+            format!("Transfer {}", NearBalance::from_yoctonear(*deposit))
+        }
+        near_primitives::views::ActionView::Stake { stake, public_key } => format!(
+            "Stake {} with {}",
+            NearBalance::from_yoctonear(*stake),
+            public_key
+        ),
+        near_primitives::views::ActionView::AddKey { public_key, .. } => {
+            format!("AddKey {}", public_key)
+        }
+        near_primitives::views::ActionView::DeleteKey { public_key } => {
+            format!("DeleteKey {}", public_key)
+        }
+        near_primitives::views::ActionView::DeleteAccount { beneficiary_id } => {
+            format!("DeleteAccount in favor of {}", beneficiary_id)
+        }
+    }
+}
+
+pub fn execution_status_summary(status: &near_primitives::views::ExecutionStatusView) -> String {
+    match status {
+        near_primitives::views::ExecutionStatusView::Unknown => "unknown".to_string(),
+        near_primitives::views::ExecutionStatusView::Failure(tx_execution_error) => {
+            format!("failure: {:?}", tx_execution_error)
+        }
+        near_primitives::views::ExecutionStatusView::SuccessValue(value) => {
+            match near_primitives::serialize::from_base64(value) {
+                Ok(value) if value.is_empty() => "success".to_string(),
+                Ok(value) => match String::from_utf8(value) {
+                    Ok(value) => format!("success, returned {}", value),
+                    Err(_) => format!("success, returned base64 {}", value),
+                },
+                Err(_) => format!("success, returned base64 {}", value),
+            }
+        }
+        near_primitives::views::ExecutionStatusView::SuccessReceiptId(receipt_id) => {
+            format!("success, the result is delegated to receipt {}", receipt_id)
+        }
+    }
+}
+
+fn is_refund_receipt(receipt: &near_primitives::views::ReceiptView) -> bool {
+    receipt.predecessor_id == SYSTEM_ACCOUNT_ID
+}
+
+/// NEAR tokens moved by the `Transfer` actions of a receipt.
+#[derive(Debug, Clone, PartialEq)]
+struct NearTransfer {
+    sender_id: String,
+    receiver_id: String,
+    amount: near_primitives::types::Balance,
+}
+
+fn receipt_transfer_amounts(
+    receipt: &near_primitives::views::ReceiptView,
+) -> Vec<near_primitives::types::Balance> {
+    match &receipt.receipt {
+        near_primitives::views::ReceiptEnumView::Action { actions, .. } => actions
+            .iter()
+            .filter_map(|action| match action {
+                near_primitives::views::ActionView::Transfer { deposit } => Some(*deposit),
+                _ => None,
+            })
+            .collect(),
+        near_primitives::views::ReceiptEnumView::Data { .. } => vec![],
+    }
+}
+
+/// The transfers made by the receipts of a transaction, refunds excluded.
+fn transaction_transfers(receipts: &[near_primitives::views::ReceiptView]) -> Vec<NearTransfer> {
+    receipts
+        .iter()
+        .filter(|receipt| !is_refund_receipt(receipt))
+        .flat_map(|receipt| {
+            receipt_transfer_amounts(receipt)
+                .into_iter()
+                .map(move |amount| NearTransfer {
+                    sender_id: receipt.predecessor_id.clone(),
+                    receiver_id: receipt.receiver_id.clone(),
+                    amount,
+                })
+        })
+        .collect()
+}
+
+/// The refunds of unused gas and of failed deposits issued for a transaction.
+fn transaction_refunds(receipts: &[near_primitives::views::ReceiptView]) -> Vec<NearTransfer> {
+    receipts
+        .iter()
+        .filter(|receipt| is_refund_receipt(receipt))
+        .map(|receipt| NearTransfer {
+            sender_id: receipt.predecessor_id.clone(),
+            receiver_id: receipt.receiver_id.clone(),
+            amount: receipt_transfer_amounts(receipt).into_iter().sum(),
+        })
+        .collect()
+}
+
+const NEP297_EVENT_LOG_PREFIX: &str = "EVENT_JSON:";
+
+/// A structured event emitted as an `EVENT_JSON:` log line (NEP-297).
+#[derive(Debug, Clone, PartialEq, serde::Serialize, serde::Deserialize)]
+pub struct NearEvent {
+    pub standard: String,
+    pub version: String,
+    pub event: String,
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub data: Option<serde_json::Value>,
+}
+
+pub fn parse_near_event(log: &str) -> Option<NearEvent> {
+    serde_json::from_str(log.strip_prefix(NEP297_EVENT_LOG_PREFIX)?.trim()).ok()
+}
+
+/// An event together with the receipt and the contract which emitted it.
+#[derive(Debug, Clone, PartialEq, serde::Serialize)]
+pub struct TransactionEvent {
+    pub receipt_id: near_primitives::hash::CryptoHash,
+    pub executor_id: String,
+    pub event: NearEvent,
+}
+
+/// Collects the events of the transaction in the order of its receipt outcomes.
+pub fn transaction_events(
+    transaction_info: &near_primitives::views::FinalExecutionOutcomeView,
+) -> Vec<TransactionEvent> {
+    transaction_info
+        .receipts_outcome
+        .iter()
+        .flat_map(|outcome| {
+            outcome
+                .outcome
+                .logs
+                .iter()
+                .filter_map(|log| parse_near_event(log))
+                .map(move |event| TransactionEvent {
+                    receipt_id: outcome.id.clone(),
+                    executor_id: outcome.outcome.executor_id.clone(),
+                    event,
+                })
+        })
+        .collect()
+}
+
+fn print_outcome_logs(logs: &[String], indent: &str) {
+    for log in logs {
+        match parse_near_event(log) {
+            Some(event) => {
+                println!(
+                    "{}event: {} (standard: {}, version: {})",
+                    indent, event.event, event.standard, event.version
+                );
+                if let Some(data) = event.data {
+                    for line in serde_json::to_string_pretty(&data).unwrap().lines() {
+                        println!("{}  {}", indent, line);
+                    }
+                }
+            }
+            None => println!("{}log: {}", indent, log),
+        }
+    }
+}
+
+fn print_receipt_tree(
+    receipt_ids: &[near_primitives::hash::CryptoHash],
+    predecessor_id: &str,
+    outcomes: &std::collections::HashMap<
+        near_primitives::hash::CryptoHash,
+        &near_primitives::views::ExecutionOutcomeWithIdView,
+    >,
+    receipts: &std::collections::HashMap<
+        near_primitives::hash::CryptoHash,
+        &near_primitives::views::ReceiptView,
+    >,
+    show_receipt_actions: bool,
+    indent: &str,
+) {
+    let receipt_ids = receipt_ids
+        .iter()
+        .filter(|receipt_id| match receipts.get(*receipt_id) {
+            Some(receipt) => !is_refund_receipt(receipt),
+            None => true,
+        })
+        .collect::<Vec<_>>();
+    for (index, receipt_id) in receipt_ids.iter().enumerate() {
+        let (branch, child_indent) = if index + 1 == receipt_ids.len() {
+            ("└─ ", "   ")
+        } else {
+            ("├─ ", "│  ")
+        };
+        let detail_indent = format!("{}{}", indent, child_indent);
+        let receipt = receipts.get(*receipt_id);
+        let outcome = match outcomes.get(*receipt_id) {
+            Some(outcome) => outcome,
+            None => {
+                println!(
+                    "{}{}{} (the outcome is not available yet)",
+                    indent, branch, receipt_id
+                );
+                continue;
+            }
+        };
+        println!(
+            "{}{}{} {} -> {} [{}]",
+            indent,
+            branch,
+            receipt_id,
+            receipt
+                .map(|receipt| receipt.predecessor_id.as_str())
+                .unwrap_or(predecessor_id),
+            outcome.outcome.executor_id,
+            execution_status_summary(&outcome.outcome.status)
+        );
+        if let (true, Some(near_primitives::views::ReceiptEnumView::Action { actions, .. })) = (
+            show_receipt_actions,
+            receipt.map(|receipt| &receipt.receipt),
+        ) {
+            for action in actions {
+                println!("{}action: {}", detail_indent, action_summary(action));
+            }
+        }
+        println!(
+            "{}gas burnt: {}, tokens burnt: {}",
+            detail_indent,
+            NearGas {
+                inner: outcome.outcome.gas_burnt
+            },
+            NearBalance::from_yoctonear(outcome.outcome.tokens_burnt)
+        );
+        print_outcome_logs(&outcome.outcome.logs, &detail_indent);
+        print_receipt_tree(
+            &outcome.outcome.receipt_ids,
+            &outcome.outcome.executor_id,
+            outcomes,
+            receipts,
+            show_receipt_actions,
+            &detail_indent,
+        );
+    }
+}
+
+/// Prints the transaction summary, the tree of its receipts and the transfers it caused.
+/// `receipts` come from `EXPERIMENTAL_tx_status`; refunds are told apart by their predecessor.
+pub fn print_transaction_status(
+    transaction_info: &near_primitives::views::FinalExecutionOutcomeView,
+    receipts: &[near_primitives::views::ReceiptView],
+    show_receipt_actions: bool,
+) {
+    let transaction = &transaction_info.transaction;
+    let outcomes = transaction_info
+        .receipts_outcome
+        .iter()
+        .map(|outcome| (outcome.id.clone(), outcome))
+        .collect::<std::collections::HashMap<_, _>>();
+    let receipts_by_id = receipts
+        .iter()
+        .map(|receipt| (receipt.receipt_id.clone(), receipt))
+        .collect::<std::collections::HashMap<_, _>>();
+
+    println!("\nTransaction {}", transaction.hash);
+    println!(
+        "  signer:   {} ({})",
+        transaction.signer_id, transaction.public_key
+    );
+    println!("  receiver: {}", transaction.receiver_id);
+    println!("  nonce:    {}", transaction.nonce);
+    for action in transaction.actions.iter() {
+        println!("  action:   {}", action_summary(action));
+    }
+    let status = match &transaction_info.status {
+        near_primitives::views::FinalExecutionStatus::NotStarted => "not started".to_string(),
+        near_primitives::views::FinalExecutionStatus::Started => "started".to_string(),
+        near_primitives::views::FinalExecutionStatus::Failure(tx_execution_error) => {
+            format!("failure: {:?}", tx_execution_error)
+        }
+        near_primitives::views::FinalExecutionStatus::SuccessValue(value) => {
+            execution_status_summary(&near_primitives::views::ExecutionStatusView::SuccessValue(
+                value.clone(),
+            ))
+        }
+    };
+    println!("  status:   {}", status);
+    println!(
+        "  gas burnt to convert the transaction: {}, tokens burnt: {}",
+        NearGas {
+            inner: transaction_info.transaction_outcome.outcome.gas_burnt
+        },
+        NearBalance::from_yoctonear(transaction_info.transaction_outcome.outcome.tokens_burnt)
+    );
+
+    println!("\nReceipts:");
+    print_receipt_tree(
+        &transaction_info.transaction_outcome.outcome.receipt_ids,
+        &transaction.signer_id,
+        &outcomes,
+        &receipts_by_id,
+        show_receipt_actions,
+        "",
+    );
+
+    // The transfers and refunds are only known from the receipts of EXPERIMENTAL_tx_status.
+    if show_receipt_actions {
+        println!("\nTransfers:");
+        for transfer in transaction_transfers(receipts) {
+            println!(
+                "  {} -> {}: {}",
+                transfer.sender_id,
+                transfer.receiver_id,
+                NearBalance::from_yoctonear(transfer.amount)
+            );
+        }
+
+        println!("\nRefunds:");
+        for refund in transaction_refunds(receipts) {
+            println!(
+                "  {} -> {}: {}",
+                refund.sender_id,
+                refund.receiver_id,
+                NearBalance::from_yoctonear(refund.amount)
+            );
+        }
+    }
+}
+
+struct ReceiptGraphNode {
+    id: String,
+    lines: Vec<String>,
+    is_failure: bool,
+}
+
+fn receipt_graph_node_status(status: &near_primitives::views::ExecutionStatusView) -> &str {
+    match status {
+        near_primitives::views::ExecutionStatusView::Unknown => "UNKNOWN",
+        near_primitives::views::ExecutionStatusView::Failure(_) => "FAILURE",
+        near_primitives::views::ExecutionStatusView::SuccessValue(_)
+        | near_primitives::views::ExecutionStatusView::SuccessReceiptId(_) => "SUCCESS",
+    }
+}
+
+fn receipt_graph_node_action(actions: &[near_primitives::views::ActionView]) -> String {
+    actions
+        .iter()
+        .map(|action| match action {
+            near_primitives::views::ActionView::FunctionCall { method_name, .. } => {
+                format!("{}()", method_name)
+            }
+            action => action_summary(action),
+        })
+        .collect::<Vec<_>>()
+        .join(", ")
+}
+
+/// Builds the receipt DAG of a transaction (edges follow the outcome `receipt_ids`) and renders
+/// it as Graphviz DOT or Mermaid, so it can be attached to incident reports.
+pub fn transaction_receipt_graph(
+    transaction_info: &near_primitives::views::FinalExecutionOutcomeView,
+    receipts: &[near_primitives::views::ReceiptView],
+    graph_format: &GraphFormat,
+) -> String {
+    let receipts_by_id = receipts
+        .iter()
+        .map(|receipt| (receipt.receipt_id.clone(), receipt))
+        .collect::<std::collections::HashMap<_, _>>();
+    let transaction_outcome = &transaction_info.transaction_outcome;
+    let mut nodes = vec![ReceiptGraphNode {
+        id: format!("tx_{}", transaction_outcome.id),
+        lines: vec![
+            format!(
+                "{} -> {}",
+                transaction_info.transaction.signer_id, transaction_info.transaction.receiver_id
+            ),
+            receipt_graph_node_action(&transaction_info.transaction.actions),
+            format!(
+                "gas burnt: {}",
+                NearGas {
+                    inner: transaction_outcome.outcome.gas_burnt
+                }
+            ),
+            receipt_graph_node_status(&transaction_outcome.outcome.status).to_string(),
+        ],
+        is_failure: matches!(
+            transaction_outcome.outcome.status,
+            near_primitives::views::ExecutionStatusView::Failure(_)
+        ),
+    }];
+    let mut edges = transaction_outcome
+        .outcome
+        .receipt_ids
+        .iter()
+        .map(|receipt_id| (nodes[0].id.clone(), format!("r_{}", receipt_id)))
+        .collect::<Vec<_>>();
+    for outcome in transaction_info.receipts_outcome.iter() {
+        let receipt = receipts_by_id.get(&outcome.id);
+        let action = match receipt {
+            Some(receipt) if is_refund_receipt(receipt) => "refund".to_string(),
+            Some(receipt) => match &receipt.receipt {
+                near_primitives::views::ReceiptEnumView::Action { actions, .. } => {
+                    receipt_graph_node_action(actions)
+                }
+                near_primitives::views::ReceiptEnumView::Data { .. } => "data".to_string(),
+            },
+            None => "the receipt is not available".to_string(),
+        };
+        nodes.push(ReceiptGraphNode {
+            id: format!("r_{}", outcome.id),
+            lines: vec![
+                outcome.outcome.executor_id.clone(),
+                action,
+                format!(
+                    "gas burnt: {}",
+                    NearGas {
+                        inner: outcome.outcome.gas_burnt
+                    }
+                ),
+                receipt_graph_node_status(&outcome.outcome.status).to_string(),
+            ],
+            is_failure: matches!(
+                outcome.outcome.status,
+                near_primitives::views::ExecutionStatusView::Failure(_)
+            ),
+        });
+        edges.extend(
+            outcome
+                .outcome
+                .receipt_ids
+                .iter()
+                .map(|receipt_id| (format!("r_{}", outcome.id), format!("r_{}", receipt_id))),
+        );
+    }
+
+    let mut graph = String::new();
+    match graph_format {
+        GraphFormat::Dot => {
+            graph.push_str("digraph transaction {\n    node [shape=box];\n");
+            for node in nodes.iter() {
+                graph.push_str(&format!(
+                    "    \"{}\" [label=\"{}\", color={}];\n",
+                    node.id,
+                    node.lines
+                        .iter()
+                        .map(|line| line.replace('\\', "\\\\").replace('"', "\\\""))
+                        .collect::<Vec<_>>()
+                        .join("\\n"),
+                    if node.is_failure { "red" } else { "black" }
+                ));
             }
-        },
+            for (from, to) in edges.iter() {
+                graph.push_str(&format!("    \"{}\" -> \"{}\";\n", from, to));
+            }
+            graph.push_str("}\n");
+        }
+        GraphFormat::Mermaid => {
+            graph.push_str("graph TD\n");
+            for node in nodes.iter() {
+                graph.push_str(&format!(
+                    "    {}[\"{}\"]\n",
+                    node.id,
+                    node.lines
+                        .iter()
+                        .map(|line| line.replace('"', "#quot;"))
+                        .collect::<Vec<_>>()
+                        .join("<br/>")
+                ));
+                if node.is_failure {
+                    graph.push_str(&format!("    style {} stroke:#f00\n", node.id));
+                }
+            }
+            for (from, to) in edges.iter() {
+                graph.push_str(&format!("    {} --> {}\n", from, to));
+            }
+        }
     }
+    graph
 }
 
 #[cfg(test)]
@@ -806,4 +4626,920 @@ mod tests {
             Err("Near Gas: invalid digit found in string".to_string())
         );
     }
+
+    fn encode_leaf_node(key: &[u8], value: &[u8]) -> Vec<u8> {
+        let mut encoded_key = vec![0x20];
+        encoded_key.extend(key);
+        let mut node = vec![TRIE_LEAF_NODE];
+        node.extend(&(encoded_key.len() as u32).to_le_bytes());
+        node.extend(&encoded_key);
+        node.extend(&(value.len() as u32).to_le_bytes());
+        node.extend(near_primitives::hash::hash(value).as_ref());
+        node.extend(&0u64.to_le_bytes());
+        node
+    }
+
+    #[test]
+    fn decode_trie_key_nibbles_odd_and_even() {
+        assert_eq!(
+            decode_trie_key_nibbles(&[0x3a, 0xbc]).unwrap(),
+            vec![0x0a, 0x0b, 0x0c]
+        );
+        assert_eq!(
+            decode_trie_key_nibbles(&[0x00, 0xbc]).unwrap(),
+            vec![0x0b, 0x0c]
+        );
+    }
+    #[test]
+    fn lookup_value_hash_in_proof_single_leaf() {
+        let leaf = encode_leaf_node(b"key", b"value");
+        let state_root = near_primitives::hash::hash(&leaf);
+        let mut proof_nodes = std::collections::HashMap::new();
+        proof_nodes.insert(state_root.clone(), leaf);
+        assert_eq!(
+            lookup_value_hash_in_proof(&proof_nodes, &state_root, b"key").unwrap(),
+            Some(near_primitives::hash::hash(b"value"))
+        );
+        assert_eq!(
+            lookup_value_hash_in_proof(&proof_nodes, &state_root, b"other").unwrap(),
+            None
+        );
+    }
+    #[test]
+    fn lookup_value_hash_in_proof_missing_node() {
+        let proof_nodes = std::collections::HashMap::new();
+        let state_root = near_primitives::hash::hash(b"unknown node");
+        assert!(lookup_value_hash_in_proof(&proof_nodes, &state_root, b"key").is_err());
+    }
+
+    fn light_client_execution_proof(
+        transaction_hash: &near_primitives::hash::CryptoHash,
+    ) -> LightClientExecutionProof {
+        let mut proof: LightClientExecutionProof = serde_json::from_value(serde_json::json!({
+            "outcome_proof": {
+                "proof": [],
+                "block_hash": near_primitives::hash::hash(b"outcome block"),
+                "id": transaction_hash,
+                "outcome": {
+                    "logs": [],
+                    "receipt_ids": [near_primitives::hash::hash(b"receipt")],
+                    "gas_burnt": 2428135649664u64,
+                    "tokens_burnt": "242813564966400000000",
+                    "executor_id": "alice.near",
+                    "status": { "SuccessReceiptId": near_primitives::hash::hash(b"receipt") },
+                    "metadata": { "version": 1, "gas_profile": null },
+                },
+            },
+            "outcome_root_proof": [],
+            "block_header_lite": {
+                "prev_block_hash": near_primitives::hash::hash(b"prev block"),
+                "inner_rest_hash": near_primitives::hash::hash(b"inner rest"),
+                "inner_lite": {
+                    "height": 42,
+                    "epoch_id": near_primitives::hash::CryptoHash::default(),
+                    "next_epoch_id": near_primitives::hash::CryptoHash::default(),
+                    "prev_state_root": near_primitives::hash::hash(b"state"),
+                    "outcome_root": near_primitives::hash::CryptoHash::default(),
+                    "timestamp": 1618000000000000000u64,
+                    "timestamp_nanosec": "1618000000000000000",
+                    "next_bp_hash": near_primitives::hash::hash(b"next bp"),
+                    "block_merkle_root": near_primitives::hash::hash(b"block merkle"),
+                },
+            },
+            "block_proof": [],
+        }))
+        .unwrap();
+        let outcome_hash =
+            near_primitives::hash::hash(&proof.outcome_proof.to_hashes().try_to_vec().unwrap());
+        proof.block_header_lite.inner_lite.outcome_root =
+            near_primitives::hash::hash(outcome_hash.as_ref());
+        proof
+    }
+
+    #[test]
+    fn light_client_execution_proof_valid() {
+        let transaction_hash = near_primitives::hash::hash(b"transaction");
+        let proof = light_client_execution_proof(&transaction_hash);
+        let block_hash = light_client_block_hash(&proof.block_header_lite);
+        assert_eq!(
+            verify_light_client_execution_proof(&proof, &transaction_hash, &block_hash).unwrap(),
+            block_hash
+        );
+    }
+
+    #[test]
+    fn light_client_execution_proof_invalid() {
+        let transaction_hash = near_primitives::hash::hash(b"transaction");
+        let proof = light_client_execution_proof(&transaction_hash);
+        let block_hash = light_client_block_hash(&proof.block_header_lite);
+        assert!(verify_light_client_execution_proof(
+            &proof,
+            &near_primitives::hash::hash(b"other transaction"),
+            &block_hash
+        )
+        .is_err());
+        assert!(verify_light_client_execution_proof(
+            &proof,
+            &transaction_hash,
+            &near_primitives::hash::hash(b"other head")
+        )
+        .is_err());
+
+        let mut tampered_proof = light_client_execution_proof(&transaction_hash);
+        tampered_proof
+            .outcome_proof
+            .outcome
+            .logs
+            .push("forged".to_string());
+        assert!(verify_light_client_execution_proof(
+            &tampered_proof,
+            &transaction_hash,
+            &block_hash
+        )
+        .is_err());
+    }
+
+    #[test]
+    fn borsh_type_from_str_struct() {
+        assert_eq!(
+            BorshType::from_str("{receiver_id: string, amount: u128, memo: option<string>}")
+                .unwrap(),
+            BorshType::Struct(vec![
+                ("receiver_id".to_string(), BorshType::String),
+                (
+                    "amount".to_string(),
+                    BorshType::Integer {
+                        signed: false,
+                        size: 16
+                    }
+                ),
+                (
+                    "memo".to_string(),
+                    BorshType::Option(Box::new(BorshType::String))
+                ),
+            ])
+        );
+    }
+    #[test]
+    fn borsh_type_from_str_unknown_type() {
+        assert_eq!(
+            BorshType::from_str("vec<u7>"),
+            Err("Borsh type: unknown type 'u7'".to_string())
+        );
+    }
+    #[test]
+    fn borsh_type_encode_struct() {
+        let borsh_type =
+            BorshType::from_str("{receiver_id: string, amount: u128, memo: option<string>}")
+                .unwrap();
+        let mut expected = vec![3, 0, 0, 0, b'b', b'o', b'b'];
+        expected.extend(&100u128.to_le_bytes());
+        expected.push(0);
+        assert_eq!(
+            borsh_type
+                .encode(&serde_json::json!({"receiver_id": "bob", "amount": "100"}))
+                .unwrap(),
+            expected
+        );
+    }
+    #[test]
+    fn borsh_type_encode_integer_overflow() {
+        assert_eq!(
+            BorshType::from_str("u8")
+                .unwrap()
+                .encode(&serde_json::json!(256)),
+            Err("Borsh: 256 does not fit into u8".to_string())
+        );
+        assert_eq!(
+            BorshType::from_str("(i16, bool)")
+                .unwrap()
+                .encode(&serde_json::json!([-2, true])),
+            Ok(vec![0xfe, 0xff, 1])
+        );
+    }
+    #[test]
+    fn borsh_type_decode_struct() {
+        let borsh_type = BorshType::from_str("{owner: string, balance: u128, rank: i8}").unwrap();
+        let mut bytes = vec![3, 0, 0, 0, b'b', b'o', b'b'];
+        bytes.extend(&100u128.to_le_bytes());
+        bytes.push(0xff);
+        assert_eq!(
+            borsh_type.decode(&bytes).unwrap(),
+            serde_json::json!({"owner": "bob", "balance": "100", "rank": -1})
+        );
+        bytes.push(0);
+        assert_eq!(
+            borsh_type.decode(&bytes),
+            Err("Borsh: 1 bytes are left after decoding".to_string())
+        );
+    }
+    #[test]
+    fn format_call_function_result_fallback() {
+        assert_eq!(
+            format_call_function_result(b"{\"a\":1}", &ResultFormat::Json),
+            "{\n  \"a\": 1\n}"
+        );
+        assert_eq!(
+            format_call_function_result(&[1, 2], &ResultFormat::from_str("hex").unwrap()),
+            "0102"
+        );
+        assert_eq!(
+            format_call_function_result(&[0xff], &ResultFormat::Json),
+            "(the result is not a valid JSON: expected value at line 1 column 1, shown as base64)\n/w=="
+        );
+    }
+    fn action_receipt(
+        receipt_id: &[u8],
+        predecessor_id: &str,
+        receiver_id: &str,
+        actions: serde_json::Value,
+    ) -> near_primitives::views::ReceiptView {
+        serde_json::from_value(serde_json::json!({
+            "predecessor_id": predecessor_id,
+            "receiver_id": receiver_id,
+            "receipt_id": near_primitives::hash::hash(receipt_id),
+            "receipt": {
+                "Action": {
+                    "signer_id": "alice.near",
+                    "signer_public_key": "ed25519:6E8sCci9badyRkXb3JoRpBj5p8C6Tw41ELDZoiihKEtp",
+                    "gas_price": "100000000",
+                    "output_data_receivers": [],
+                    "input_data_ids": [],
+                    "actions": actions,
+                }
+            },
+        }))
+        .unwrap()
+    }
+
+    #[test]
+    fn transaction_transfers_and_refunds() {
+        let receipts = vec![
+            action_receipt(
+                b"transfer",
+                "alice.near",
+                "bob.near",
+                serde_json::json!([{ "Transfer": { "deposit": "2000000000000000000000000" } }]),
+            ),
+            action_receipt(
+                b"call",
+                "bob.near",
+                "carol.near",
+                serde_json::json!([
+                    {
+                        "FunctionCall": {
+                            "method_name": "deposit",
+                            "args": "",
+                            "gas": 5000000000000u64,
+                            "deposit": "1000000000000000000000000",
+                        }
+                    },
+                    { "Transfer": { "deposit": "1" } },
+                ]),
+            ),
+            action_receipt(
+                b"refund",
+                SYSTEM_ACCOUNT_ID,
+                "alice.near",
+                serde_json::json!([{ "Transfer": { "deposit": "300" } }]),
+            ),
+        ];
+        assert!(!is_refund_receipt(&receipts[0]));
+        assert!(is_refund_receipt(&receipts[2]));
+        assert_eq!(
+            transaction_transfers(&receipts),
+            vec![
+                NearTransfer {
+                    sender_id: "alice.near".to_string(),
+                    receiver_id: "bob.near".to_string(),
+                    amount: 2_000_000_000_000_000_000_000_000,
+                },
+                NearTransfer {
+                    sender_id: "bob.near".to_string(),
+                    receiver_id: "carol.near".to_string(),
+                    amount: 1,
+                },
+            ]
+        );
+        assert_eq!(
+            transaction_refunds(&receipts),
+            vec![NearTransfer {
+                sender_id: SYSTEM_ACCOUNT_ID.to_string(),
+                receiver_id: "alice.near".to_string(),
+                amount: 300,
+            }]
+        );
+    }
+
+    #[test]
+    fn transaction_status_summaries() {
+        assert_eq!(
+            action_summary(&near_primitives::views::ActionView::FunctionCall {
+                method_name: "ft_transfer".to_string(),
+                args: "".to_string(),
+                gas: 30_000_000_000_000,
+                deposit: 1,
+            }),
+            "FunctionCall ft_transfer() with less than 0.001 NEAR (1 yoctoNEAR) attached and 30 TeraGas prepaid"
+        );
+        assert_eq!(
+            action_summary(&near_primitives::views::ActionView::Transfer {
+                deposit: 1_500_000_000_000_000_000_000_000,
+            }),
+            "Transfer 1.500 NEAR"
+        );
+        assert_eq!(
+            execution_status_summary(&near_primitives::views::ExecutionStatusView::SuccessValue(
+                near_primitives::serialize::to_base64(b"\"42\"")
+            )),
+            "success, returned \"42\""
+        );
+        assert_eq!(
+            execution_status_summary(&near_primitives::views::ExecutionStatusView::SuccessValue(
+                "".to_string()
+            )),
+            "success"
+        );
+    }
+
+    fn execution_outcome(
+        id: &near_primitives::hash::CryptoHash,
+        executor_id: &str,
+        receipt_ids: Vec<near_primitives::hash::CryptoHash>,
+        status: serde_json::Value,
+    ) -> serde_json::Value {
+        serde_json::json!({
+            "proof": [],
+            "block_hash": near_primitives::hash::hash(b"block"),
+            "id": id,
+            "outcome": {
+                "logs": [],
+                "receipt_ids": receipt_ids,
+                "gas_burnt": 2428000000000u64,
+                "tokens_burnt": "0",
+                "executor_id": executor_id,
+                "status": status,
+                "metadata": { "version": 1, "gas_profile": null },
+            },
+        })
+    }
+
+    fn transaction_with_failed_receipt(
+        transaction_status: serde_json::Value,
+    ) -> (
+        near_primitives::views::FinalExecutionOutcomeView,
+        Vec<near_primitives::views::ReceiptView>,
+    ) {
+        let transaction_hash = near_primitives::hash::hash(b"transaction");
+        let call_receipt_id = near_primitives::hash::hash(b"call");
+        let refund_receipt_id = near_primitives::hash::hash(b"refund");
+        let transaction_info = serde_json::from_value(serde_json::json!({
+            "status": { "Failure": { "InvalidTxError": "InvalidSignature" } },
+            "transaction": {
+                "signer_id": "alice.near",
+                "public_key": near_crypto::PublicKey::empty(near_crypto::KeyType::ED25519),
+                "nonce": 1,
+                "receiver_id": "bob.near",
+                "actions": [{
+                    "FunctionCall": {
+                        "method_name": "deposit",
+                        "args": "",
+                        "gas": 5000000000000u64,
+                        "deposit": "0",
+                    }
+                }],
+                "signature": near_crypto::Signature::empty(near_crypto::KeyType::ED25519),
+                "hash": transaction_hash,
+            },
+            "transaction_outcome": execution_outcome(
+                &transaction_hash,
+                "alice.near",
+                vec![call_receipt_id],
+                transaction_status,
+            ),
+            "receipts_outcome": [
+                execution_outcome(
+                    &call_receipt_id,
+                    "bob.near",
+                    vec![refund_receipt_id],
+                    serde_json::json!({
+                        "Failure": {
+                            "ActionError": {
+                                "index": 0,
+                                "kind": { "AccountDoesNotExist": { "account_id": "bob.near" } },
+                            }
+                        }
+                    }),
+                ),
+                execution_outcome(
+                    &refund_receipt_id,
+                    "alice.near",
+                    vec![],
+                    serde_json::json!({ "SuccessValue": "" }),
+                ),
+            ],
+        }))
+        .unwrap();
+        let receipts = vec![
+            action_receipt(
+                b"call",
+                "alice.near",
+                "bob.near",
+                serde_json::json!([{
+                    "FunctionCall": {
+                        "method_name": "deposit",
+                        "args": "",
+                        "gas": 5000000000000u64,
+                        "deposit": "0",
+                    }
+                }]),
+            ),
+            action_receipt(
+                b"refund",
+                SYSTEM_ACCOUNT_ID,
+                "alice.near",
+                serde_json::json!([{ "Transfer": { "deposit": "300" } }]),
+            ),
+        ];
+        (transaction_info, receipts)
+    }
+
+    #[test]
+    fn transaction_receipt_graph_dot() {
+        let (transaction_info, receipts) = transaction_with_failed_receipt(
+            serde_json::json!({ "SuccessReceiptId": near_primitives::hash::hash(b"call") }),
+        );
+        let transaction_node = format!("tx_{}", near_primitives::hash::hash(b"transaction"));
+        let call_node = format!("r_{}", near_primitives::hash::hash(b"call"));
+        let refund_node = format!("r_{}", near_primitives::hash::hash(b"refund"));
+        let graph = transaction_receipt_graph(&transaction_info, &receipts, &GraphFormat::Dot);
+        assert!(graph.starts_with("digraph transaction {\n"));
+        assert!(graph.contains(&format!(
+            "    \"{}\" [label=\"alice.near -> bob.near\\ndeposit()\\ngas burnt: 2 TeraGas\\nSUCCESS\", color=black];\n",
+            transaction_node
+        )));
+        assert!(graph.contains(&format!(
+            "    \"{}\" [label=\"bob.near\\ndeposit()\\ngas burnt: 2 TeraGas\\nFAILURE\", color=red];\n",
+            call_node
+        )));
+        assert!(graph.contains(&format!(
+            "    \"{}\" [label=\"alice.near\\nrefund\\ngas burnt: 2 TeraGas\\nSUCCESS\", color=black];\n",
+            refund_node
+        )));
+        assert!(graph.contains(&format!(
+            "    \"{}\" -> \"{}\";\n",
+            transaction_node, call_node
+        )));
+        assert!(graph.contains(&format!("    \"{}\" -> \"{}\";\n", call_node, refund_node)));
+        assert!(graph.ends_with("}\n"));
+    }
+
+    #[test]
+    fn transaction_receipt_graph_mermaid() {
+        let (transaction_info, receipts) = transaction_with_failed_receipt(serde_json::json!({
+            "Failure": { "InvalidTxError": "InvalidSignature" }
+        }));
+        let transaction_node = format!("tx_{}", near_primitives::hash::hash(b"transaction"));
+        let call_node = format!("r_{}", near_primitives::hash::hash(b"call"));
+        let refund_node = format!("r_{}", near_primitives::hash::hash(b"refund"));
+        let graph = transaction_receipt_graph(&transaction_info, &receipts, &GraphFormat::Mermaid);
+        assert!(graph.starts_with("graph TD\n"));
+        assert!(graph.contains(&format!(
+            "    {}[\"alice.near -> bob.near<br/>deposit()<br/>gas burnt: 2 TeraGas<br/>FAILURE\"]\n    style {} stroke:#f00\n",
+            transaction_node, transaction_node
+        )));
+        assert!(graph.contains(&format!("    style {} stroke:#f00\n", call_node)));
+        assert!(!graph.contains(&format!("    style {} stroke:#f00\n", refund_node)));
+        assert!(graph.contains(&format!("    {} --> {}\n", transaction_node, call_node)));
+        assert!(graph.contains(&format!("    {} --> {}\n", call_node, refund_node)));
+    }
+
+    #[test]
+    fn parse_near_event_nep141_transfer() {
+        assert_eq!(
+            parse_near_event(
+                r#"EVENT_JSON:{"standard":"nep141","version":"1.0.0","event":"ft_transfer","data":[{"old_owner_id":"alice.near","new_owner_id":"bob.near","amount":"100"}]}"#
+            ),
+            Some(NearEvent {
+                standard: "nep141".to_string(),
+                version: "1.0.0".to_string(),
+                event: "ft_transfer".to_string(),
+                data: Some(serde_json::json!([{
+                    "old_owner_id": "alice.near",
+                    "new_owner_id": "bob.near",
+                    "amount": "100"
+                }])),
+            })
+        );
+    }
+    #[test]
+    fn transaction_events_with_emitter() {
+        let (mut transaction_info, _) = transaction_with_failed_receipt(
+            serde_json::json!({ "SuccessReceiptId": near_primitives::hash::hash(b"call") }),
+        );
+        transaction_info.receipts_outcome[0].outcome.logs = vec![
+            "Deposit".to_string(),
+            r#"EVENT_JSON:{"standard":"nep141","version":"1.0.0","event":"ft_burn","data":[{"owner_id":"alice.near","amount":"1"}]}"#.to_string(),
+        ];
+        assert_eq!(
+            serde_json::to_value(transaction_events(&transaction_info)).unwrap(),
+            serde_json::json!([{
+                "receipt_id": near_primitives::hash::hash(b"call"),
+                "executor_id": "bob.near",
+                "event": {
+                    "standard": "nep141",
+                    "version": "1.0.0",
+                    "event": "ft_burn",
+                    "data": [{ "owner_id": "alice.near", "amount": "1" }],
+                },
+            }])
+        );
+    }
+
+    #[test]
+    fn parse_near_event_plain_log() {
+        assert_eq!(parse_near_event("Transfer 100 from alice.near"), None);
+        assert_eq!(parse_near_event("EVENT_JSON:{not a json}"), None);
+    }
+    fn encode_wasm_name(buf: &mut Vec<u8>, name: &str) {
+        buf.push(name.len() as u8);
+        buf.extend_from_slice(name.as_bytes());
+    }
+
+    fn encode_wasm_section(code: &mut Vec<u8>, section_id: u8, content: &[u8]) {
+        code.push(section_id);
+        code.push(content.len() as u8);
+        code.extend_from_slice(content);
+    }
+
+    /// A module with the given function imports and exports and a "near-abi" custom section.
+    fn encode_wasm_module(imports: &[(&str, &str)], exports: &[&str]) -> Vec<u8> {
+        let mut code = b"\0asm\x01\0\0\0".to_vec();
+        let mut custom = vec![];
+        encode_wasm_name(&mut custom, "near-abi");
+        custom.extend_from_slice(b"{}");
+        encode_wasm_section(&mut code, 0, &custom);
+        let mut import_section = vec![imports.len() as u8];
+        for (module, name) in imports {
+            encode_wasm_name(&mut import_section, module);
+            encode_wasm_name(&mut import_section, name);
+            import_section.extend_from_slice(&[0, 0]);
+        }
+        encode_wasm_section(&mut code, 2, &import_section);
+        let mut export_section = vec![exports.len() as u8];
+        for (index, name) in exports.iter().enumerate() {
+            encode_wasm_name(&mut export_section, name);
+            export_section.extend_from_slice(&[0, index as u8]);
+        }
+        encode_wasm_section(&mut code, 7, &export_section);
+        code
+    }
+
+    #[test]
+    fn wasm_module_info_parse() {
+        let code = encode_wasm_module(&[("env", "input")], &["get"]);
+        let module = WasmModuleInfo::parse(&code).unwrap();
+        assert_eq!(module.version, 1);
+        assert_eq!(module.exported_functions(), vec!["get"]);
+        assert_eq!(module.imports.len(), 1);
+        assert_eq!(module.imports[0].module, "env");
+        assert_eq!(module.imports[0].name, "input");
+        assert_eq!(module.custom_section("near-abi"), Some(&b"{}"[..]));
+        assert!(WasmModuleInfo::parse(b"not a wasm").is_err());
+    }
+
+    #[test]
+    fn check_contract_code_init_method_and_imports() {
+        let code = encode_wasm_module(
+            &[("env", "input"), ("env", "fd_write"), ("wasi", "proc_exit")],
+            &["new", "get"],
+        );
+        let contract_code_check = check_contract_code(&code, 1024, Some("new")).unwrap();
+        assert_eq!(
+            contract_code_check.unknown_imports,
+            vec![
+                "env.fd_write (function)".to_string(),
+                "wasi.proc_exit (function)".to_string()
+            ]
+        );
+        assert_eq!(
+            check_contract_code(&code, 1024, Some("init")).unwrap_err(),
+            "the init method 'init' is not exported by the contract"
+        );
+        assert!(check_contract_code(&code, 16, None).is_err());
+    }
+
+    #[test]
+    fn describe_wasm_custom_section_producers_and_text() {
+        let mut producers = vec![1];
+        encode_wasm_name(&mut producers, "processed-by");
+        producers.push(1);
+        encode_wasm_name(&mut producers, "rustc");
+        encode_wasm_name(&mut producers, "1.53.0");
+        assert_eq!(
+            describe_wasm_custom_section("producers", &producers),
+            "processed-by: rustc 1.53.0"
+        );
+        assert_eq!(
+            describe_wasm_custom_section("contract-metadata", b"{\"version\":\"1.0.0\"}"),
+            "{\"version\":\"1.0.0\"}"
+        );
+        assert_eq!(describe_wasm_custom_section("name", &[0, 1, 2]), "3 bytes");
+    }
+
+    #[test]
+    fn exported_functions_diff() {
+        let old_module =
+            WasmModuleInfo::parse(&encode_wasm_module(&[], &["get_num", "increment"])).unwrap();
+        let new_module =
+            WasmModuleInfo::parse(&encode_wasm_module(&[], &["increment", "get_count"])).unwrap();
+        assert_eq!(
+            ExportedFunctionsDiff::new(&old_module, &new_module),
+            ExportedFunctionsDiff {
+                added: vec!["get_count".to_string()],
+                removed: vec!["get_num".to_string()],
+                unchanged: vec!["increment".to_string()],
+            }
+        );
+    }
+
+    #[test]
+    fn contract_code_matches_account_code_hash() {
+        let code = encode_wasm_module(&[], &["get"]);
+        let account_view: near_primitives::views::AccountView =
+            serde_json::from_value(serde_json::json!({
+                "amount": "1000000000000000000000000",
+                "locked": "0",
+                "code_hash": near_primitives::hash::hash(&code).to_string(),
+                "storage_usage": 182,
+                "storage_paid_at": 0
+            }))
+            .unwrap();
+        assert!(contract_code_matches_code_hash(
+            &code,
+            &account_view.code_hash
+        ));
+        assert!(!contract_code_matches_code_hash(
+            &encode_wasm_module(&[], &["get", "set"]),
+            &account_view.code_hash
+        ));
+        assert!(!contract_code_matches_code_hash(
+            &[],
+            &near_primitives::hash::CryptoHash::default()
+        ));
+    }
+
+    fn ft_transfer_abi() -> ContractAbi {
+        ContractAbi::from_json(
+            br##"{
+                "schema_version": "0.3.0",
+                "body": {
+                    "functions": [{
+                        "name": "ft_transfer",
+                        "kind": "call",
+                        "modifiers": ["payable"],
+                        "params": {
+                            "serialization_type": "json",
+                            "args": [
+                                {"name": "receiver_id", "type_schema": {"$ref": "#/definitions/AccountId"}},
+                                {"name": "amount", "type_schema": {"$ref": "#/definitions/U128"}},
+                                {"name": "memo", "type_schema": {"type": ["string", "null"]}}
+                            ]
+                        }
+                    }],
+                    "root_schema": {
+                        "definitions": {
+                            "AccountId": {"type": "string"},
+                            "U128": {"type": "string"}
+                        }
+                    }
+                }
+            }"##,
+        )
+        .unwrap()
+    }
+
+    #[test]
+    fn contract_abi_validate_args() {
+        let contract_abi = ft_transfer_abi();
+        let function = contract_abi.function("ft_transfer").unwrap();
+        assert_eq!(
+            contract_abi.validate_args(function, br#"{"receiver_id": "bob.near", "amount": "10"}"#),
+            Ok(())
+        );
+        assert_eq!(
+            contract_abi.validate_args(function, br#"{"receiver_id": "bob.near"}"#),
+            Err("args: missing argument 'amount'".to_string())
+        );
+        assert_eq!(
+            contract_abi.validate_args(function, br#"{"receiver_id": "bob.near", "amount": 10}"#),
+            Err("amount: expected U128, got 10".to_string())
+        );
+    }
+
+    #[test]
+    fn contract_abi_from_compressed_wasm_section() {
+        let abi = zstd::encode_all(
+            &br#"{"schema_version":"0.3.0","body":{"functions":[{"name":"get","kind":"view"}]}}"#[..],
+            0,
+        )
+        .unwrap();
+        let mut custom = vec![];
+        encode_wasm_name(&mut custom, "near-abi");
+        custom.extend_from_slice(&abi);
+        assert!(
+            custom.len() < 0x80,
+            "the test encodes section sizes in one byte"
+        );
+        let mut code = b"\0asm\x01\0\0\0".to_vec();
+        encode_wasm_section(&mut code, 0, &custom);
+        let contract_abi = ContractAbi::from_wasm(&code).unwrap();
+        assert!(contract_abi.function("get").is_some());
+    }
+
+    #[test]
+    fn contract_abi_recursive_schema() {
+        let root_schema = serde_json::json!({
+            "definitions": {
+                "Loop": { "$ref": "#/definitions/Loop" },
+                "Tree": {
+                    "anyOf": [
+                        { "type": "string" },
+                        { "type": "array", "items": { "$ref": "#/definitions/Tree" } },
+                    ]
+                },
+                "Cycle": { "anyOf": [{ "$ref": "#/definitions/Cycle" }] },
+            }
+        });
+        assert_eq!(
+            resolve_json_schema(
+                &serde_json::json!({ "$ref": "#/definitions/Loop" }),
+                &root_schema
+            ),
+            &JSON_SCHEMA_ANY
+        );
+        let tree = serde_json::json!({ "$ref": "#/definitions/Tree" });
+        assert!(validate_json_schema(
+            &serde_json::json!(["a", ["b"]]),
+            &tree,
+            &root_schema,
+            "args"
+        )
+        .is_ok());
+        assert!(
+            validate_json_schema(&serde_json::json!([1]), &tree, &root_schema, "args").is_err()
+        );
+        assert!(validate_json_schema(
+            &serde_json::json!("a"),
+            &serde_json::json!({ "$ref": "#/definitions/Cycle" }),
+            &root_schema,
+            "args"
+        )
+        .is_err());
+    }
+
+    #[test]
+    fn fungible_token_amount() {
+        let metadata = FungibleTokenMetadata {
+            spec: "ft-1.0.0".to_string(),
+            name: "USD Coin".to_string(),
+            symbol: "USDC".to_string(),
+            decimals: 6,
+        };
+        assert_eq!(metadata.parse_amount("10"), Ok(10_000_000));
+        assert_eq!(metadata.parse_amount("10.5 usdc"), Ok(10_500_000));
+        assert_eq!(metadata.parse_amount("0.000001USDC"), Ok(1));
+        assert!(metadata.parse_amount("0.0000001").is_err());
+        assert!(metadata.parse_amount("10 NEAR").is_err());
+        assert_eq!(metadata.format_amount(10_500_000), "10.5 USDC");
+        assert_eq!(metadata.format_amount(10_000_000), "10 USDC");
+        assert_eq!(metadata.format_amount(1), "0.000001 USDC");
+        let metadata = FungibleTokenMetadata {
+            decimals: 39,
+            ..metadata
+        };
+        assert!(metadata.parse_amount("1").is_err());
+        assert!(metadata.parse_amount("0.1").is_err());
+        assert_eq!(metadata.format_amount(1), "1 (in the smallest USDC units)");
+    }
+
+    #[test]
+    fn account_id_list() {
+        assert_eq!(
+            "usdc.fakes.testnet, wrap.testnet,,ref.fakes.testnet"
+                .parse::<AccountIdList>()
+                .unwrap()
+                .inner,
+            vec!["usdc.fakes.testnet", "wrap.testnet", "ref.fakes.testnet"]
+        );
+        let file_path = std::env::temp_dir().join("near-cli-account-id-list-test.txt");
+        std::fs::write(
+            &file_path,
+            "# stablecoins\nusdc.fakes.testnet\nusdt.fakes.testnet # tether\n\nwrap.testnet\n",
+        )
+        .unwrap();
+        assert_eq!(
+            file_path
+                .to_str()
+                .unwrap()
+                .parse::<AccountIdList>()
+                .unwrap()
+                .inner,
+            vec!["usdc.fakes.testnet", "usdt.fakes.testnet", "wrap.testnet"]
+        );
+        std::fs::remove_file(&file_path).unwrap();
+        assert!(" , ".parse::<AccountIdList>().is_err());
+    }
+
+    #[test]
+    fn non_fungible_token_media_url() {
+        let metadata = NonFungibleTokenContractMetadata {
+            spec: "nft-1.0.0".to_string(),
+            name: "Team badges".to_string(),
+            symbol: "BADGE".to_string(),
+            base_uri: Some("https://ipfs.io/ipfs/".to_string()),
+        };
+        assert_eq!(
+            metadata.media_url("/bafybeid"),
+            "https://ipfs.io/ipfs/bafybeid"
+        );
+        assert_eq!(
+            metadata.media_url("https://example.com/1.png"),
+            "https://example.com/1.png"
+        );
+        let token: NonFungibleToken = serde_json::from_str(
+            r#"{"token_id": "1", "owner_id": "alice.near", "metadata": {"title": "One", "extra": "x"}, "approved_account_ids": {"market.near": 3}}"#,
+        )
+        .unwrap();
+        assert_eq!(token.metadata.unwrap().title, Some("One".to_string()));
+        assert_eq!(
+            token.approved_account_ids.unwrap().get("market.near"),
+            Some(&3)
+        );
+    }
+
+    #[test]
+    fn seat_price() {
+        const N: u128 = 1_000_000_000_000_000_000_000_000;
+        let seat_price_config = SeatPriceConfig {
+            num_seats: 2,
+            minimum_stake_ratio: None,
+            minimum_stake_divisor: 10,
+        };
+        assert_eq!(
+            seat_price_config.estimate_seat_price(&[1_000_000 * N, 1_000_000 * N, 100 * N]),
+            Some(1_000_000 * N)
+        );
+        assert_eq!(
+            seat_price_config.estimate_seat_price(&[3_000_000 * N, 100 * N]),
+            Some(1_500_000 * N)
+        );
+        assert_eq!(seat_price_config.estimate_seat_price(&[1]), None);
+        let seat_price_config = SeatPriceConfig {
+            num_seats: 3,
+            minimum_stake_ratio: Some((1, 6250)),
+            minimum_stake_divisor: 10,
+        };
+        assert_eq!(
+            seat_price_config.estimate_seat_price(&[6_000 * N, 250 * N]),
+            Some(N)
+        );
+        assert_eq!(
+            seat_price_config.estimate_seat_price(&[6_000 * N, 250 * N, 10 * N]),
+            Some(10 * N + 1)
+        );
+        assert_eq!(seat_price_config.minimum_stake(100 * N), 10 * N);
+    }
+
+    #[test]
+    fn stake_proposal() {
+        const N: u128 = 1_000_000_000_000_000_000_000_000;
+        assert!(check_stake_proposal(100 * N, 60 * N, 40 * N).is_ok());
+        assert!(check_stake_proposal(101 * N, 60 * N, 40 * N).is_err());
+        assert!(check_stake_proposal(5 * N, 60 * N, 0).is_ok());
+        assert!(check_stake_proposal(0, 60 * N, 40 * N).is_ok());
+        assert!(check_stake_proposal(0, 60 * N, 0).is_err());
+        let ed25519_public_key: near_crypto::PublicKey =
+            "ed25519:DReZmNmnGhpsYcCFFeYgPsJ9YCm9xH16GGujCPe3KQEq"
+                .parse()
+                .unwrap();
+        assert!(check_staking_key(&ed25519_public_key).is_ok());
+        let secp256k1_public_key =
+            near_crypto::SecretKey::from_seed(near_crypto::KeyType::SECP256K1, "test").public_key();
+        assert!(check_staking_key(&secp256k1_public_key).is_err());
+    }
+
+    #[test]
+    fn validator_kickout_reason() {
+        assert_eq!(
+            validator_kickout_reason_summary(&serde_json::json!({
+                "NotEnoughBlocks": {"produced": 1, "expected": 10}
+            })),
+            "NotEnoughBlocks (produced 1 of 10 expected)"
+        );
+        assert_eq!(
+            validator_kickout_reason_summary(&serde_json::json!("Unstaked")),
+            "Unstaked"
+        );
+    }
 }
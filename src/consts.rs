@@ -19,3 +19,14 @@ pub const DIR_NAME_TESTNET: &str = ".near-credentials/testnet/";
 pub const DIR_NAME_MAINNET: &str = ".near-credentials/mainnet/";
 pub const DIR_NAME_BETANET: &str = ".near-credentials/betanet/";
 pub const DIR_NAME_CUSTOM: &str = ".near-credentials/default/";
+pub const DIR_NAME_CONTRACT_BACKUPS: &str = ".near-cli/contract-backups/";
+
+/// The deposit the token and storage management standards (NEP-141, NEP-145, NEP-171) require
+/// for the calls that need a full access key.
+pub const ONE_YOCTONEAR: near_primitives::types::Balance = 1;
+
+pub const TESTNET_LOCKUP_MASTER_ACCOUNT_ID: &str = "lockup.m0";
+pub const MAINNET_LOCKUP_MASTER_ACCOUNT_ID: &str = "lockup.near";
+
+pub const TESTNET_LOCKUP_WHITELIST_ACCOUNT_ID: &str = "whitelist.f863973.m0";
+pub const MAINNET_LOCKUP_WHITELIST_ACCOUNT_ID: &str = "lockup-whitelist.near";